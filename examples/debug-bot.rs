@@ -35,13 +35,17 @@ impl Player for DebugAI {
 }
 
 fn main() -> SC2Result<()> {
+	let mut bot = DebugAI::default();
+
+	// Arguments passed by ladder managers are parsed by the library
+	if let Some(args) = LadderArgs::from_env()? {
+		bot.set_game_step(1);
+		return run_ladder_game_with_args(&mut bot, &args);
+	}
+
 	let app = clap_app!(DebugBot =>
 		(version: crate_version!())
 		(author: crate_authors!())
-		(@arg ladder_server: --LadderServer +takes_value)
-		(@arg opponent_id: --OpponentId +takes_value)
-		(@arg host_port: --GamePort +takes_value)
-		(@arg player_port: --StartPort +takes_value)
 		(@arg race: -r --race
 			+takes_value
 			"Sets race for debug bot"
@@ -109,7 +113,6 @@ fn main() -> SC2Result<()> {
 		None => unreachable!(),
 	};
 
-	let mut bot = DebugAI::default();
 	bot.set_game_step(game_step);
 	if let Some(race) = app
 		.value_of("race")
@@ -118,85 +121,74 @@ fn main() -> SC2Result<()> {
 		bot.race = race;
 	}
 
-	if app.is_present("ladder_server") {
-		run_ladder_game(
-			&mut bot,
-			app.value_of("ladder_server").unwrap_or("127.0.0.1"),
-			app.value_of("host_port").expect("GamePort must be specified"),
-			app.value_of("player_port")
-				.expect("StartPort must be specified")
-				.parse()
-				.expect("Can't parse StartPort"),
-			app.value_of("opponent_id"),
-		)
-	} else {
-		let mut rng = thread_rng();
+	let mut rng = thread_rng();
 
-		match app.subcommand() {
-			("local", Some(sub)) => run_vs_computer(
-				&mut bot,
-				Computer::new(
-					sub.value_of("race").map_or(Race::Random, |race| {
-						race.parse().expect("Can't parse computer race")
-					}),
-					sub.value_of("difficulty")
-						.map_or(Difficulty::VeryEasy, |difficulty| {
-							difficulty.parse().expect("Can't parse computer difficulty")
-						}),
-					sub.value_of("ai_build")
-						.map(|ai_build| ai_build.parse().expect("Can't parse computer build")),
-				),
-				sub.value_of("map").unwrap_or_else(|| {
-					[
-						"AcropolisLE",
-						"DiscoBloodbathLE",
-						"EphemeronLE",
-						"ThunderbirdLE",
-						"TritonLE",
-						"WintersGateLE",
-						"WorldofSleepersLE",
-					]
-					.choose(&mut rng)
-					.unwrap()
+	match app.subcommand() {
+		("local", Some(sub)) => run_vs_computer(
+			&mut bot,
+			Computer::new(
+				sub.value_of("race").map_or(Race::Random, |race| {
+					race.parse().expect("Can't parse computer race")
 				}),
-				LaunchOptions {
-					sc2_version: sub.value_of("sc2_version"),
-					realtime: sub.is_present("realtime"),
-					save_replay_as: sub.value_of("save_replay"),
-				},
+				sub.value_of("difficulty")
+					.map_or(Difficulty::VeryEasy, |difficulty| {
+						difficulty.parse().expect("Can't parse computer difficulty")
+					}),
+				sub.value_of("ai_build")
+					.map(|ai_build| ai_build.parse().expect("Can't parse computer build")),
 			),
-			("human", Some(sub)) => run_vs_human(
-				&mut bot,
-				PlayerSettings::new(
-					sub.value_of("race")
-						.unwrap()
-						.parse()
-						.expect("Can't parse human race"),
-					sub.value_of("name"),
-				),
-				sub.value_of("map").unwrap_or_else(|| {
-					[
-						"AcropolisLE",
-						"DiscoBloodbathLE",
-						"EphemeronLE",
-						"ThunderbirdLE",
-						"TritonLE",
-						"WintersGateLE",
-						"WorldofSleepersLE",
-					]
-					.choose(&mut rng)
+			sub.value_of("map").unwrap_or_else(|| {
+				[
+					"AcropolisLE",
+					"DiscoBloodbathLE",
+					"EphemeronLE",
+					"ThunderbirdLE",
+					"TritonLE",
+					"WintersGateLE",
+					"WorldofSleepersLE",
+				]
+				.choose(&mut rng)
+				.unwrap()
+			}),
+			LaunchOptions {
+				sc2_version: sub.value_of("sc2_version"),
+				realtime: sub.is_present("realtime"),
+				save_replay_as: sub.value_of("save_replay"),
+				..Default::default()
+			},
+		),
+		("human", Some(sub)) => run_vs_human(
+			&mut bot,
+			PlayerSettings::new(
+				sub.value_of("race")
 					.unwrap()
-				}),
-				LaunchOptions {
-					sc2_version: sub.value_of("sc2_version"),
-					realtime: true,
-					save_replay_as: sub.value_of("save_replay"),
-				},
+					.parse()
+					.expect("Can't parse human race"),
+				sub.value_of("name"),
 			),
-			_ => {
-				println!("Game mode is not specified! Use -h, --help to print help information.");
-				std::process::exit(0);
-			}
+			sub.value_of("map").unwrap_or_else(|| {
+				[
+					"AcropolisLE",
+					"DiscoBloodbathLE",
+					"EphemeronLE",
+					"ThunderbirdLE",
+					"TritonLE",
+					"WintersGateLE",
+					"WorldofSleepersLE",
+				]
+				.choose(&mut rng)
+				.unwrap()
+			}),
+			LaunchOptions {
+				sc2_version: sub.value_of("sc2_version"),
+				realtime: true,
+				save_replay_as: sub.value_of("save_replay"),
+				..Default::default()
+			},
+		),
+		_ => {
+			println!("Game mode is not specified! Use -h, --help to print help information.");
+			std::process::exit(0);
 		}
 	}
 }
@@ -1,4 +1,4 @@
-use rust_sc2::prelude::*;
+use rust_sc2::{action::Action, prelude::*};
 
 // Example of how to use events
 
@@ -24,6 +24,10 @@ impl Player for EmptyBot {
 				if let Some(u) = self.units.my.structures.get(tag) { /* your code here */ }
 			}
 			Event::RandomRaceDetected(race) => { /* your code here */ }
+			Event::ActionFailed(outcome) => {
+				// Failed command can be retried here, it will be sent on the same step
+				if let Action::UnitCommand(ability, target, ..) = outcome.action { /* your code here */ }
+			}
 		}
 		Ok(())
 	}
@@ -32,10 +36,12 @@ impl Player for EmptyBot {
 fn main() -> SC2Result<()> {
 	let mut bot = EmptyBot::default();
 
-	run_vs_computer(
+	// Joins ladder game if launched with ladder arguments, otherwise plays locally
+	run_ladder_or_local(
 		&mut bot,
-		Computer::new(Race::Random, Difficulty::VeryEasy, None),
-		"EverDreamLE",
-		Default::default(),
+		LocalOptions::new(
+			Computer::new(Race::Random, Difficulty::VeryEasy, None),
+			"EverDreamLE",
+		),
 	)
 }
@@ -20,10 +20,10 @@ fn main() -> SC2Result<()> {
 		Computer::new(Race::Random, Difficulty::VeryEasy, None),
 		"EverDreamLE",
 		Some("4.10"), // Client version can be specified, otherwise will be used latest available version
-	);
+	)?;
 
 	// 2. Configure runner
-	runner.set_map("EternalEmpireLE");
+	runner.set_map("EternalEmpireLE")?;
 	runner.computer = Computer::new(Race::Protoss, Difficulty::VeryHard, Some(AIBuild::Air));
 	runner.realtime = true; // Default: false
 	runner.save_replay_as = Some("path/to/replay/MyReplay.SC2Replay"); // Default: None == don't save replay
@@ -42,7 +42,7 @@ fn main() -> SC2Result<()> {
 	// Run multiply times
 	for i in 0..3 {
 		// Configuration can be changed between games
-		runner.set_map(MAPS[i]);
+		runner.set_map(MAPS[i])?;
 		runner.computer.race = RACES[i];
 		runner.computer.difficulty = DIFFICULTIES[i];
 
@@ -57,7 +57,7 @@ fn main() -> SC2Result<()> {
 		Computer::new(Race::Random, Difficulty::VeryEasy, None),
 		"Flat32",
 		None,
-	);
+	)?;
 	other.run_game()?;
 	other.close();
 
@@ -68,10 +68,10 @@ fn main() -> SC2Result<()> {
 		PlayerSettings::new(Race::Random, Some("Name")),
 		"PillarsofGoldLE",
 		None,
-	);
+	)?;
 
 	// 2. Configure runner
-	runner.set_map("PillarsofGoldLE");
+	runner.set_map("PillarsofGoldLE")?;
 	runner.human_settings = PlayerSettings::new(Race::Random, Some("Name"));
 	runner.realtime = false;
 	runner.save_replay_as = None;
@@ -82,6 +82,21 @@ fn main() -> SC2Result<()> {
 	// 4. Run games
 	runner.run_game()?;
 
+	// Series of games vs Computer
+	// Every map is played vs every opponent given number of times
+	let mut batch = BatchRunner::new(
+		MAPS,
+		&[
+			Computer::new(Race::Zerg, Difficulty::Hard, None),
+			Computer::new(Race::Protoss, Difficulty::Hard, None),
+		],
+		2,
+	);
+	batch.parallel = 2; // Games can be played on several clients at once
+	let summary = batch.run(EmptyBot::default)?; // New bot is created for every game
+	println!("Win rate: {:.1}%", summary.win_rate() * 100.0);
+	summary.save_csv("results.csv")?;
+
 	// Runners dropped here:
 	// Any launched sc2 clients will be closed automatically
 
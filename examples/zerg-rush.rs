@@ -37,7 +37,7 @@ impl Player for ZergRushAI {
 	fn on_step(&mut self, _iteration: usize) -> SC2Result<()> {
 		self.distribute_workers();
 		self.upgrades();
-		self.build();
+		self.build()?;
 		self.order_units();
 		self.execute_micro();
 		Ok(())
@@ -252,9 +252,9 @@ impl ZergRushAI {
 			})
 			.closest(pos)
 	}
-	fn build(&mut self) {
+	fn build(&mut self) -> SC2Result<()> {
 		if self.minerals < 75 {
-			return;
+			return Ok(());
 		}
 
 		let mineral_tags = self
@@ -267,7 +267,7 @@ impl ZergRushAI {
 		let pool = UnitTypeId::SpawningPool;
 		if self.counter().all().count(pool) == 0 && self.can_afford(pool, false) {
 			let place = self.start_location.towards(self.game_info.map_center, 6.0);
-			if let Some(location) = self.find_placement(pool, place, Default::default()) {
+			if let Some(location) = self.find_placement(pool, place, Default::default())? {
 				if let Some(builder) = self.get_builder(location, &mineral_tags) {
 					builder.build(pool, location, false);
 					self.subtract_resources(pool, false);
@@ -278,7 +278,7 @@ impl ZergRushAI {
 		let extractor = UnitTypeId::Extractor;
 		if self.counter().all().count(extractor) == 0 && self.can_afford(extractor, false) {
 			let start = self.start_location;
-			if let Some(geyser) = self.find_gas_placement(start) {
+			if let Some(geyser) = self.find_gas_placement(start)? {
 				if let Some(builder) = self.get_builder(geyser.position, &mineral_tags) {
 					builder.build_gas(geyser.tag, false);
 					self.subtract_resources(extractor, false);
@@ -288,13 +288,14 @@ impl ZergRushAI {
 
 		let hatchery = UnitTypeId::Hatchery;
 		if self.can_afford(hatchery, false) {
-			if let Some((location, _resource_center)) = self.get_expansion() {
+			if let Some((location, _resource_center)) = self.get_expansion()? {
 				if let Some(builder) = self.get_builder(location, &mineral_tags) {
 					builder.build(hatchery, location, false);
 					self.subtract_resources(hatchery, false);
 				}
 			}
 		}
+		Ok(())
 	}
 
 	fn upgrades(&mut self) {
@@ -384,13 +385,17 @@ impl ZergRushAI {
 }
 
 fn main() -> SC2Result<()> {
+	let mut bot = ZergRushAI::default();
+
+	// Arguments passed by ladder managers are parsed by the library
+	if let Some(args) = LadderArgs::from_env()? {
+		bot.set_game_step(1);
+		return run_ladder_game_with_args(&mut bot, &args);
+	}
+
 	let app = clap_app!(RustyLings =>
 		(version: crate_version!())
 		(author: crate_authors!())
-		(@arg ladder_server: --LadderServer +takes_value)
-		(@arg opponent_id: --OpponentId +takes_value)
-		(@arg host_port: --GamePort +takes_value)
-		(@arg player_port: --StartPort +takes_value)
 		(@arg game_step: -s --step
 			+takes_value
 			default_value("1")
@@ -454,82 +459,70 @@ fn main() -> SC2Result<()> {
 		None => unreachable!(),
 	};
 
-	let mut bot = ZergRushAI::default();
 	bot.set_game_step(game_step);
 
-	if app.is_present("ladder_server") {
-		run_ladder_game(
+	let mut rng = thread_rng();
+
+	match app.subcommand() {
+		("local", Some(sub)) => run_vs_computer(
 			&mut bot,
-			app.value_of("ladder_server").unwrap_or("127.0.0.1"),
-			app.value_of("host_port").expect("GamePort must be specified"),
-			app.value_of("player_port")
-				.expect("StartPort must be specified")
-				.parse()
-				.expect("Can't parse StartPort"),
-			app.value_of("opponent_id"),
-		)
-	} else {
-		let mut rng = thread_rng();
-
-		match app.subcommand() {
-			("local", Some(sub)) => run_vs_computer(
-				&mut bot,
-				Computer::new(
-					sub.value_of("race").map_or(Race::Random, |race| {
-						race.parse().expect("Can't parse computer race")
-					}),
-					sub.value_of("difficulty")
-						.map_or(Difficulty::VeryEasy, |difficulty| {
-							difficulty.parse().expect("Can't parse computer difficulty")
-						}),
-					sub.value_of("ai_build")
-						.map(|ai_build| ai_build.parse().expect("Can't parse computer build")),
-				),
-				sub.value_of("map").unwrap_or_else(|| {
-					[
-						"DeathAuraLE",
-					]
-					.choose(&mut rng)
-					.unwrap()
+			Computer::new(
+				sub.value_of("race").map_or(Race::Random, |race| {
+					race.parse().expect("Can't parse computer race")
 				}),
-				LaunchOptions {
-					sc2_version: sub.value_of("sc2_version"),
-					realtime: sub.is_present("realtime"),
-					save_replay_as: sub.value_of("save_replay"),
-				},
+				sub.value_of("difficulty")
+					.map_or(Difficulty::VeryEasy, |difficulty| {
+						difficulty.parse().expect("Can't parse computer difficulty")
+					}),
+				sub.value_of("ai_build")
+					.map(|ai_build| ai_build.parse().expect("Can't parse computer build")),
 			),
-			("human", Some(sub)) => run_vs_human(
-				&mut bot,
-				PlayerSettings::new(
-					sub.value_of("race")
-						.unwrap()
-						.parse()
-						.expect("Can't parse human race"),
-					sub.value_of("name"),
-				),
-				sub.value_of("map").unwrap_or_else(|| {
-					[
-						"AcropolisLE",
-						"DiscoBloodbathLE",
-						"EphemeronLE",
-						"ThunderbirdLE",
-						"TritonLE",
-						"WintersGateLE",
-						"WorldofSleepersLE",
-					]
-					.choose(&mut rng)
+			sub.value_of("map").unwrap_or_else(|| {
+				[
+					"DeathAuraLE",
+				]
+				.choose(&mut rng)
+				.unwrap()
+			}),
+			LaunchOptions {
+				sc2_version: sub.value_of("sc2_version"),
+				realtime: sub.is_present("realtime"),
+				save_replay_as: sub.value_of("save_replay"),
+				..Default::default()
+			},
+		),
+		("human", Some(sub)) => run_vs_human(
+			&mut bot,
+			PlayerSettings::new(
+				sub.value_of("race")
 					.unwrap()
-				}),
-				LaunchOptions {
-					sc2_version: sub.value_of("sc2_version"),
-					realtime: true,
-					save_replay_as: sub.value_of("save_replay"),
-				},
+					.parse()
+					.expect("Can't parse human race"),
+				sub.value_of("name"),
 			),
-			_ => {
-				println!("Game mode is not specified! Use -h, --help to print help information.");
-				std::process::exit(0);
-			}
+			sub.value_of("map").unwrap_or_else(|| {
+				[
+					"AcropolisLE",
+					"DiscoBloodbathLE",
+					"EphemeronLE",
+					"ThunderbirdLE",
+					"TritonLE",
+					"WintersGateLE",
+					"WorldofSleepersLE",
+				]
+				.choose(&mut rng)
+				.unwrap()
+			}),
+			LaunchOptions {
+				sc2_version: sub.value_of("sc2_version"),
+				realtime: true,
+				save_replay_as: sub.value_of("save_replay"),
+				..Default::default()
+			},
+		),
+		_ => {
+			println!("Game mode is not specified! Use -h, --help to print help information.");
+			std::process::exit(0);
 		}
 	}
 }
@@ -10,7 +10,14 @@ use serde::{Serialize, Deserialize};
 """
 DERIVES = """\
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, FromPrimitive, ToPrimitive, Copy, Clone, PartialEq, Eq, Hash)]\
+#[derive(Debug, FromPrimitive, ToPrimitive, FromStr, Copy, Clone, PartialEq, Eq, Hash)]\
+"""
+REMAPS_HEAD = """\
+//! Ids of game versions 4.10.x, which differ from the latest ones.
+#![allow(deprecated)]
+
+use super::*;
+use num_traits::{FromPrimitive, ToPrimitive};
 """
 ENUM_NAMES = ("UnitTypeId", "AbilityId", "UpgradeId", "BuffId", "EffectId")
 FILE_NAMES = ("unit_typeid", "ability_id", "upgrade_id", "buff_id", "effect_id")
@@ -145,6 +152,42 @@ def gen_enum(enum, name):
 	)
 
 
+def gen_remaps(enum, enum_old, name, file):
+	# Ids of older version are mapped to the latest ones by names,
+	# ids which don't exist in one of versions are mapped to `None` (from raw) or `0` (to raw)
+	names = {v: k for k, v in enum.items()}
+	names_old = {v: k for k, v in enum_old.items()}
+	from_old = []
+	for raw in sorted(names.keys() | names_old.keys()):
+		key = names_old.get(raw)
+		key = key if key in enum else None
+		if key != names.get(raw):
+			from_old.append((raw, f"Some({name}::{key})" if key else "None"))
+	to_old = [
+		(k, enum_old.get(k, 0))
+		for k, v in sorted(enum.items(), key=lambda x: x[1])
+		if enum_old.get(k, 0) != v
+	]
+
+	def gen_match(arg, arms, default):
+		if not arms:
+			return f"\t{default}\n"
+		return (
+			f"\tmatch {arg} {{\n"
+			+ "".join(f"\t\t{k} => {v},\n" for k, v in arms)
+			+ f"\t\t_ => {default},\n\t}}\n"
+		)
+
+	return (
+		f"pub(crate) fn {file}_from_4_10(raw: u32) -> Option<{name}> {{\n"
+		+ gen_match("raw", from_old, f"{name}::from_u32(raw)")
+		+ "}\n\n"
+		+ f"pub(crate) fn {file}_to_4_10(id: {name}) -> u32 {{\n"
+		+ gen_match("id", [(f"{name}::{k}", v) for k, v in to_old], "id.to_u32().unwrap()")
+		+ "}\n"
+	)
+
+
 def generate():
 	mod = [
 		[
@@ -154,7 +197,7 @@ def generate():
 		],
 		[],
 		[],
-		["mod impls;"],
+		["mod impls;", "mod remaps;", "mod versions;", "", "pub use versions::DataVersion;"],
 	]
 	enums_latest = parse_data(
 		load((Path.home() / "Documents" / "StarCraft II" / "stableid.json").open())
@@ -166,22 +209,16 @@ def generate():
 		version="4.10",
 	)
 
-	for name, file, enum, enum_linux in zip(
+	remaps = [REMAPS_HEAD]
+	for name, file, enum, enum_4_10 in zip(
 		ENUM_NAMES, FILE_NAMES, enums_latest, enums_4_10
 	):
-		if enum == enum_linux:
-			generated = f"{HEAD}\n{gen_enum(enum, name)}"
-		else:
-			generated = (
-				f'{HEAD}\n#[cfg(target_os = "windows")]\n'
-				+ gen_enum(enum, name)
-				+ '\n#[cfg(target_os = "linux")]\n'
-				+ gen_enum(enum_linux, name)
-			)
-
+		generated = f"{HEAD}\n{gen_enum(enum, name)}"
 		(Path.cwd() / "src" / "ids" / f"{file}.rs").write_text(generated)
+		remaps.append(gen_remaps(enum, enum_4_10, name, file))
 		mod[1].append(f"mod {file};")
 		mod[2].append(f"pub use {file}::{name};")
+	(Path.cwd() / "src" / "ids" / "remaps.rs").write_text("\n".join(remaps))
 	(Path.cwd() / "src" / "ids" / "mod.rs").write_text(
 		"\n\n".join("\n".join(part) for part in mod) + "\n"
 	)
@@ -2,10 +2,9 @@
 
 use crate::{
 	geometry::{Point2, Point3},
-	ids::AbilityId,
+	ids::{AbilityId, DataVersion},
 	FromProto, IntoProto,
 };
-use num_traits::{FromPrimitive, ToPrimitive};
 use rustc_hash::FxHashMap;
 use sc2_proto::{
 	error::ActionResult as ProtoActionResult,
@@ -40,10 +39,11 @@ pub enum Action {
 	ToggleAutocast(AbilityId, Vec<u64>),
 	Chat(String, bool),
 }
-impl IntoProto<ProtoAction> for &Action {
+impl IntoProto<ProtoAction> for (&Action, DataVersion) {
 	fn into_proto(self) -> ProtoAction {
+		let (bot_action, version) = self;
 		let mut action = ProtoAction::new();
-		match self {
+		match bot_action {
 			Action::Chat(message, team_only) => {
 				let chat_action = action.mut_action_chat();
 				chat_action.set_channel({
@@ -57,7 +57,7 @@ impl IntoProto<ProtoAction> for &Action {
 			}
 			Action::UnitCommand(ability, target, units, queue) => {
 				let unit_command = action.mut_action_raw().mut_unit_command();
-				unit_command.set_ability_id(ability.to_i32().unwrap());
+				unit_command.set_ability_id(ability.to_raw(version) as i32);
 				match target {
 					Target::Pos(pos) => unit_command.set_target_world_space_pos(pos.into_proto()),
 					Target::Tag(tag) => unit_command.set_target_unit_tag(*tag),
@@ -72,20 +72,20 @@ impl IntoProto<ProtoAction> for &Action {
 			}
 			Action::ToggleAutocast(ability, units) => {
 				let toggle_autocast = action.mut_action_raw().mut_toggle_autocast();
-				toggle_autocast.set_ability_id(ability.to_i32().unwrap());
+				toggle_autocast.set_ability_id(ability.to_raw(version) as i32);
 				toggle_autocast.set_unit_tags(units.to_vec());
 			}
 		}
 		action
 	}
 }
-impl FromProto<&ProtoAction> for Option<Action> {
-	fn from_proto(action: &ProtoAction) -> Self {
+impl FromProto<(&ProtoAction, DataVersion)> for Option<Action> {
+	fn from_proto((action, version): (&ProtoAction, DataVersion)) -> Self {
 		// let game_loop: u32 = action.get_game_loop();
 		if action.has_action_raw() {
 			match &action.get_action_raw().action {
 				Some(ProtoRawAction::unit_command(unit_command)) => Some(Action::UnitCommand(
-					AbilityId::from_i32(unit_command.get_ability_id()).unwrap(),
+					AbilityId::from_raw(unit_command.get_ability_id() as u32, version).unwrap(),
 					{
 						match &unit_command.target {
 							Some(ProtoTarget::target_world_space_pos(pos)) => {
@@ -102,7 +102,7 @@ impl FromProto<&ProtoAction> for Option<Action> {
 					Point3::from_proto(camera_move.get_center_world_space()),
 				)),
 				Some(ProtoRawAction::toggle_autocast(toggle_autocast)) => Some(Action::ToggleAutocast(
-					AbilityId::from_i32(toggle_autocast.get_ability_id()).unwrap(),
+					AbilityId::from_raw(toggle_autocast.get_ability_id() as u32, version).unwrap(),
 					toggle_autocast.get_unit_tags().to_vec(),
 				)),
 				None => unreachable!(),
@@ -132,16 +132,32 @@ pub struct ActionError {
 	/// Result of executed action.
 	pub result: ActionResult,
 }
-impl FromProto<&ProtoActionError> for ActionError {
-	fn from_proto(e: &ProtoActionError) -> Self {
+impl FromProto<(&ProtoActionError, DataVersion)> for ActionError {
+	fn from_proto((e, version): (&ProtoActionError, DataVersion)) -> Self {
 		Self {
 			unit: e.get_unit_tag(),
-			ability: AbilityId::from_u64(e.get_ability_id()).unwrap(),
+			ability: AbilityId::from_raw(e.get_ability_id() as u32, version).unwrap(),
 			result: ActionResult::from_proto(e.get_result()),
 		}
 	}
 }
 
+/// Immediate result of action sent on current game step.
+/// Stored in [`action_results`](crate::bot::Bot::action_results).
+#[derive(Debug, Clone)]
+pub struct ActionOutcome {
+	/// Action sent to SC2 (e.g. [`Action::UnitCommand`] given to units).
+	pub action: Action,
+	/// Result of the action.
+	pub result: ActionResult,
+}
+impl ActionOutcome {
+	/// Checks if action succeeded.
+	pub fn is_success(&self) -> bool {
+		self.result == ActionResult::Success
+	}
+}
+
 /// Result of executed action.
 #[allow(missing_docs)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
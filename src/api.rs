@@ -1,28 +1,101 @@
 //! Contains nice wrapper around SC2 API.
 
-use crate::client::{SC2Result, WS};
+use crate::{
+	client::{response_result, SC2Error, SC2Result, WS},
+	recording::{Playback, Recorder},
+};
 use protobuf::Message;
 use sc2_proto::sc2api::{Request, Response};
-use tungstenite::Message::Binary;
+use std::{collections::VecDeque, io::ErrorKind, time::Duration};
+use tungstenite::{stream::Stream, Error as WsError, Message::Binary};
+
+#[cfg(feature = "async")]
+use crate::async_client::{block_on, SharedAPI};
+
+enum Connection {
+	Socket(WS),
+	Playback(Playback),
+	#[cfg(feature = "async")]
+	Async(SharedAPI),
+}
 
 /// SC2 API. Can be accessed through [`self.api()`](crate::bot::Bot::api).
-pub struct API(pub(crate) WS);
+pub struct API {
+	connection: Connection,
+	recorder: Option<Recorder>,
+	pending: VecDeque<Request>,
+}
 impl API {
-	/// Sends request and returns a response.
-	pub fn send(&mut self, req: Request) -> SC2Result<Response> {
-		self.0.write_message(Binary(req.write_to_bytes()?))?;
+	pub(crate) fn new(ws: WS) -> Self {
+		Self {
+			connection: Connection::Socket(ws),
+			recorder: None,
+			pending: VecDeque::new(),
+		}
+	}
+	pub(crate) fn playback(path: &str) -> SC2Result<Self> {
+		Ok(Self {
+			connection: Connection::Playback(Playback::open(path)?),
+			recorder: None,
+			pending: VecDeque::new(),
+		})
+	}
 
-		let msg = self.0.read_message()?;
+	#[cfg(feature = "async")]
+	pub(crate) fn shared(api: SharedAPI) -> Self {
+		Self {
+			connection: Connection::Async(api),
+			recorder: None,
+			pending: VecDeque::new(),
+		}
+	}
 
-		let mut res = Response::new();
-		res.merge_from_bytes(msg.into_data().as_slice())?;
-		Ok(res)
+	/// Starts recording of every `Request`/`Response` pair to the file in given path.
+	///
+	/// Recording can be played back with [`run_recorded_game`](crate::client::run_recorded_game).
+	pub fn start_recording(&mut self, path: &str) -> SC2Result<()> {
+		self.recorder = Some(Recorder::create(path)?);
+		Ok(())
+	}
+	/// Stops recording started with [`start_recording`](Self::start_recording).
+	pub fn stop_recording(&mut self) {
+		self.recorder = None;
+	}
+	/// Returns `true` if API plays back recorded responses instead of talking to SC2 client.
+	pub fn is_playback(&self) -> bool {
+		matches!(self.connection, Connection::Playback(_))
+	}
+
+	/// Sets timeout of waiting for every response from SC2, `None` means to wait forever.
+	///
+	/// When timeout is reached, [`SC2Error::Timeout`] is returned.
+	/// Initial value is taken from [`ConnectOptions`](crate::client::ConnectOptions).
+	pub fn set_read_timeout(&mut self, timeout: Option<Duration>) -> SC2Result<()> {
+		match &mut self.connection {
+			Connection::Socket(ws) => {
+				if let Stream::Plain(stream) = ws.get_ref() {
+					stream.set_read_timeout(timeout)?;
+				}
+			}
+			Connection::Playback(_) => {}
+			#[cfg(feature = "async")]
+			Connection::Async(api) => block_on(api.lock())?.set_read_timeout(timeout),
+		}
+		Ok(())
+	}
+
+	/// Sends request and returns a response.
+	///
+	/// Returns [`SC2Error::Response`](crate::client::SC2Error::Response) if SC2 responded with errors.
+	pub fn send(&mut self, req: Request) -> SC2Result<Response> {
+		self.send_only(req)?;
+		self.wait_response()
 	}
 
 	/// Sends request, waits for the response, but ignores it (useful when response is empty).
 	pub fn send_request(&mut self, req: Request) -> SC2Result<()> {
-		self.0.write_message(Binary(req.write_to_bytes()?))?;
-		let _ = self.0.read_message()?;
+		self.send_only(req)?;
+		let _ = self.wait_response()?;
 		Ok(())
 	}
 
@@ -32,17 +105,50 @@ impl API {
 	/// [`send`]: Self::send
 	/// [`send_request`]: Self::send_request
 	pub fn send_only(&mut self, req: Request) -> SC2Result<()> {
-		self.0.write_message(Binary(req.write_to_bytes()?))?;
+		match &mut self.connection {
+			Connection::Socket(ws) => ws.write_message(Binary(req.write_to_bytes()?))?,
+			Connection::Playback(_) => {}
+			#[cfg(feature = "async")]
+			Connection::Async(api) => {
+				let req = req.clone();
+				block_on(async { api.lock().await.send_only(req).await })??
+			}
+		}
+		if self.recorder.is_some() || self.is_playback() {
+			self.pending.push_back(req);
+		}
 		Ok(())
 	}
 	/// Waits for a response (useful only after [`send_only`]).
 	///
 	/// [`send_only`]: Self::send_only
 	pub fn wait_response(&mut self) -> SC2Result<Response> {
-		let msg = self.0.read_message()?;
+		let res = match &mut self.connection {
+			Connection::Socket(ws) => {
+				let msg = ws.read_message().map_err(|e| match e {
+					WsError::Io(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+						SC2Error::Timeout("SC2 didn't respond in time".to_string())
+					}
+					e => e.into(),
+				})?;
+
+				let mut res = Response::new();
+				res.merge_from_bytes(msg.into_data().as_slice())?;
+				res
+			}
+			Connection::Playback(playback) => {
+				let req = self.pending.front().cloned().unwrap_or_default();
+				playback.respond(&req)?
+			}
+			#[cfg(feature = "async")]
+			Connection::Async(api) => block_on(async { api.lock().await.wait_response().await })??,
+		};
 
-		let mut res = Response::new();
-		res.merge_from_bytes(msg.into_data().as_slice())?;
-		Ok(res)
+		if let Some(req) = self.pending.pop_front() {
+			if let Some(recorder) = &mut self.recorder {
+				recorder.write(&req, &res)?;
+			}
+		}
+		response_result(res)
 	}
 }
@@ -0,0 +1,405 @@
+//! Async versions of [`API`](crate::api::API) and runners, powered by `tokio`.
+//!
+//! Enabled with `async` feature.
+//!
+//! Async runners don't block the thread while waiting for SC2 responses,
+//! so futures of several games can be joined on one task. While one game waits for SC2, another one can run:
+//! ```
+//! #[tokio::main(flavor = "multi_thread")]
+//! async fn main() -> SC2Result<()> {
+//!     let mut bot1 = MyBot::default();
+//!     let mut bot2 = MyBot::default();
+//!     let computer = || Computer::new(Race::Random, Difficulty::Hard, None);
+//!     let (res1, res2) = tokio::join!(
+//!         run_vs_computer_async(&mut bot1, computer(), "EverDreamLE", Default::default()),
+//!         run_vs_computer_async(&mut bot2, computer(), "GoldenWallLE", Default::default()),
+//!     );
+//!     res1?;
+//!     res2
+//! }
+//! ```
+//! Code of bots isn't async, so steps of joined games never run in parallel:
+//! step of one bot (with all requests it sends) blocks the task until it's finished.
+//! Bots aren't `Send`, so to use several threads run each game on it's own thread with it's own runtime.
+//!
+//! In realtime mode the next observation is requested before the bot starts it's step,
+//! so SC2 prepares it while the bot is busy. Otherwise it's requested together with the step.
+//!
+//! Requests sent by the bot itself (e.g. in [`query_placement`](crate::bot::Bot::query_placement))
+//! still look synchronous for the bot, they're executed with [`block_in_place`],
+//! so multi-threaded `tokio` runtime is required.
+//! On other runtimes, or outside of runtime, such requests fail with [`SC2Error::Runtime`].
+//!
+//! [`block_in_place`]: tokio::task::block_in_place
+
+#[cfg(feature = "test-utils")]
+use crate::mock::MockServer;
+use crate::{
+	api::API,
+	bot::Rs,
+	client::{
+		actions_request, create_computer_setup, create_game_result, create_player_setup, debug_request,
+		game_result, get_unused_port, join_request, join_result, launch_client, observation_request,
+		response_result, save_replay, set_static_data, step_request, ConnectOptions, LaunchOptions, Ports,
+		SC2Error, SC2Result, HOST,
+	},
+	game_state::update_state,
+	paths::*,
+	player::Computer,
+	Player,
+};
+use futures_util::{SinkExt, StreamExt};
+use protobuf::Message;
+use sc2_proto::sc2api::{Request, Response, Status};
+use std::{future::Future, time::Duration};
+use tokio::{
+	net::TcpStream,
+	runtime::{Handle, RuntimeFlavor},
+	sync::Mutex,
+	task::block_in_place,
+	time::{sleep, timeout},
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message::Binary, MaybeTlsStream, WebSocketStream};
+
+/// Async SC2 API.
+pub struct AsyncAPI {
+	ws: WebSocketStream<MaybeTlsStream<TcpStream>>,
+	read_timeout: Option<Duration>,
+	observation_pending: bool,
+	observation: Option<Response>,
+}
+impl AsyncAPI {
+	/// Sets timeout of waiting for every response from SC2, `None` means to wait forever.
+	pub fn set_read_timeout(&mut self, timeout: Option<Duration>) {
+		self.read_timeout = timeout;
+	}
+
+	/// Sends request and returns a response.
+	pub async fn send(&mut self, req: Request) -> SC2Result<Response> {
+		self.send_only(req).await?;
+		self.wait_response().await
+	}
+	/// Sends request, waits for the response, but ignores it (useful when response is empty).
+	pub async fn send_request(&mut self, req: Request) -> SC2Result<()> {
+		self.send_only(req).await?;
+		let _ = self.wait_response().await?;
+		Ok(())
+	}
+	/// Sends request, but doesn't wait for the response.
+	pub async fn send_only(&mut self, req: Request) -> SC2Result<()> {
+		self.ws.send(Binary(req.write_to_bytes()?)).await?;
+		Ok(())
+	}
+	/// Waits for a response (useful only after [`send_only`](Self::send_only)).
+	pub async fn wait_response(&mut self) -> SC2Result<Response> {
+		// Response to observation requested ahead comes first, it's kept until runner takes it
+		if self.observation_pending {
+			self.observation_pending = false;
+			self.observation = Some(self.read_timed().await?);
+		}
+		self.read_timed().await
+	}
+
+	// Requests observation, which is taken later with `take_observation`,
+	// other requests can be sent and awaited meanwhile
+	async fn request_observation(&mut self, req: Request) -> SC2Result<()> {
+		self.send_only(req).await?;
+		self.observation_pending = true;
+		Ok(())
+	}
+	async fn take_observation(&mut self) -> SC2Result<Response> {
+		match self.observation.take() {
+			Some(res) => Ok(res),
+			None => {
+				self.observation_pending = false;
+				self.read_timed().await
+			}
+		}
+	}
+
+	async fn read_timed(&mut self) -> SC2Result<Response> {
+		match self.read_timeout {
+			Some(read_timeout) => timeout(read_timeout, self.read_response())
+				.await
+				.map_err(|_| SC2Error::Timeout("SC2 didn't respond in time".to_string()))?,
+			None => self.read_response().await,
+		}
+	}
+	async fn read_response(&mut self) -> SC2Result<Response> {
+		loop {
+			let msg = self.ws.next().await.ok_or(SC2Error::ConnectionClosed)??;
+			if msg.is_close() {
+				return Err(SC2Error::ConnectionClosed);
+			}
+			if msg.is_binary() {
+				let mut res = Response::new();
+				res.merge_from_bytes(msg.into_data().as_slice())?;
+				return response_result(res);
+			}
+		}
+	}
+}
+
+/// Shared handle to [`AsyncAPI`], used by both runner and bot.
+pub(crate) type SharedAPI = Rs<Mutex<AsyncAPI>>;
+
+/// Runs future to completion from synchronous code (i.e. requests sent by the bot).
+///
+/// Returns [`SC2Error::Runtime`] instead of panicking if called outside of multi-threaded runtime.
+pub(crate) fn block_on<F: Future>(future: F) -> SC2Result<F::Output> {
+	let handle = Handle::try_current()
+		.map_err(|_| SC2Error::Runtime("Request is sent outside of tokio runtime".to_string()))?;
+	if handle.runtime_flavor() != RuntimeFlavor::MultiThread {
+		return Err(SC2Error::Runtime(
+			"Multi-threaded tokio runtime is required to send requests from the bot".to_string(),
+		));
+	}
+	Ok(block_in_place(|| handle.block_on(future)))
+}
+
+async fn connect_to_websocket_async(host: &str, port: i32, options: &ConnectOptions) -> SC2Result<SharedAPI> {
+	let url = format!("ws://{}:{}/sc2api", host, port);
+
+	let mut backoff = options.backoff;
+	for attempt in 1..=options.max_attempts {
+		match timeout(options.timeout, connect_async(url.as_str())).await {
+			Ok(Ok((ws, _))) => {
+				return Ok(Rs::new(Mutex::new(AsyncAPI {
+					ws,
+					read_timeout: options.read_timeout,
+					observation_pending: false,
+					observation: None,
+				})))
+			}
+			Ok(Err(e)) => debug!("Connection attempt {} failed: {}", attempt, e),
+			Err(_) => debug!("Connection attempt {} timed out", attempt),
+		}
+		if attempt < options.max_attempts {
+			sleep(backoff).await;
+			backoff = options.next_backoff(backoff);
+		}
+	}
+	Err(SC2Error::Timeout(format!(
+		"Can't connect to {} in {} attempts",
+		url, options.max_attempts
+	)))
+}
+
+/// Async version of [`run_vs_computer`](crate::client::run_vs_computer).
+///
+/// Options `record_as`, `pipelined` and `limits` aren't supported,
+/// [`SC2Error::InvalidArgument`] is returned if any of them is set.
+pub async fn run_vs_computer_async<B>(
+	bot: &mut B,
+	computer: Computer,
+	map_name: &str,
+	options: LaunchOptions<'_>,
+) -> SC2Result<()>
+where
+	B: Player,
+{
+	check_options(&options)?;
+	debug!("Starting game vs computer");
+	let sc2_path = get_path_to_sc2()?;
+	let map_path = get_map_path(&sc2_path, map_name)?;
+	let port = get_unused_port();
+
+	debug!("Launching SC2 process");
+	bot.bot().borrow_mut().process = Some(launch_client(
+		&sc2_path,
+		port,
+		options.sc2_version,
+		&options.launcher,
+	)?);
+	debug!("Connecting to websocket");
+	let api = connect_to_websocket_async(HOST, port, &options.connect_options).await?;
+	play_vs_computer_async(bot, api, computer, map_path, options).await
+}
+
+/// Async version of [`RunnerSingle::mock`], plays game vs computer on [`MockServer`] instead of SC2 client.
+/// Options are checked like in [`run_vs_computer_async`].
+///
+/// [`RunnerSingle::mock`]: crate::client::RunnerSingle::mock
+#[cfg(feature = "test-utils")]
+pub async fn run_mock_game_async<B>(
+	bot: &mut B,
+	computer: Computer,
+	server: &MockServer,
+	options: LaunchOptions<'_>,
+) -> SC2Result<()>
+where
+	B: Player,
+{
+	check_options(&options)?;
+	debug!("Connecting to mock server");
+	let api = connect_to_websocket_async(server.host(), server.port(), &options.connect_options).await?;
+	play_vs_computer_async(bot, api, computer, server.map_path(), options).await
+}
+
+// Options which async runners don't support are rejected instead of being silently ignored
+fn check_options(options: &LaunchOptions) -> SC2Result<()> {
+	let unsupported = if options.record_as.is_some() {
+		"record_as"
+	} else if options.pipelined {
+		"pipelined"
+	} else if options.limits.max_game_loop.is_some() || options.limits.max_time.is_some() {
+		"limits"
+	} else {
+		return Ok(());
+	};
+	Err(SC2Error::InvalidArgument(format!(
+		"Option `{}` isn't supported by async runners",
+		unsupported
+	)))
+}
+
+async fn play_vs_computer_async<B>(
+	bot: &mut B,
+	api: SharedAPI,
+	computer: Computer,
+	map_path: String,
+	options: LaunchOptions<'_>,
+) -> SC2Result<()>
+where
+	B: Player,
+{
+	bot.bot().borrow_mut().api = Some(API::shared(Rs::clone(&api)));
+
+	let settings = bot.get_player_settings();
+
+	debug!("Sending CreateGame request");
+	let mut req = Request::new();
+	let req_create_game = req.mut_create_game();
+	req_create_game.mut_local_map().set_map_path(map_path);
+	create_player_setup(&settings, req_create_game);
+	create_computer_setup(&computer, req_create_game);
+	req_create_game.set_realtime(options.realtime);
+
+	let res = api.lock().await.send(req).await?;
+	create_game_result(&res)?;
+
+	debug!("Sending JoinGame request");
+	let res = api.lock().await.send(join_request(&settings, None)).await?;
+	bot.bot().borrow_mut().player_id = join_result(&res)?;
+
+	play_game_async(bot, &api, options.realtime).await?;
+
+	if let Some(path) = options.save_replay_as {
+		save_replay(bot.bot().borrow_mut().api(), path)?;
+	}
+	Ok(())
+}
+
+/// Async version of [`run_ladder_game`](crate::client::run_ladder_game).
+///
+/// Like the sync version, it doesn't record the game, send pipelined requests or check limits.
+pub async fn run_ladder_game_async<B>(
+	bot: &mut B,
+	host: &str,
+	port: &str,
+	player_port: i32,
+	opponent_id: Option<&str>,
+) -> SC2Result<()>
+where
+	B: Player,
+{
+	debug!("Starting ladder game");
+
+	debug!("Connecting to websocket");
+	let api = connect_to_websocket_async(host, port.parse()?, &ConnectOptions::default()).await?;
+	bot.bot().borrow_mut().api = Some(API::shared(Rs::clone(&api)));
+
+	if let Some(id) = opponent_id {
+		bot.bot().borrow_mut().opponent_id = id.to_string();
+	}
+
+	debug!("Sending JoinGame request");
+	let ports = Ports {
+		server: (player_port + 2, player_port + 3),
+		client: vec![(player_port + 4, player_port + 5)],
+	};
+	let req = join_request(&bot.get_player_settings(), Some(&ports));
+	let res = api.lock().await.send(req).await?;
+	bot.bot().borrow_mut().player_id = join_result(&res)?;
+
+	play_game_async(bot, &api, false).await
+}
+
+async fn play_game_async<B>(bot: &mut B, api: &SharedAPI, realtime: bool) -> SC2Result<()>
+where
+	B: Player,
+{
+	set_static_data(&mut bot.bot().borrow_mut())?;
+
+	debug!("Entered main loop");
+	let mut first_step = true;
+	let mut iteration = 0;
+	let req = observation_request(&bot.bot().borrow());
+	let mut res = api.lock().await.send(req).await?;
+	loop {
+		if matches!(res.get_status(), Status::ended) {
+			let result = game_result(&res, bot.bot().borrow().player_id);
+			debug!("Result for bot: {:?}", result);
+			bot.on_end(result)?;
+			break;
+		}
+
+		// In realtime mode game doesn't wait for the bot,
+		// so the next observation is prepared by SC2 while the bot is busy with this one
+		if realtime {
+			let req = observation_request(&bot.bot().borrow());
+			api.lock().await.request_observation(req).await?;
+		}
+
+		if first_step {
+			bot.bot().borrow_mut().init_data_for_unit();
+			update_state(bot, res.get_observation())?;
+			bot.bot().borrow_mut().prepare_start();
+			bot.on_start()?;
+		} else {
+			update_state(bot, res.get_observation())?;
+			bot.bot().borrow_mut().prepare_step();
+			bot.on_step(iteration)?;
+			iteration += 1;
+		}
+
+		let requests = {
+			let bot = bot.bot();
+			let mut bot = bot.borrow_mut();
+			let mut requests = Vec::with_capacity(4);
+			requests.extend(actions_request(&mut bot));
+			if !first_step {
+				requests.extend(debug_request(&mut bot));
+			}
+			if !realtime {
+				requests.push(step_request(&bot));
+				requests.push(observation_request(&bot));
+			}
+			requests
+		};
+		first_step = false;
+
+		// Requests are sent at once and responses awaited after,
+		// so SC2 doesn't wait for the bot between them
+		let mut api = api.lock().await;
+		let count = requests.len();
+		for req in requests {
+			api.send_only(req).await?;
+		}
+		if realtime {
+			for _ in 0..count {
+				api.wait_response().await?;
+			}
+			res = api.take_observation().await?;
+		} else {
+			// The last one is response to observation request
+			for _ in 1..count {
+				api.wait_response().await?;
+			}
+			res = api.wait_response().await?;
+		}
+	}
+	debug!("Game finished");
+
+	Ok(())
+}
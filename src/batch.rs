@@ -0,0 +1,434 @@
+//! Batch runner, which plays series of games vs built-in AI and collects their results.
+//!
+//! Useful for regression testing of the bot: every map is played vs every computer given number of times.
+//!
+//! # Usage
+//! ```no_run
+//! let mut batch = BatchRunner::new(
+//!     &["EverDreamLE", "Ladder2019Season3/AcropolisLE"],
+//!     &[
+//!         Computer::new(Race::Zerg, Difficulty::Hard, None),
+//!         Computer::new(Race::Protoss, Difficulty::VeryHard, Some(AIBuild::Rush)),
+//!     ],
+//!     5,
+//! );
+//! // Games are played on 2 SC2 clients at once
+//! batch.parallel = 2;
+//! batch.replays_dir = Some("replays".to_string());
+//! // Games longer than 30 minutes are counted as ties
+//! batch.limits.max_game_loop = Some(30 * 60 * 22);
+//!
+//! // New bot is created for every game
+//! let summary = batch.run(MyBot::default)?;
+//! println!("Win rate: {:.1}%", summary.win_rate() * 100.0);
+//! summary.save_csv("results.csv")?;
+//! ```
+//!
+//! With `serde` feature [`BatchSummary`] can be serialized to JSON or any other format.
+
+#[cfg(feature = "test-utils")]
+use crate::mock::MockServer;
+use crate::{
+	api::API,
+	client::{ConnectOptions, GameLimits, Launcher, RunnerSingle, SC2Error, SC2Result},
+	player::{Computer, GameResult},
+	process::{close_client, SC2Process},
+	score::Score,
+	Player,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::VecDeque,
+	fmt::Write,
+	fs,
+	path::Path,
+	sync::{mpsc, Arc, Mutex},
+	thread,
+	time::{Duration, Instant},
+};
+
+/// Runner of games on all combinations of maps and computer opponents.
+#[derive(Clone)]
+pub struct BatchRunner {
+	/// Maps to play on, can be names or paths relative to maps folder.
+	pub maps: Vec<String>,
+	/// Computer opponents to play against.
+	pub computers: Vec<Computer>,
+	/// Number of games played on each map vs each opponent.
+	pub repetitions: u32,
+	/// Number of SC2 clients to play games on at once. Default: `1`.
+	pub parallel: usize,
+	/// SC2 version to play on, otherwise latest available will be used.
+	pub sc2_version: Option<String>,
+	/// Play games in real time mode or not. Default: `false`.
+	pub realtime: bool,
+	/// Limits of length of every game, useful to not get stuck in endless games.
+	/// Default: no limits.
+	pub limits: GameLimits,
+	/// Folder to save replays of all games in. Default: `None` (replays aren't saved).
+	pub replays_dir: Option<String>,
+	/// Timeouts and retry policy of connection to SC2 clients.
+	pub connect_options: ConnectOptions,
+	/// Command used to launch SC2 clients.
+	pub launcher: Launcher,
+}
+impl BatchRunner {
+	/// Constructs new batch runner with given maps, opponents and number of repetitions.
+	pub fn new(maps: &[&str], computers: &[Computer], repetitions: u32) -> Self {
+		Self {
+			maps: maps.iter().map(|map| map.to_string()).collect(),
+			computers: computers.to_vec(),
+			repetitions,
+			parallel: 1,
+			sc2_version: None,
+			realtime: false,
+			limits: Default::default(),
+			replays_dir: None,
+			connect_options: Default::default(),
+			launcher: Default::default(),
+		}
+	}
+
+	/// Returns all games of the batch in order they are played.
+	pub fn games(&self) -> Vec<BatchGame> {
+		let count = self.maps.len() * self.computers.len() * self.repetitions as usize;
+		let mut games = Vec::with_capacity(count);
+		for repetition in 0..self.repetitions {
+			for map in &self.maps {
+				for computer in &self.computers {
+					games.push(BatchGame {
+						map: map.clone(),
+						computer: *computer,
+						repetition,
+					});
+				}
+			}
+		}
+		games
+	}
+
+	/// Plays all games of the batch with bots returned by `make_bot`, which is called for every game.
+	///
+	/// Failed games don't stop the batch, their errors are saved in [`GameRecord::error`].
+	/// Clients are reused between games and relaunched if they crash.
+	pub fn run<B, F>(&self, make_bot: F) -> SC2Result<BatchSummary>
+	where
+		B: Player + 'static,
+		F: Fn() -> B + Send + Sync + 'static,
+	{
+		self.run_on(make_bot, Clients::Launch(Mutex::new(())))
+	}
+
+	/// Plays all games of the batch on given [`MockServer`]s instead of SC2 clients.
+	///
+	/// Every server is used for one game, batch must not have more games than servers given.
+	#[cfg(feature = "test-utils")]
+	pub fn run_mock<B, F>(&self, make_bot: F, servers: Vec<MockServer>) -> SC2Result<BatchSummary>
+	where
+		B: Player + 'static,
+		F: Fn() -> B + Send + Sync + 'static,
+	{
+		self.run_on(make_bot, Clients::Mock(Mutex::new(servers.into())))
+	}
+
+	fn run_on<B, F>(&self, make_bot: F, clients: Clients) -> SC2Result<BatchSummary>
+	where
+		B: Player + 'static,
+		F: Fn() -> B + Send + Sync + 'static,
+	{
+		if let Some(dir) = &self.replays_dir {
+			fs::create_dir_all(dir)?;
+		}
+
+		let queue = Arc::new(Mutex::new(
+			self.games().into_iter().enumerate().collect::<VecDeque<_>>(),
+		));
+		let clients = Arc::new(clients);
+		let (sender, receiver) = mpsc::channel();
+		let workers = self.parallel.max(1);
+		if workers == 1 {
+			self.run_worker(&make_bot, &queue, &clients, &sender);
+		} else {
+			let runner = Arc::new(self.clone());
+			let make_bot = Arc::new(make_bot);
+			let handles = (0..workers)
+				.map(|_| {
+					let (runner, make_bot) = (Arc::clone(&runner), Arc::clone(&make_bot));
+					let (queue, clients, sender) = (Arc::clone(&queue), Arc::clone(&clients), sender.clone());
+					thread::spawn(move || runner.run_worker(&*make_bot, &queue, &clients, &sender))
+				})
+				.collect::<Vec<_>>();
+			for handle in handles {
+				handle
+					.join()
+					.map_err(|_| SC2Error::from("Thread of batch runner panicked"))?;
+			}
+		}
+		drop(sender);
+
+		let mut games = receiver.into_iter().collect::<Vec<(usize, GameRecord)>>();
+		games.sort_unstable_by_key(|(i, _)| *i);
+		Ok(BatchSummary {
+			games: games.into_iter().map(|(_, record)| record).collect(),
+		})
+	}
+
+	// Plays games from the queue on one SC2 client until queue is empty
+	fn run_worker<B, F>(
+		&self,
+		make_bot: &F,
+		queue: &Mutex<VecDeque<(usize, BatchGame)>>,
+		clients: &Clients,
+		sender: &mpsc::Sender<(usize, GameRecord)>,
+	) where
+		B: Player,
+		F: Fn() -> B,
+	{
+		let mut client = None;
+		loop {
+			let next = queue.lock().unwrap().pop_front();
+			let (index, game) = match next {
+				Some(next) => next,
+				None => break,
+			};
+			info!(
+				"Starting game {} on {} vs {:?}",
+				index + 1,
+				game.map,
+				game.computer
+			);
+
+			let mut bot = make_bot();
+			let start = Instant::now();
+			let record = match self.play_game(&mut bot, index, &game, clients, &mut client) {
+				Ok(result) => {
+					let shared_bot = bot.bot();
+					let borrowed_bot = shared_bot.borrow();
+					let state = &borrowed_bot.state;
+					GameRecord {
+						game,
+						result,
+						game_loops: state.observation.game_loop,
+						score: state.observation.score.clone(),
+						wall_time: start.elapsed(),
+						error: None,
+					}
+				}
+				Err(e) => {
+					error!("Game {} failed: {}", index + 1, e);
+					GameRecord {
+						game,
+						result: GameResult::Undecided,
+						game_loops: 0,
+						score: Default::default(),
+						wall_time: start.elapsed(),
+						error: Some(e.to_string()),
+					}
+				}
+			};
+			// Receiver lives until all workers finish
+			let _ = sender.send((index, record));
+		}
+
+		// Client left from the last game is quit the same way bot does it
+		if let Some((mut process, mut api)) = client.take() {
+			close_client(Some(&mut api), Some(&mut process));
+		}
+	}
+
+	// Plays the game on client left from previous game if it's healthy, otherwise launches new one.
+	// Client is taken back only if game ended normally, broken one is closed with the bot.
+	fn play_game<B>(
+		&self,
+		bot: &mut B,
+		index: usize,
+		game: &BatchGame,
+		clients: &Clients,
+		client: &mut Option<(SC2Process, API)>,
+	) -> SC2Result<GameResult>
+	where
+		B: Player,
+	{
+		let replay = self.replays_dir.as_ref().map(|dir| {
+			let map = Path::new(&game.map)
+				.file_name()
+				.map_or_else(|| game.map.clone(), |name| name.to_string_lossy().into_owned());
+			format!(
+				"{}/{}_{}_{:?}_{:?}.SC2Replay",
+				dir,
+				index + 1,
+				map,
+				game.computer.race,
+				game.computer.difficulty
+			)
+		});
+
+		let shared_bot = bot.bot();
+		let result = {
+			let mut runner = match clients {
+				Clients::Launch(launch) => {
+					let mut runner =
+						RunnerSingle::new(bot, game.computer, &game.map, self.sc2_version.as_deref())?;
+					runner.connect_options = self.connect_options;
+					runner.launcher = self.launcher.clone();
+					// Taken only after runner is created, so it's kept for next game if creation fails
+					let launched = match client.take() {
+						Some((process, api)) => {
+							let mut borrowed_bot = shared_bot.borrow_mut();
+							borrowed_bot.process = Some(process);
+							borrowed_bot.api = Some(api);
+							true
+						}
+						None => false,
+					};
+					let exit_status = if launched {
+						runner.client_exit_status()
+					} else {
+						None
+					};
+					if let Some(status) = &exit_status {
+						warn!("SC2 client has exited ({}), relaunching it", status);
+					}
+					if !launched || exit_status.is_some() {
+						// Unused port is released before the client binds it,
+						// so concurrent launches could pick the same one
+						let _launch = launch.lock().unwrap();
+						runner.launch()?;
+					}
+					runner
+				}
+				#[cfg(feature = "test-utils")]
+				Clients::Mock(servers) => {
+					let server = servers
+						.lock()
+						.unwrap()
+						.pop_front()
+						.ok_or_else(|| SC2Error::from("No mock servers left for the game"))?;
+					RunnerSingle::mock(bot, game.computer, &server)?
+				}
+			};
+			runner.realtime = self.realtime;
+			runner.limits = self.limits;
+			runner.save_replay_as = replay.as_deref();
+			runner.run_game()?
+		};
+
+		let mut borrowed_bot = shared_bot.borrow_mut();
+		if let (Some(process), Some(api)) = (borrowed_bot.process.take(), borrowed_bot.api.take()) {
+			*client = Some((process, api));
+		}
+		Ok(result)
+	}
+}
+
+// Where workers get SC2 clients from
+enum Clients {
+	// Launched by workers one at a time
+	Launch(Mutex<()>),
+	// Taken from the pool, one per game
+	#[cfg(feature = "test-utils")]
+	Mock(Mutex<VecDeque<MockServer>>),
+}
+
+/// Game of the batch.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BatchGame {
+	/// Map game is played on.
+	pub map: String,
+	/// Computer opponent.
+	pub computer: Computer,
+	/// Number of repetition of this map and opponent, starting from `0`.
+	pub repetition: u32,
+}
+
+/// Results of the game in batch.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone)]
+pub struct GameRecord {
+	/// Played game.
+	pub game: BatchGame,
+	/// Result of the bot, [`Undecided`](GameResult::Undecided) if game failed.
+	pub result: GameResult,
+	/// Length of the game in game loops.
+	pub game_loops: u32,
+	/// Score of the bot on the last step.
+	pub score: Score,
+	/// Real time taken by the game, including launch of SC2 client.
+	pub wall_time: Duration,
+	/// Error occured during the game.
+	pub error: Option<String>,
+}
+
+const CSV_HEADER: &str = "map,race,difficulty,ai_build,repetition,result,game_loops,score,\
+	collected_minerals,collected_vespene,killed_value_units,killed_value_structures,wall_time,error";
+
+/// Results of all games in batch.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Default)]
+pub struct BatchSummary {
+	/// Records of games in order they were scheduled.
+	pub games: Vec<GameRecord>,
+}
+impl BatchSummary {
+	/// Returns number of games with given result.
+	pub fn count(&self, result: GameResult) -> usize {
+		self.games.iter().filter(|g| g.result == result).count()
+	}
+	/// Returns number of games failed with error.
+	pub fn errors(&self) -> usize {
+		self.games.iter().filter(|g| g.error.is_some()).count()
+	}
+	/// Returns part of won games from `0` to `1`.
+	pub fn win_rate(&self) -> f32 {
+		if self.games.is_empty() {
+			return 0.0;
+		}
+		self.count(GameResult::Victory) as f32 / self.games.len() as f32
+	}
+
+	/// Formats results as CSV table with header, one line per game.
+	pub fn to_csv(&self) -> String {
+		let mut csv = format!("{}\n", CSV_HEADER);
+		for g in &self.games {
+			let s = &g.score;
+			let _ = writeln!(
+				csv,
+				"{},{:?},{:?},{},{},{:?},{},{},{},{},{},{},{:.3},{}",
+				csv_field(&g.game.map),
+				g.game.computer.race,
+				g.game.computer.difficulty,
+				g.game
+					.computer
+					.ai_build
+					.map_or_else(String::new, |b| format!("{:?}", b)),
+				g.game.repetition,
+				g.result,
+				g.game_loops,
+				s.total_score,
+				s.collected_minerals,
+				s.collected_vespene,
+				s.killed_value_units,
+				s.killed_value_structures,
+				g.wall_time.as_secs_f32(),
+				g.error.as_deref().map_or_else(String::new, csv_field),
+			);
+		}
+		csv
+	}
+
+	/// Saves results to CSV file in given path.
+	pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> SC2Result<()> {
+		fs::write(path, self.to_csv())?;
+		Ok(())
+	}
+}
+
+fn csv_field(s: &str) -> String {
+	if s.contains(&[',', '"', '\n', '\r'][..]) {
+		format!("\"{}\"", s.replace('"', "\"\""))
+	} else {
+		s.to_string()
+	}
+}
@@ -1,9 +1,9 @@
 //! [`Bot`] struct and it's helpers.
 
 use crate::{
-	action::{Action, ActionResult, Commander, Target},
+	action::{Action, ActionOutcome, ActionResult, Commander, Target},
 	api::API,
-	client::SC2Result,
+	client::{SC2Result, StepStats},
 	consts::{RaceValues, INHIBITOR_IDS, RACE_VALUES, TECH_ALIAS, UNIT_ALIAS},
 	debug::{DebugCommand, Debugger},
 	distance::*,
@@ -12,22 +12,24 @@ use crate::{
 	game_state::Effect,
 	game_state::{Alliance, GameState},
 	geometry::Point2,
-	ids::{AbilityId, EffectId, UnitTypeId, UpgradeId},
+	ids::{AbilityId, DataVersion, EffectId, UnitTypeId, UpgradeId},
+	pathfinding::PathingGrid,
 	player::Race,
+	process::{close_client, SC2Process},
 	ramp::{Ramp, Ramps},
+	regions::Regions,
 	unit::{DataForUnit, SharedUnitData, Unit},
 	units::{AllUnits, Units},
 	utils::{dbscan, range_query},
 	FromProto, IntoProto,
 };
-use num_traits::ToPrimitive;
 use rand::prelude::{thread_rng, SliceRandom};
 use rustc_hash::{FxHashMap, FxHashSet};
 use sc2_proto::{
 	query::{RequestQueryBuildingPlacement, RequestQueryPathing},
 	sc2api::Request,
 };
-use std::process::Child;
+use std::mem;
 
 #[cfg(feature = "enemies_cache")]
 use crate::{consts::BURROWED_IDS, unit::DisplayType};
@@ -336,10 +338,12 @@ impl Default for Completion {
 /// [`DerefMut`]: std::ops::DerefMut
 /// [b]: macro@crate::bot
 pub struct Bot {
-	pub(crate) process: Option<Child>,
+	pub(crate) process: Option<SC2Process>,
 	pub(crate) api: Option<API>,
 	pub(crate) game_step: Rs<LockU32>,
 	pub(crate) allow_spam: Rs<LockBool>,
+	pub(crate) step_stats: StepStats,
+	pub(crate) data_version: DataVersion,
 	#[doc(hidden)]
 	pub disable_fog: bool,
 	/// Actual race of your bot.
@@ -353,6 +357,8 @@ pub struct Bot {
 	/// Opponent id on ladder, filled in `--OpponentId`.
 	pub opponent_id: String,
 	actions: Vec<Action>,
+	sent_actions: Vec<Action>,
+	pub(crate) action_results: Vec<ActionOutcome>,
 	commander: Rw<Commander>,
 	/// Debug API
 	pub debug: Debugger,
@@ -407,6 +413,9 @@ pub struct Bot {
 	pub vision_blockers: Vec<Point2>,
 	/// Ramps on map.
 	pub ramps: Ramps,
+	// Grid regions are computed on, with resources as they were at start of the game
+	regions_grid: Option<PathingGrid>,
+	regions: Rw<Option<Rs<Regions>>>,
 	enemy_upgrades: Rw<FxHashSet<UpgradeId>>,
 	pub(crate) owned_tags: FxHashSet<u64>,
 	pub(crate) under_construction: FxHashSet<u64>,
@@ -430,6 +439,20 @@ impl Bot {
 	pub fn game_step(&self) -> u32 {
 		self.game_step.get_locked()
 	}
+	/// Returns immediate results of actions sent on current step, in order they were sent.
+	///
+	/// Failed actions are also reported with [`Event::ActionFailed`](crate::Event::ActionFailed).
+	pub fn action_results(&self) -> &[ActionOutcome] {
+		&self.action_results
+	}
+	/// Returns timings of game steps, useful to measure performance of the bot and connection to SC2.
+	pub fn step_stats(&self) -> &StepStats {
+		&self.step_stats
+	}
+	/// Returns version of game data used by connected client, determines raw ids sent to SC2.
+	pub fn data_version(&self) -> DataVersion {
+		self.data_version
+	}
 	/// Sets to `true`, allows units to forcibly execute commands, ignoring spam filter.
 	pub fn set_allow_spam(&self, val: bool) {
 		self.allow_spam.set_locked(val);
@@ -475,6 +498,21 @@ impl Bot {
 	pub fn enemy_counter(&self) -> CountOptions {
 		CountOptions::new(self, true)
 	}
+	/// Regions of map and chokes between them.
+	///
+	/// Splitting map takes a while, so it's done on first call and then cached until the next game.
+	/// Resources block pathing as they were at start of the game.
+	pub fn regions(&self) -> Rs<Regions> {
+		if let Some(regions) = &*self.regions.read_lock() {
+			return Rs::clone(regions);
+		}
+		let regions = Rs::new(match &self.regions_grid {
+			Some(grid) => Regions::new(grid, &self.expansions, &self.ramps.all),
+			None => Default::default(),
+		});
+		*self.regions.write_lock() = Some(Rs::clone(&regions));
+		regions
+	}
 	pub(crate) fn get_actions(&mut self) -> &[Action] {
 		let actions = &mut self.actions;
 
@@ -501,8 +539,12 @@ impl Bot {
 
 		actions
 	}
+	// Sent actions are saved to match them with results later
 	pub(crate) fn clear_actions(&mut self) {
-		self.actions.clear();
+		self.sent_actions = mem::take(&mut self.actions);
+	}
+	pub(crate) fn take_sent_actions(&mut self) -> Vec<Action> {
+		mem::take(&mut self.sent_actions)
 	}
 	pub(crate) fn get_debug_commands(&mut self) -> &[DebugCommand] {
 		self.debug.get_commands()
@@ -765,6 +807,7 @@ impl Bot {
 			creep: Rs::clone(&self.state.observation.raw.creep),
 			game_step: Rs::clone(&self.game_step),
 			allow_spam: Rs::clone(&self.allow_spam),
+			data_version: self.data_version,
 		});
 	}
 	pub(crate) fn prepare_start(&mut self) {
@@ -950,6 +993,18 @@ impl Bot {
 		}
 
 		self.ramps.all = ramps;
+
+		// Regions are split on first request, resources block pathing until mined out
+		// and townhalls at start locations don't
+		let mut grid = PathingGrid::new(&self.game_info.pathing_grid);
+		for r in &self.units.resources {
+			grid.block_unit(r);
+		}
+		for (location, _) in &self.expansions {
+			grid.set_rect(*location, (5, 5), true);
+		}
+		self.regions_grid = Some(grid);
+		*self.regions.write_lock() = None;
 	}
 	pub(crate) fn prepare_step(&mut self) {
 		let observation = &self.state.observation;
@@ -1385,27 +1440,27 @@ impl Bot {
 
 	/// Simple wrapper around [`query_placement`](Self::query_placement).
 	/// Checks if it's possible to build given building on given position.
-	pub fn can_place(&mut self, building: UnitTypeId, pos: Point2) -> bool {
-		self.query_placement(
+	pub fn can_place(&mut self, building: UnitTypeId, pos: Point2) -> SC2Result<bool> {
+		let results = self.query_placement(
 			vec![(self.game_data.units[&building].ability.unwrap(), pos, None)],
 			false,
-		)
-		.unwrap()[0] == ActionResult::Success
+		)?;
+		Ok(results[0] == ActionResult::Success)
 	}
 	/// Simple wrapper around [`query_placement`](Self::query_placement).
 	/// Multi-version of [`can_place`](Self::can_place).
-	pub fn can_place_some(&mut self, places: Vec<(UnitTypeId, Point2)>) -> Vec<bool> {
-		self.query_placement(
-			places
-				.into_iter()
-				.map(|(building, pos)| (self.game_data.units[&building].ability.unwrap(), pos, None))
-				.collect(),
-			false,
-		)
-		.unwrap()
-		.into_iter()
-		.map(|r| r == ActionResult::Success)
-		.collect()
+	pub fn can_place_some(&mut self, places: Vec<(UnitTypeId, Point2)>) -> SC2Result<Vec<bool>> {
+		Ok(self
+			.query_placement(
+				places
+					.into_iter()
+					.map(|(building, pos)| (self.game_data.units[&building].ability.unwrap(), pos, None))
+					.collect(),
+				false,
+			)?
+			.into_iter()
+			.map(|r| r == ActionResult::Success)
+			.collect())
 	}
 
 	/// Nice wrapper around [`query_placement`](Self::query_placement).
@@ -1416,7 +1471,7 @@ impl Bot {
 		building: UnitTypeId,
 		near: Point2,
 		options: PlacementOptions,
-	) -> Option<Point2> {
+	) -> SC2Result<Option<Point2>> {
 		if let Some(data) = self.game_data.units.get(&building) {
 			if let Some(ability) = data.ability {
 				let addon = options.addon;
@@ -1431,12 +1486,11 @@ impl Bot {
 							vec![(ability, near, None)]
 						},
 						false,
-					)
-					.unwrap()
+					)?
 					.iter()
 					.all(|r| matches!(r, ActionResult::Success))
 				{
-					return Some(near);
+					return Ok(Some(near));
 				}
 
 				let placement_step = options.step;
@@ -1453,8 +1507,7 @@ impl Bot {
 						})
 						.collect::<Vec<Point2>>();
 					let results = self
-						.query_placement(positions.iter().map(|pos| (ability, *pos, None)).collect(), false)
-						.unwrap();
+						.query_placement(positions.iter().map(|pos| (ability, *pos, None)).collect(), false)?;
 
 					let mut valid_positions = positions
 						.iter()
@@ -1478,8 +1531,7 @@ impl Bot {
 									})
 									.collect(),
 								false,
-							)
-							.unwrap();
+							)?;
 						valid_positions = valid_positions
 							.into_iter()
 							.zip(results.into_iter())
@@ -1489,42 +1541,40 @@ impl Bot {
 					}
 
 					if !valid_positions.is_empty() {
-						return if options.random {
+						return Ok(if options.random {
 							valid_positions.choose(&mut thread_rng()).copied()
 						} else {
 							valid_positions.iter().closest(near).copied()
-						};
+						});
 					}
 				}
 			}
 		}
-		None
+		Ok(None)
 	}
 	/// Another wrapper around [`query_placement`](Self::query_placement),
 	/// used to find free geyser near given base.
 	///
 	/// Returns `Unit` of geyser or `None` if there're no free geysers around given base.
-	pub fn find_gas_placement(&mut self, base: Point2) -> Option<Unit> {
+	pub fn find_gas_placement(&mut self, base: Point2) -> SC2Result<Option<Unit>> {
 		let ability = self.game_data.units[&self.race_values.gas].ability.unwrap();
 
 		let geysers = self.units.vespene_geysers.closer(11.0, base);
-		let results = self
-			.query_placement(
-				geysers.iter().map(|u| (ability, u.position, None)).collect(),
-				false,
-			)
-			.unwrap();
+		let results = self.query_placement(
+			geysers.iter().map(|u| (ability, u.position, None)).collect(),
+			false,
+		)?;
 
-		geysers
+		Ok(geysers
 			.into_iter()
 			.zip(results.into_iter())
 			.find(|(_, res)| *res == ActionResult::Success)
-			.map(|(geyser, _)| geyser)
+			.map(|(geyser, _)| geyser))
 	}
 
 	/// Returns next possible location from [`expansions`](Self::expansions) closest to bot's start location
 	/// or `None` if there aren't any free locations.
-	pub fn get_expansion(&mut self) -> Option<(Point2, Point2)> {
+	pub fn get_expansion(&mut self) -> SC2Result<Option<(Point2, Point2)>> {
 		let expansions = self
 			.expansions
 			.iter()
@@ -1534,46 +1584,42 @@ impl Bot {
 			})
 			.copied()
 			.collect::<Vec<(Point2, Point2)>>();
-		let paths = self
-			.query_pathing(
-				expansions
-					.iter()
-					.map(|(loc, _)| (Target::Pos(self.start_location), *loc))
-					.collect(),
-			)
-			.unwrap();
+		let paths = self.query_pathing(
+			expansions
+				.iter()
+				.map(|(loc, _)| (Target::Pos(self.start_location), *loc))
+				.collect(),
+		)?;
 
-		expansions
+		Ok(expansions
 			.into_iter()
 			.zip(paths.into_iter())
 			.filter_map(|(loc, path)| Some((loc, path?)))
 			.min_by(|(_, path1), (_, path2)| path1.partial_cmp(&path2).unwrap())
-			.map(|(loc, _)| loc)
+			.map(|(loc, _)| loc))
 	}
 	/// Returns next possible location from [`expansions`](Self::expansions) closest to
 	/// opponent's start location or `None` if there aren't any free locations.
-	pub fn get_enemy_expansion(&mut self) -> Option<(Point2, Point2)> {
+	pub fn get_enemy_expansion(&mut self) -> SC2Result<Option<(Point2, Point2)>> {
 		let expansions = self
 			.expansions
 			.iter()
 			.filter(|(loc, _)| self.units.enemy.townhalls.iter().all(|t| t.is_further(7.0, *loc)))
 			.copied()
 			.collect::<Vec<(Point2, Point2)>>();
-		let paths = self
-			.query_pathing(
-				expansions
-					.iter()
-					.map(|(loc, _)| (Target::Pos(self.enemy_start), *loc))
-					.collect(),
-			)
-			.unwrap();
+		let paths = self.query_pathing(
+			expansions
+				.iter()
+				.map(|(loc, _)| (Target::Pos(self.enemy_start), *loc))
+				.collect(),
+		)?;
 
-		expansions
+		Ok(expansions
 			.into_iter()
 			.zip(paths.into_iter())
 			.filter_map(|(loc, path)| Some((loc, path?)))
 			.min_by(|(_, path1), (_, path2)| path1.partial_cmp(&path2).unwrap())
-			.map(|(loc, _)| loc)
+			.map(|(loc, _)| loc))
 	}
 	/// Returns all [`expansions`](Self::expansions) taken by bot.
 	pub fn owned_expansions(&self) -> Vec<(Point2, Point2)> {
@@ -1658,9 +1704,10 @@ impl Bot {
 		req_query.set_ignore_resource_requirements(!check_resources);
 		let req_placement = req_query.mut_placements();
 
+		let version = self.data_version;
 		places.iter().for_each(|(ability, pos, builder)| {
 			let mut placement = RequestQueryBuildingPlacement::new();
-			placement.set_ability_id(ability.to_i32().unwrap());
+			placement.set_ability_id(ability.to_raw(version) as i32);
 			placement.set_target_pos(pos.into_proto());
 			if let Some(tag) = builder {
 				placement.set_placing_unit_tag(*tag);
@@ -1689,26 +1736,56 @@ impl Bot {
 		self.api().send_request(req)
 	}
 
-	pub(crate) fn close_client(&mut self) {
-		if let Some(api) = &mut self.api {
-			let mut req = Request::new();
-			req.mut_leave_game();
-			if let Err(e) = api.send_request(req) {
-				error!("Request LeaveGame failed: {}", e);
-			}
-
-			let mut req = Request::new();
-			req.mut_quit();
-			if let Err(e) = api.send_request(req) {
-				error!("Request QuitGame failed: {}", e);
-			}
-		}
+	/// Saves current state of the game, so it can be restored later with [`quick_load`](Self::quick_load).
+	///
+	/// Works only in single player games, only the last save is kept.
+	pub fn quick_save(&mut self) -> SC2Result<()> {
+		let mut req = Request::new();
+		req.mut_quick_save();
+		self.api().send_request(req)
+	}
+	/// Restores state of the game saved with [`quick_save`](Self::quick_save).
+	///
+	/// Cached data of the bot, which isn't valid in restored game, is cleared
+	/// and new state will be received on the next step.
+	pub fn quick_load(&mut self) -> SC2Result<()> {
+		let mut req = Request::new();
+		req.mut_quick_load();
+		self.api().send_request(req)?;
+		self.reset_cache();
+		Ok(())
+	}
+	/// Restarts the game from the beginning, works only in single player games.
+	///
+	/// Cached data of the bot is cleared the same way as in [`quick_load`](Self::quick_load),
+	/// but [`on_start`] isn't called again.
+	///
+	/// [`on_start`]: crate::Player::on_start
+	pub fn restart_game(&mut self) -> SC2Result<()> {
+		let mut req = Request::new();
+		req.mut_restart_game();
+		self.api().send_request(req)?;
+		self.reset_cache();
+		Ok(())
+	}
+	// Clears data collected over previous steps, used when game state is changed not by the game itself
+	fn reset_cache(&mut self) {
+		#[cfg(feature = "enemies_cache")]
+		self.units.cached.clear();
+		self.enemy_upgrades.write_lock().clear();
+		self.last_units_health.write_lock().clear();
+		self.saved_hallucinations.clear();
+		self.owned_tags.clear();
+		self.under_construction.clear();
+		// Commands given before loading aren't relevant anymore
+		self.actions.clear();
+		let mut commander = self.commander.write_lock();
+		commander.commands.clear();
+		commander.autocast.clear();
+	}
 
-		if let Some(process) = &mut self.process {
-			if let Err(e) = process.kill() {
-				error!("Can't kill SC2 process: {}", e);
-			}
-		}
+	pub(crate) fn close_client(&mut self) {
+		close_client(self.api.as_mut(), self.process.as_mut());
 	}
 }
 
@@ -1722,10 +1799,14 @@ impl Default for Bot {
 			process: None,
 			api: None,
 			allow_spam: Default::default(),
+			step_stats: Default::default(),
+			data_version: Default::default(),
 			player_id: Default::default(),
 			enemy_player_id: Default::default(),
 			opponent_id: Default::default(),
 			actions: Default::default(),
+			sent_actions: Default::default(),
+			action_results: Default::default(),
 			commander: Default::default(),
 			debug: Default::default(),
 			game_info: Default::default(),
@@ -1756,6 +1837,8 @@ impl Default for Bot {
 			last_units_health: Default::default(),
 			vision_blockers: Default::default(),
 			ramps: Default::default(),
+			regions_grid: Default::default(),
+			regions: Default::default(),
 			enemy_upgrades: Default::default(),
 			owned_tags: Default::default(),
 			under_construction: Default::default(),
@@ -4,30 +4,49 @@
 //! and simple runner functions for playing once.
 
 use crate::{
+	action::{ActionOutcome, ActionResult},
 	api::API,
 	bot::{Bot, LockOwned, Rs},
 	game_state::update_state,
+	ids::DataVersion,
 	paths::*,
-	player::Computer,
-	IntoProto, IntoSC2, Player, PlayerSettings,
+	player::{Computer, GameResult},
+	process::{close_client, SC2Process},
+	Event, FromProto, IntoProto, IntoSC2, Player, PlayerSettings, SpatialOptions,
+};
+use protobuf::ProtobufError;
+use sc2_proto::sc2api::{
+	InterfaceOptions, PlayerSetup, PlayerType, PortSet, Request, RequestCreateGame, Response,
+	SpatialCameraSetup, Status,
 };
-use sc2_proto::sc2api::{PlayerSetup, PlayerType, PortSet, Request, RequestCreateGame, Status};
 use std::{
+	env,
 	error::Error,
 	fmt,
 	fs::File,
-	io::Write,
-	net::TcpListener,
+	io::{self, Write},
+	net::{TcpListener, TcpStream, ToSocketAddrs},
+	num::ParseIntError,
 	ops::{Deref, DerefMut},
-	process::{Child, Command},
+	path::Path,
+	process::{Command, ExitStatus},
+	thread,
+	time::{Duration, Instant},
+};
+use tungstenite::{
+	client::{client, AutoStream},
+	stream::Stream,
+	WebSocket,
 };
-use tungstenite::{client::AutoStream, connect, WebSocket};
 use url::Url;
 
+#[cfg(feature = "test-utils")]
+use crate::mock::MockServer;
+
 pub(crate) type WS = WebSocket<AutoStream>;
-pub type SC2Result<T> = Result<T, Box<dyn Error>>;
+pub type SC2Result<T> = Result<T, SC2Error>;
 
-const HOST: &str = "127.0.0.1";
+pub(crate) const HOST: &str = "127.0.0.1";
 const SC2_BINARY: &str = {
 	#[cfg(target_os = "windows")]
 	{
@@ -94,6 +113,29 @@ where
 	pub realtime: bool,
 	/// Save replay after the game in given path.
 	pub save_replay_as: Option<&'a str>,
+	/// Record API protocol stream of the game in given path.
+	/// Recording can be played back with [`run_recorded_game`].
+	///
+	/// Not supported by async runners, they return [`SC2Error::InvalidArgument`] if it's set.
+	pub record_as: Option<&'a str>,
+	/// Send actions, debug and step requests at once and request next observation together with them,
+	/// instead of waiting for every response separately. Default: `false`.
+	///
+	/// Stats of steps are available in [`step_stats`](Bot::step_stats).
+	///
+	/// Not supported by async runners, they return [`SC2Error::InvalidArgument`] if it's set.
+	pub pipelined: bool,
+	/// Limits of game length and what to do when they're reached. Default: no limits.
+	///
+	/// Not supported by async runners, they return [`SC2Error::InvalidArgument`] if any limit is set.
+	pub limits: GameLimits,
+	/// Timeouts and retry policy of connection to SC2 client.
+	pub connect_options: ConnectOptions,
+	/// Command used to launch SC2 client.
+	pub launcher: Launcher,
+	/// Relaunch SC2 client before the game if it has exited (e.g. crashed after previous game).
+	/// Default: `true`.
+	pub relaunch: bool,
 }
 
 impl<'a, B> RunnerSingle<'a, B>
@@ -101,38 +143,114 @@ where
 	B: Player
 {
 	/// Constructs new single player runner.
-	pub fn new(bot: &'a mut B, computer: Computer, map: &str, sc2_version: Option<&'a str>) -> Self {
+	///
+	/// Returns error if SC2 installation or given map not found.
+	pub fn new(
+		bot: &'a mut B,
+		computer: Computer,
+		map: &str,
+		sc2_version: Option<&'a str>,
+	) -> SC2Result<Self> {
 		debug!("Starting game vs computer");
-		let sc2_path = get_path_to_sc2();
-		let map_path = get_map_path(&sc2_path, map);
+		let sc2_path = get_path_to_sc2()?;
+		let map_path = get_map_path(&sc2_path, map)?;
 
-		Self {
+		Ok(Self {
 			bot,
 			sc2_path,
 			sc2_version,
 			computer,
 			map_path,
 			save_replay_as: None,
+			record_as: None,
 			realtime: false,
-		}
+			pipelined: false,
+			limits: Default::default(),
+			connect_options: Default::default(),
+			launcher: Default::default(),
+			relaunch: true,
+		})
+	}
+
+	/// Constructs new single player runner, connected to the [`MockServer`] instead of SC2 client.
+	///
+	/// [`MockServer`]: crate::mock::MockServer
+	#[cfg(feature = "test-utils")]
+	pub fn mock(bot: &'a mut B, computer: Computer, server: &MockServer) -> SC2Result<Self> {
+		debug!("Starting game vs computer on mock server");
+		debug!("Connecting to websocket");
+		let connect_options = ConnectOptions::default();
+		bot.bot().borrow_mut().api = Some(API::new(connect_to_websocket(
+			server.host(),
+			server.port(),
+			&connect_options,
+			None,
+		)?));
+
+		Ok(Self {
+			bot,
+			sc2_path: String::new(),
+			sc2_version: None,
+			computer,
+			map_path: server.map_path(),
+			save_replay_as: None,
+			record_as: None,
+			realtime: false,
+			pipelined: false,
+			limits: Default::default(),
+			connect_options,
+			launcher: Default::default(),
+			relaunch: true,
+		})
 	}
 
 	/// Launches SC2 client and connects bot to the API.
 	pub fn launch(&mut self) -> SC2Result<()> {
 		let port = get_unused_port();
+		let mut bot = self.bot.bot().borrow_mut();
 		debug!("Launching SC2 process");
-		self.bot.bot().borrow_mut().process = Some(launch_client(&self.sc2_path, port, self.sc2_version)?);
+		bot.process = Some(launch_client(
+			&self.sc2_path,
+			port,
+			self.sc2_version,
+			&self.launcher,
+		)?);
 		debug!("Connecting to websocket");
-		self.bot.bot().borrow_mut().api = Some(API(connect_to_websocket(HOST, port)?));
+		let ws = connect_to_websocket(HOST, port, &self.connect_options, bot.process.as_mut())?;
+		bot.api = Some(API::new(ws));
 		Ok(())
 	}
 
-	/// Runs requested game.
-	pub fn run_game(&mut self) -> SC2Result<()> {
+	/// Runs requested game, returns result of the bot.
+	///
+	/// If SC2 client crashes during the game, returns [`SC2Error::ProcessExited`].
+	pub fn run_game(&mut self) -> SC2Result<GameResult> {
+		if self.relaunch {
+			if let Some(status) = self.client_exit_status() {
+				warn!("SC2 client has exited ({}), relaunching it", status);
+				self.launch()?;
+			}
+		}
+		self.play_game().map_err(|e| match e {
+			SC2Error::WebSocket(_) | SC2Error::ConnectionClosed | SC2Error::Timeout(_) => {
+				match self.client_exit_status() {
+					Some(status) => SC2Error::ProcessExited(status),
+					None => e,
+				}
+			}
+			e => e,
+		})
+	}
+
+	fn play_game(&mut self) -> SC2Result<GameResult> {
 		let settings = self.bot.get_player_settings();
 		let bot = self.bot.bot();
 		let mut borrowed_bot = bot.borrow_mut();
 
+		if let Some(path) = self.record_as {
+			borrowed_bot.api().start_recording(path)?;
+		}
+
 		debug!("Sending CreateGame request");
 		let mut req = Request::new();
 		let req_create_game = req.mut_create_game();
@@ -146,16 +264,7 @@ where
 		req_create_game.set_realtime(self.realtime);
 
 		let res = borrowed_bot.api().send(req)?;
-		let res_create_game = res.get_create_game();
-		if res_create_game.has_error() {
-			let err = format!(
-				"{:?}: {}",
-				res_create_game.get_error(),
-				res_create_game.get_error_details()
-			);
-			error!("{}", err);
-			panic!(err);
-		}
+		create_game_result(&res)?;
 
 		debug!("Sending JoinGame request");
 		borrowed_bot.player_id = join_game(&settings, borrowed_bot.api(), None)?;
@@ -164,25 +273,79 @@ where
 
 		debug!("Entered main loop");
 		drop(borrowed_bot);
-		play_first_step(self.bot, self.realtime)?;
-		let mut iteration = 0;
-		while play_step(self.bot, iteration, self.realtime)? {
-			iteration += 1;
-		}
+		self.play_to_end()
+	}
+
+	// Plays the game from current state to the end, then saves replay and leaves the game if needed
+	fn play_to_end(&mut self) -> SC2Result<GameResult> {
+		let (result, leave) = play_game_loop(self.bot, self.realtime, self.pipelined, &self.limits)?;
 		info!("Game finished!");
 
+		let bot = self.bot.bot();
+		let mut borrowed_bot = bot.borrow_mut();
+		if self.record_as.is_some() {
+			borrowed_bot.api().stop_recording();
+		}
 		if let Some(path) = &self.save_replay_as {
-			save_replay(bot.borrow_mut().api(), &path)?;
+			save_replay(borrowed_bot.api(), &path)?;
 		}
-		Ok(())
+		if leave {
+			borrowed_bot.leave()?;
+		}
+		Ok(result)
+	}
+
+	/// Restarts the last game from the beginning and plays it again, returns result of the bot.
+	///
+	/// The game must not be left after the end, i.e. it shouldn't be stopped by [`limits`](Self::limits)
+	/// with [`LimitAction::Leave`] or [`LimitAction::Tie`].
+	/// Cached data of the bot is cleared (see [`Bot::restart_game`]) and [`on_start`] is called again.
+	///
+	/// [`on_start`]: Player::on_start
+	pub fn restart_game(&mut self) -> SC2Result<GameResult> {
+		debug!("Sending RestartGame request");
+		self.bot.bot().borrow_mut().restart_game()?;
+		self.play_to_end()
+	}
+
+	/// Restores state of the last game saved with [`Bot::quick_save`] or [`quick_save`]
+	/// and plays it again from there, returns result of the bot.
+	///
+	/// The same as [`restart_game`](Self::restart_game), but continues from the saved state.
+	///
+	/// [`quick_save`]: Self::quick_save
+	pub fn quick_load(&mut self) -> SC2Result<GameResult> {
+		debug!("Sending QuickLoad request");
+		self.bot.bot().borrow_mut().quick_load()?;
+		self.play_to_end()
+	}
+
+	/// Saves current state of the game, which can be restored with [`quick_load`](Self::quick_load).
+	///
+	/// Runner can save only the state at the end of the game, use [`Bot::quick_save`]
+	/// to save state during the game (e.g. after scenario is set up in [`on_start`]).
+	///
+	/// [`on_start`]: Player::on_start
+	pub fn quick_save(&mut self) -> SC2Result<()> {
+		debug!("Sending QuickSave request");
+		self.bot.bot().borrow_mut().quick_save()
 	}
 
 	/// Changes map to play on.
 	///
-	/// # Panics
-	/// Panics if the map doesn't exist in maps directory.
-	pub fn set_map(&mut self, map: &str) {
-		self.map_path = get_map_path(&self.sc2_path, map);
+	/// Map can be a path relative to maps directory (e.g. `Ladder2019Season3/AcropolisLE`)
+	/// or just a name, which is searched in all sub-directories (see [`get_map_path`]).
+	///
+	/// Returns error if the map doesn't exist in maps directory.
+	pub fn set_map(&mut self, map: &str) -> SC2Result<()> {
+		self.map_path = get_map_path(&self.sc2_path, map)?;
+		Ok(())
+	}
+
+	/// Returns exit status of SC2 client if it has exited (e.g. crashed),
+	/// or `None` if it's still running or wasn't launched.
+	pub fn client_exit_status(&mut self) -> Option<ExitStatus> {
+		self.bot.bot().borrow_mut().process.as_mut()?.exit_status()
 	}
 
 	/// Manually closes SC2 client.
@@ -207,6 +370,26 @@ where
 	pub realtime: bool,
 	/// Save replay after the game in given path.
 	pub save_replay_as: Option<&'a str>,
+	/// Record API protocol stream of the game in given path.
+	/// Recording can be played back with [`run_recorded_game`].
+	///
+	/// Not supported by async runners, they return [`SC2Error::InvalidArgument`] if it's set.
+	pub record_as: Option<&'a str>,
+	/// Send actions, debug and step requests at once and request next observation together with them,
+	/// instead of waiting for every response separately. Default: `false`.
+	///
+	/// Stats of steps are available in [`step_stats`](Bot::step_stats).
+	///
+	/// Not supported by async runners, they return [`SC2Error::InvalidArgument`] if it's set.
+	pub pipelined: bool,
+	/// Limits of game length and what to do when they're reached. Default: no limits.
+	///
+	/// Not supported by async runners, they return [`SC2Error::InvalidArgument`] if any limit is set.
+	pub limits: GameLimits,
+	/// Timeouts and retry policy of connection to SC2 clients.
+	pub connect_options: ConnectOptions,
+	/// Command used to launch SC2 clients.
+	pub launcher: Launcher,
 }
 
 impl<'a, B> RunnerMulti<'a, B>
@@ -214,17 +397,19 @@ where
 	B: Player + DerefMut<Target = Bot> + Deref<Target = Bot>,
 {
 	/// Constructs new multi player runner.
+	///
+	/// Returns error if SC2 installation or given map not found.
 	pub fn new(
 		bot: &'a mut B,
 		human_settings: PlayerSettings,
 		map: &str,
 		sc2_version: Option<&'a str>,
-	) -> Self {
+	) -> SC2Result<Self> {
 		debug!("Starting human vs bot");
-		let sc2_path = get_path_to_sc2();
-		let map_path = get_map_path(&sc2_path, map);
+		let sc2_path = get_path_to_sc2()?;
+		let map_path = get_map_path(&sc2_path, map)?;
 
-		Self {
+		Ok(Self {
 			bot,
 			human: Human::default(),
 			sc2_path,
@@ -232,8 +417,13 @@ where
 			human_settings,
 			map_path,
 			save_replay_as: None,
+			record_as: None,
 			realtime: false,
-		}
+			pipelined: false,
+			limits: Default::default(),
+			connect_options: Default::default(),
+			launcher: Default::default(),
+		})
 	}
 
 	/// Launches SC2 clients and connects bot to the API.
@@ -243,14 +433,31 @@ where
 		let (port_bot, port_human) = (ports[0], ports[1]);
 
 		debug!("Launching host SC2 process");
-		self.human.process = Some(launch_client(&self.sc2_path, port_human, self.sc2_version)?);
+		self.human.process = Some(launch_client(
+			&self.sc2_path,
+			port_human,
+			self.sc2_version,
+			&self.launcher,
+		)?);
 		debug!("Launching client SC2 process");
-		self.bot.process = Some(launch_client(&self.sc2_path, port_bot, self.sc2_version)?);
+		self.bot.process = Some(launch_client(
+			&self.sc2_path,
+			port_bot,
+			self.sc2_version,
+			&self.launcher,
+		)?);
 
 		debug!("Connecting to host websocket");
-		self.human.api = Some(API(connect_to_websocket(HOST, port_human)?));
+		let ws = connect_to_websocket(
+			HOST,
+			port_human,
+			&self.connect_options,
+			self.human.process.as_mut(),
+		)?;
+		self.human.api = Some(API::new(ws));
 		debug!("Connecting to client websocket");
-		self.bot.api = Some(API(connect_to_websocket(HOST, port_bot)?));
+		let ws = connect_to_websocket(HOST, port_bot, &self.connect_options, self.bot.process.as_mut())?;
+		self.bot.api = Some(API::new(ws));
 
 		Ok(())
 	}
@@ -272,16 +479,7 @@ where
 		req_create_game.set_realtime(self.realtime);
 
 		let res = human_api.send(req)?;
-		let res_create_game = res.get_create_game();
-		if res_create_game.has_error() {
-			let err = format!(
-				"{:?}: {}",
-				res_create_game.get_error(),
-				res_create_game.get_error_details()
-			);
-			error!("{}", err);
-			panic!(err);
-		}
+		create_game_result(&res)?;
 
 		debug!("Sending JoinGame request to both processes");
 		/*let ports = Ports {
@@ -294,6 +492,9 @@ where
 			server: (ports[0], ports[1]),
 			client: vec![(ports[2], ports[3]), (ports[4], ports[5])],
 		};
+		if let Some(path) = self.record_as {
+			self.bot.api().start_recording(path)?;
+		}
 		join_game2(&self.human_settings, human_api, Some(&ports))?;
 		join_game2(&bot_settings, self.bot.api(), Some(&ports))?;
 		let _ = wait_join(human_api)?;
@@ -302,24 +503,30 @@ where
 		set_static_data(self.bot)?;
 
 		debug!("Entered main loop");
-		play_first_step(self.bot, self.realtime)?;
-		let mut iteration = 0;
-		while play_step(self.bot, iteration, self.realtime)? {
-			iteration += 1;
-		}
+		let (_, leave) = play_game_loop(self.bot, self.realtime, self.pipelined, &self.limits)?;
 		debug!("Game finished");
 
+		if self.record_as.is_some() {
+			self.bot.api().stop_recording();
+		}
 		if let Some(path) = &self.save_replay_as {
 			save_replay(self.bot.api(), &path)?;
 		}
+		if leave {
+			self.bot.leave()?;
+		}
 		Ok(())
 	}
 
 	/// Changes map to play on.
-	/// # Panics
-	/// Panics if the map doesn't exist in maps directory.
-	pub fn set_map(&mut self, map: &str) {
-		self.map_path = get_map_path(&self.sc2_path, map);
+	///
+	/// Map can be a path relative to maps directory (e.g. `Ladder2019Season3/AcropolisLE`)
+	/// or just a name, which is searched in all sub-directories (see [`get_map_path`]).
+	///
+	/// Returns error if the map doesn't exist in maps directory.
+	pub fn set_map(&mut self, map: &str) -> SC2Result<()> {
+		self.map_path = get_map_path(&self.sc2_path, map)?;
+		Ok(())
 	}
 
 	/// Manually closes SC2 clients.
@@ -331,42 +538,225 @@ where
 
 #[derive(Default)]
 struct Human {
-	process: Option<Child>,
+	process: Option<SC2Process>,
 	api: Option<API>,
 }
 impl Human {
 	pub(crate) fn close_client(&mut self) {
-		if let Some(api) = &mut self.api {
-			let mut req = Request::new();
-			req.mut_leave_game();
-			if let Err(e) = api.send_request(req) {
-				error!("Request LeaveGame failed: {}", e);
-			}
+		close_client(self.api.as_mut(), self.process.as_mut());
+	}
+}
+impl Drop for Human {
+	fn drop(&mut self) {
+		self.close_client();
+	}
+}
 
-			let mut req = Request::new();
-			req.mut_quit();
-			if let Err(e) = api.send_request(req) {
-				error!("Request QuitGame failed: {}", e);
-			}
+/// Runner for games between two bots.
+pub struct RunnerBotVsBot<'a, B1, B2>
+where
+	B1: Player,
+	B2: Player,
+{
+	bot1: &'a mut B1,
+	bot2: &'a mut B2,
+	sc2_path: String,
+	sc2_version: Option<&'a str>,
+	map_path: String,
+	/// Play games in real time mode or not.
+	pub realtime: bool,
+	/// Save replay after the game in given path (replay is saved from the first bot's client).
+	pub save_replay_as: Option<&'a str>,
+	/// Timeouts and retry policy of connection to SC2 clients.
+	pub connect_options: ConnectOptions,
+	/// Command used to launch SC2 clients.
+	pub launcher: Launcher,
+}
+
+impl<'a, B1, B2> RunnerBotVsBot<'a, B1, B2>
+where
+	B1: Player,
+	B2: Player,
+{
+	/// Constructs new bot vs bot runner.
+	///
+	/// Returns error if SC2 installation or given map not found.
+	pub fn new(
+		bot1: &'a mut B1,
+		bot2: &'a mut B2,
+		map: &str,
+		sc2_version: Option<&'a str>,
+	) -> SC2Result<Self> {
+		debug!("Starting bot vs bot");
+		let sc2_path = get_path_to_sc2()?;
+		let map_path = get_map_path(&sc2_path, map)?;
+
+		Ok(Self {
+			bot1,
+			bot2,
+			sc2_path,
+			sc2_version,
+			map_path,
+			realtime: false,
+			save_replay_as: None,
+			connect_options: Default::default(),
+			launcher: Default::default(),
+		})
+	}
+
+	/// Constructs new bot vs bot runner, where bots are connected to given [`MockServer`]s
+	/// instead of SC2 clients. Game is created on the server of the first bot.
+	///
+	/// [`MockServer`]: crate::mock::MockServer
+	#[cfg(feature = "test-utils")]
+	pub fn mock(
+		bot1: &'a mut B1,
+		bot2: &'a mut B2,
+		server1: &MockServer,
+		server2: &MockServer,
+	) -> SC2Result<Self> {
+		debug!("Starting bot vs bot on mock servers");
+		let connect_options = ConnectOptions::default();
+		for (bot, server) in &[(bot1.bot(), server1), (bot2.bot(), server2)] {
+			debug!("Connecting to websocket on port {}", server.port());
+			let ws = connect_to_websocket(server.host(), server.port(), &connect_options, None)?;
+			bot.borrow_mut().api = Some(API::new(ws));
 		}
 
-		if let Some(process) = &mut self.process {
-			if let Err(e) = process.kill() {
-				error!("Can't kill SC2 process: {}", e);
+		Ok(Self {
+			bot1,
+			bot2,
+			sc2_path: String::new(),
+			sc2_version: None,
+			map_path: server1.map_path(),
+			realtime: false,
+			save_replay_as: None,
+			connect_options,
+			launcher: Default::default(),
+		})
+	}
+
+	/// Launches SC2 client for each bot and connects bots to the API.
+	pub fn launch(&mut self) -> SC2Result<()> {
+		let ports = get_unused_ports(2);
+		let (port1, port2) = (ports[0], ports[1]);
+
+		debug!("Launching host SC2 process");
+		self.bot1.bot().borrow_mut().process = Some(launch_client(
+			&self.sc2_path,
+			port1,
+			self.sc2_version,
+			&self.launcher,
+		)?);
+		debug!("Launching client SC2 process");
+		self.bot2.bot().borrow_mut().process = Some(launch_client(
+			&self.sc2_path,
+			port2,
+			self.sc2_version,
+			&self.launcher,
+		)?);
+
+		for (bot, port) in &[(self.bot1.bot(), port1), (self.bot2.bot(), port2)] {
+			debug!("Connecting to websocket on port {}", port);
+			let mut bot = bot.borrow_mut();
+			let ws = connect_to_websocket(HOST, *port, &self.connect_options, bot.process.as_mut())?;
+			bot.api = Some(API::new(ws));
+		}
+
+		Ok(())
+	}
+
+	/// Runs requested game.
+	///
+	/// Returns results of the first and the second bot.
+	pub fn run_game(&mut self) -> SC2Result<(GameResult, GameResult)> {
+		let settings1 = self.bot1.get_player_settings();
+		let settings2 = self.bot2.get_player_settings();
+		let (bot1, bot2) = (self.bot1.bot(), self.bot2.bot());
+
+		debug!("Sending CreateGame request to host process");
+		let mut req = Request::new();
+		let req_create_game = req.mut_create_game();
+
+		req_create_game
+			.mut_local_map()
+			.set_map_path(self.map_path.clone());
+		create_player_setup(&settings1, req_create_game);
+		create_player_setup(&settings2, req_create_game);
+		req_create_game.set_realtime(self.realtime);
+
+		let res = bot1.borrow_mut().api().send(req)?;
+		create_game_result(&res)?;
+
+		debug!("Sending JoinGame request to both processes");
+		let ports = get_unused_ports(6);
+		let ports = Ports {
+			server: (ports[0], ports[1]),
+			client: vec![(ports[2], ports[3]), (ports[4], ports[5])],
+		};
+		join_game2(&settings1, bot1.borrow_mut().api(), Some(&ports))?;
+		join_game2(&settings2, bot2.borrow_mut().api(), Some(&ports))?;
+		let player_id = wait_join(bot1.borrow_mut().api())?;
+		bot1.borrow_mut().player_id = player_id;
+		let player_id = wait_join(bot2.borrow_mut().api())?;
+		bot2.borrow_mut().player_id = player_id;
+
+		set_static_data(&mut bot1.borrow_mut())?;
+		set_static_data(&mut bot2.borrow_mut())?;
+
+		debug!("Entered main loop");
+		let mut iteration = None;
+		let (mut result1, mut result2) = (None, None);
+		// Bots are stepped together, since game doesn't advance until both of them send step request
+		while result1.is_none() || result2.is_none() {
+			if result1.is_none() {
+				result1 = play_step_lockstep(self.bot1, iteration, self.realtime)?;
+			}
+			if result2.is_none() {
+				result2 = play_step_lockstep(self.bot2, iteration, self.realtime)?;
+			}
+			if !self.realtime {
+				// Bots which finished in this iteration haven't sent step request
+				if result1.is_none() {
+					bot1.borrow_mut().api().wait_response()?;
+				}
+				if result2.is_none() {
+					bot2.borrow_mut().api().wait_response()?;
+				}
 			}
+			iteration = Some(iteration.map_or(0, |i| i + 1));
 		}
+		info!("Game finished!");
+
+		if let Some(path) = &self.save_replay_as {
+			save_replay(bot1.borrow_mut().api(), &path)?;
+		}
+		Ok((result1.unwrap(), result2.unwrap()))
 	}
-}
-impl Drop for Human {
-	fn drop(&mut self) {
-		self.close_client();
+
+	/// Changes map to play on.
+	///
+	/// Map can be a path relative to maps directory (e.g. `Ladder2019Season3/AcropolisLE`)
+	/// or just a name, which is searched in all sub-directories (see [`get_map_path`]).
+	///
+	/// Returns error if the map doesn't exist in maps directory.
+	pub fn set_map(&mut self, map: &str) -> SC2Result<()> {
+		self.map_path = get_map_path(&self.sc2_path, map)?;
+		Ok(())
+	}
+
+	/// Manually closes SC2 clients.
+	pub fn close(&mut self) {
+		self.bot1.bot().borrow_mut().close_client();
+		self.bot2.bot().borrow_mut().close_client();
 	}
 }
 
+/// Error returned by SC2 in response to `RequestCreateGame` or `RequestJoinGame`.
 #[derive(Debug)]
-struct ProtoError(String);
+pub struct ProtoError(String);
 impl ProtoError {
-	fn new<E: fmt::Debug>(error: E, details: &str) -> Self {
+	pub(crate) fn new<E: fmt::Debug>(error: E, details: &str) -> Self {
 		Self(format!("{:?}: {}", error, details))
 	}
 }
@@ -377,10 +767,152 @@ impl fmt::Display for ProtoError {
 }
 impl Error for ProtoError {}
 
-struct Ports {
+/// All errors which can happen while running the game.
+#[derive(Debug)]
+pub enum SC2Error {
+	/// SC2 installation or one of it's folders not found.
+	NotInstalled(String),
+	/// SC2 process can't be launched.
+	Launch(io::Error),
+	/// SC2 process exited unexpectedly, contains it's exit status.
+	ProcessExited(ExitStatus),
+	/// Map doesn't exist in maps folder.
+	MapNotFound(String),
+	/// Requested SC2 version is unknown.
+	UnknownVersion(String),
+	/// SC2 returned error on `RequestCreateGame` or `RequestJoinGame`.
+	Protocol(ProtoError),
+	/// SC2 returned response with errors, contains status of the game and these errors.
+	Response(Status, Vec<String>),
+	/// Websocket connection failed.
+	WebSocket(tungstenite::Error),
+	/// Websocket connection of [`AsyncAPI`](crate::async_client::AsyncAPI) failed.
+	#[cfg(feature = "async")]
+	AsyncWebSocket(tokio_tungstenite::tungstenite::Error),
+	/// Blocking request was sent outside of multi-threaded `tokio` runtime.
+	#[cfg(feature = "async")]
+	Runtime(String),
+	/// SC2 closed websocket connection.
+	ConnectionClosed,
+	/// Connection to SC2 or waiting for response timed out.
+	Timeout(String),
+	/// Protobuf message can't be encoded or decoded.
+	Protobuf(ProtobufError),
+	/// Bot's requests don't match recorded ones, or recording is broken.
+	Playback(String),
+	/// Replay file is broken or has unsupported format.
+	InvalidReplay(String),
+	/// Invalid argument given, e.g. port can't be parsed.
+	InvalidArgument(String),
+	/// IO error.
+	Io(io::Error),
+	/// Any other error, e.g. returned from bot's code.
+	Other(Box<dyn Error>),
+}
+impl fmt::Display for SC2Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::NotInstalled(msg) => write!(f, "SC2 installation not found: {}", msg),
+			Self::Launch(e) => write!(f, "Can't launch SC2 process: {}", e),
+			Self::ProcessExited(status) => write!(f, "SC2 process exited unexpectedly: {}", status),
+			Self::MapNotFound(path) => write!(f, "Map doesn't exist: {}", path),
+			Self::UnknownVersion(v) => write!(f, "Can't find info about version: {:?}", v),
+			Self::Protocol(e) => write!(f, "SC2 returned error: {}", e),
+			Self::Response(status, errors) => {
+				write!(
+					f,
+					"SC2 responded with errors in status {:?}: {}",
+					status,
+					errors.join("; ")
+				)
+			}
+			Self::WebSocket(e) => write!(f, "Websocket error: {}", e),
+			#[cfg(feature = "async")]
+			Self::AsyncWebSocket(e) => write!(f, "Websocket error: {}", e),
+			#[cfg(feature = "async")]
+			Self::Runtime(msg) => write!(f, "Async runtime error: {}", msg),
+			Self::ConnectionClosed => write!(f, "SC2 closed websocket connection"),
+			Self::Timeout(msg) => write!(f, "Timed out: {}", msg),
+			Self::Protobuf(e) => write!(f, "Protobuf error: {}", e),
+			Self::Playback(msg) => write!(f, "Playback error: {}", msg),
+			Self::InvalidReplay(msg) => write!(f, "Invalid replay: {}", msg),
+			Self::InvalidArgument(msg) => write!(f, "Invalid argument: {}", msg),
+			Self::Io(e) => write!(f, "IO error: {}", e),
+			Self::Other(e) => write!(f, "{}", e),
+		}
+	}
+}
+impl Error for SC2Error {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Launch(e) | Self::Io(e) => Some(e),
+			Self::Protocol(e) => Some(e),
+			Self::WebSocket(e) => Some(e),
+			#[cfg(feature = "async")]
+			Self::AsyncWebSocket(e) => Some(e),
+			Self::Protobuf(e) => Some(e),
+			Self::Other(e) => Some(e.as_ref()),
+			_ => None,
+		}
+	}
+}
+
+impl From<ProtoError> for SC2Error {
+	fn from(e: ProtoError) -> Self {
+		Self::Protocol(e)
+	}
+}
+impl From<tungstenite::Error> for SC2Error {
+	fn from(e: tungstenite::Error) -> Self {
+		Self::WebSocket(e)
+	}
+}
+#[cfg(feature = "async")]
+impl From<tokio_tungstenite::tungstenite::Error> for SC2Error {
+	fn from(e: tokio_tungstenite::tungstenite::Error) -> Self {
+		Self::AsyncWebSocket(e)
+	}
+}
+impl From<ProtobufError> for SC2Error {
+	fn from(e: ProtobufError) -> Self {
+		Self::Protobuf(e)
+	}
+}
+impl From<io::Error> for SC2Error {
+	fn from(e: io::Error) -> Self {
+		Self::Io(e)
+	}
+}
+impl From<url::ParseError> for SC2Error {
+	fn from(e: url::ParseError) -> Self {
+		Self::InvalidArgument(e.to_string())
+	}
+}
+impl From<ParseIntError> for SC2Error {
+	fn from(e: ParseIntError) -> Self {
+		Self::InvalidArgument(e.to_string())
+	}
+}
+impl From<Box<dyn Error>> for SC2Error {
+	fn from(e: Box<dyn Error>) -> Self {
+		Self::Other(e)
+	}
+}
+impl From<String> for SC2Error {
+	fn from(e: String) -> Self {
+		Self::Other(e.into())
+	}
+}
+impl From<&str> for SC2Error {
+	fn from(e: &str) -> Self {
+		Self::Other(e.into())
+	}
+}
+
+pub(crate) struct Ports {
 	// shared: i32,
-	server: (i32, i32),
-	client: Vec<(i32, i32)>,
+	pub server: (i32, i32),
+	pub client: Vec<(i32, i32)>,
 }
 
 /// Additional launch options for [`run_vs_computer`] and [`run_vs_human`].
@@ -392,10 +924,259 @@ pub struct LaunchOptions<'a> {
 	pub save_replay_as: Option<&'a str>,
 	/// Play games in real time mode or not.
 	pub realtime: bool,
+	/// Record API protocol stream of the game in given path.
+	/// Recording can be played back with [`run_recorded_game`].
+	///
+	/// Not supported by async runners, they return [`SC2Error::InvalidArgument`] if it's set.
+	pub record_as: Option<&'a str>,
+	/// Send actions, debug and step requests at once and request next observation together with them,
+	/// instead of waiting for every response separately. Default: `false`.
+	///
+	/// Stats of steps are available in [`step_stats`](Bot::step_stats).
+	///
+	/// Not supported by async runners, they return [`SC2Error::InvalidArgument`] if it's set.
+	pub pipelined: bool,
+	/// Limits of game length and what to do when they're reached. Default: no limits.
+	///
+	/// Not supported by async runners, they return [`SC2Error::InvalidArgument`] if any limit is set.
+	pub limits: GameLimits,
+	/// Timeouts and retry policy of connection to SC2 client.
+	pub connect_options: ConnectOptions,
+	/// Command used to launch SC2 client.
+	pub launcher: Launcher,
+}
+
+/// Command used to launch SC2 client.
+///
+/// By default SC2 executable of installation is launched directly,
+/// but it can be run with another program, e.g. Windows client with Wine on Linux:
+/// ```no_run
+/// # use rust_sc2::client::Launcher;
+/// // `SC2PATH` should point to Windows installation, like `~/.wine/drive_c/Program Files (x86)/StarCraft II`
+/// let mut launcher = Launcher::wine();
+/// launcher.env.push(("WINEDEBUG".to_string(), "-all".to_string()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Launcher {
+	/// Program which runs SC2 executable, path to executable is passed to it as the first argument.
+	/// Default: `None` (executable is launched directly).
+	pub command: Option<String>,
+	/// Path to SC2 executable, relative paths are resolved from `Versions/Base*` folder of used version.
+	/// Default: `None` (executable of current platform).
+	pub executable: Option<String>,
+	/// Additional arguments passed to SC2 executable.
+	pub args: Vec<String>,
+	/// Environment variables set for launched process.
+	pub env: Vec<(String, String)>,
+	/// Folder where output of SC2 clients is written to `SC2_<port>.log` files.
+	/// Default: `None` (output isn't captured).
+	pub log_dir: Option<String>,
+	/// Time given to SC2 client to exit after `QuitGame` request, then it's killed. Default: `5s`.
+	pub shutdown_timeout: Duration,
+}
+impl Default for Launcher {
+	fn default() -> Self {
+		Self {
+			command: None,
+			executable: None,
+			args: Vec::new(),
+			env: Vec::new(),
+			log_dir: None,
+			shutdown_timeout: Duration::from_secs(5),
+		}
+	}
+}
+impl Launcher {
+	/// Launches Windows SC2 client with `wine`.
+	pub fn wine() -> Self {
+		Self {
+			command: Some("wine".to_string()),
+			executable: Some("SC2_x64.exe".to_string()),
+			..Default::default()
+		}
+	}
+
+	/// Builds command which launches SC2 client of given version, listening on given port.
+	///
+	/// Returns error if version is unknown or no versions installed.
+	pub fn command(&self, sc2_path: &str, port: i32, sc2_version: Option<&str>) -> SC2Result<Command> {
+		let (base_version, data_hash) = match sc2_version {
+			Some(ver) => get_version_info(ver)?,
+			None => (get_latest_base_version(sc2_path)?, ""),
+		};
+
+		let version_dir = Path::new(sc2_path).join(format!("Versions/Base{}", base_version));
+		let executable = version_dir.join(self.executable.as_deref().unwrap_or(SC2_BINARY));
+		let mut process = match &self.command {
+			Some(command) => {
+				let mut process = Command::new(command);
+				process.arg(executable);
+				process
+			}
+			None => Command::new(executable),
+		};
+		process
+			.current_dir(format!("{}/{}", sc2_path, SC2_SUPPORT))
+			.arg("-listen")
+			.arg(HOST)
+			.arg("-port")
+			.arg(port.to_string())
+			// 0 - windowed, 1 - fullscreen
+			.arg("-displayMode")
+			.arg("0");
+		if !data_hash.is_empty() {
+			process.arg("-dataVersion").arg(data_hash);
+		}
+		process
+			.args(&self.args)
+			.envs(self.env.iter().map(|(k, v)| (k, v)));
+		Ok(process)
+	}
+}
+
+/// Timeouts and retry policy of connection to SC2 client.
+///
+/// SC2 client needs some time to start listening after launch,
+/// so connection is retried until it succeeds or `max_attempts` reached,
+/// with delay between attempts starting from `backoff` and doubling up to `max_backoff`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectOptions {
+	/// Timeout of single connection attempt. Default: `2s`.
+	pub timeout: Duration,
+	/// Delay after the first failed attempt. Default: `100ms`.
+	pub backoff: Duration,
+	/// Maximum delay between attempts. Default: `2s`.
+	pub max_backoff: Duration,
+	/// Maximum number of connection attempts. Default: `60`.
+	pub max_attempts: u32,
+	/// Timeout of waiting for every response from SC2, `None` means to wait forever. Default: `None`.
+	///
+	/// Can be changed later with [`API::set_read_timeout`].
+	pub read_timeout: Option<Duration>,
+}
+impl Default for ConnectOptions {
+	fn default() -> Self {
+		Self {
+			timeout: Duration::from_secs(2),
+			backoff: Duration::from_millis(100),
+			max_backoff: Duration::from_secs(2),
+			max_attempts: 60,
+			read_timeout: None,
+		}
+	}
+}
+impl ConnectOptions {
+	pub(crate) fn next_backoff(&self, backoff: Duration) -> Duration {
+		(backoff * 2).min(self.max_backoff)
+	}
+}
+
+/// Limits of game length, checked by runners after every step.
+///
+/// All limits are disabled by default.
+///
+/// Limits are supported by [`RunnerSingle`] and [`RunnerMulti`] (also when used by [`run_vs_computer`]
+/// and [`run_vs_human`]). [`RunnerBotVsBot`] and observer runners don't check them,
+/// games played with these last until the end. Async runners return error if any limit is set.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GameLimits {
+	/// Game loop after which game is stopped. Default: `None`.
+	pub max_game_loop: Option<u32>,
+	/// Real time after which game is stopped, counting from the first step. Default: `None`.
+	pub max_time: Option<Duration>,
+	/// How game is stopped when one of limits is reached. Default: [`LimitAction::Tie`].
+	pub action: LimitAction,
+}
+impl GameLimits {
+	fn is_reached(&self, game_loop: u32, time: Duration) -> bool {
+		self.max_game_loop.map_or(false, |max| game_loop >= max)
+			|| self.max_time.map_or(false, |max| time >= max)
+	}
+}
+
+/// How game is stopped when [`GameLimits`] are reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitAction {
+	/// Bot leaves the game and gets [`Defeat`](GameResult::Defeat).
+	Leave,
+	/// Bot leaves the game, but it's counted as [`Tie`](GameResult::Tie).
+	Tie,
+	/// Game is ended with debug command: [`win_game`] if `true`, [`end_game`] otherwise.
+	/// Result is received from SC2 as usual.
+	///
+	/// [`win_game`]: crate::debug::Debugger::win_game
+	/// [`end_game`]: crate::debug::Debugger::end_game
+	EndGame(bool),
+}
+impl Default for LimitAction {
+	fn default() -> Self {
+		Self::Tie
+	}
+}
+
+/// Timings of game steps, measured by runners.
+/// Can be accessed through [`step_stats`](Bot::step_stats).
+///
+/// Step time is measured from request of observation to the moment SC2 received all requests of the step,
+/// so it includes both time spent by the bot and time spent waiting for SC2.
+/// The first step isn't measured, because it includes initialization of the bot.
+#[derive(Debug, Default, Clone)]
+pub struct StepStats {
+	/// Number of measured steps.
+	pub steps: u32,
+	/// Total time of all steps.
+	pub total_time: Duration,
+	/// Total time spent by the bot (processing observation and [`on_step`](crate::Player::on_step)).
+	pub bot_time: Duration,
+	/// Time of the last step.
+	pub last_time: Duration,
+	/// Time of the longest step.
+	pub max_time: Duration,
+}
+impl StepStats {
+	/// Average time of step.
+	pub fn average(&self) -> Duration {
+		self.total_time.checked_div(self.steps).unwrap_or_default()
+	}
+	/// Average time of waiting for SC2 in step.
+	pub fn average_sc2(&self) -> Duration {
+		(self.total_time - self.bot_time)
+			.checked_div(self.steps)
+			.unwrap_or_default()
+	}
+	pub(crate) fn record(&mut self, time: Duration, bot_time: Duration) {
+		self.steps += 1;
+		self.total_time += time;
+		self.bot_time += bot_time;
+		self.last_time = time;
+		self.max_time = self.max_time.max(time);
+	}
 }
 
 // Runners
 
+/// Simple function to run game between two bots.
+///
+/// Returns results of the first and the second bot.
+pub fn run_bot_vs_bot<B1, B2>(
+	bot1: &mut B1,
+	bot2: &mut B2,
+	map_name: &str,
+	options: LaunchOptions,
+) -> SC2Result<(GameResult, GameResult)>
+where
+	B1: Player,
+	B2: Player,
+{
+	let mut runner = RunnerBotVsBot::new(bot1, bot2, map_name, options.sc2_version)?;
+	runner.connect_options = options.connect_options;
+	runner.launcher = options.launcher;
+	runner.launch()?;
+	runner.realtime = options.realtime;
+	runner.save_replay_as = options.save_replay_as;
+	runner.run_game()
+}
+
 /// Simple function to run game vs built-in AI.
 pub fn run_vs_computer<B>(
 	bot: &mut B,
@@ -406,10 +1187,15 @@ pub fn run_vs_computer<B>(
 where
 	B: Player
 {
-	let mut runner = RunnerSingle::new(bot, computer, map_name, options.sc2_version);
+	let mut runner = RunnerSingle::new(bot, computer, map_name, options.sc2_version)?;
+	runner.connect_options = options.connect_options;
+	runner.launcher = options.launcher;
 	runner.launch()?;
 	runner.realtime = options.realtime;
 	runner.save_replay_as = options.save_replay_as;
+	runner.record_as = options.record_as;
+	runner.pipelined = options.pipelined;
+	runner.limits = options.limits;
 	runner.run_game()?;
 	Ok(())
 }
@@ -422,18 +1208,64 @@ pub fn run_ladder_game<B>(
 	player_port: i32,
 	opponent_id: Option<&str>,
 ) -> SC2Result<()>
+where
+	B: Player
+{
+	ladder_game(bot, &ladder_args(host, port, player_port, opponent_id)?, None)
+}
+
+/// The same as [`run_ladder_game`], but also records API protocol stream of the game in given path.
+/// Recording can be played back with [`run_recorded_game`].
+pub fn run_ladder_game_recorded<B>(
+	bot: &mut B,
+	host: &str,
+	port: &str,
+	player_port: i32,
+	opponent_id: Option<&str>,
+	record_as: &str,
+) -> SC2Result<()>
+where
+	B: Player
+{
+	ladder_game(
+		bot,
+		&ladder_args(host, port, player_port, opponent_id)?,
+		Some(record_as),
+	)
+}
+
+fn ladder_args(host: &str, port: &str, player_port: i32, opponent_id: Option<&str>) -> SC2Result<LadderArgs> {
+	Ok(LadderArgs {
+		ladder_server: host.to_string(),
+		game_port: port.parse()?,
+		start_port: player_port,
+		opponent_id: opponent_id.map(|id| id.to_string()),
+		realtime: false,
+		connect_options: Default::default(),
+	})
+}
+
+fn ladder_game<B>(bot: &mut B, args: &LadderArgs, record_as: Option<&str>) -> SC2Result<()>
 where
 	B: Player
 {
 	debug!("Starting ladder game");
 
 	debug!("Connecting to websocket");
-	bot.bot().borrow_mut().api = Some(API(connect_to_websocket(&host, port.parse()?)?));
+	bot.bot().borrow_mut().api = Some(API::new(connect_to_websocket(
+		&args.ladder_server,
+		args.game_port,
+		&args.connect_options,
+		None,
+	)?));
+	if let Some(path) = record_as {
+		bot.bot().borrow_mut().api().start_recording(path)?;
+	}
 
 	debug!("Sending JoinGame request");
 
-	if let Some(id) = opponent_id {
-		bot.bot().borrow_mut().opponent_id = id.to_string();
+	if let Some(id) = &args.opponent_id {
+		bot.bot().borrow_mut().opponent_id = id.clone();
 	}
 
 	let player_id = join_game(
@@ -441,8 +1273,8 @@ where
 		bot.bot().borrow_mut().api(),
 		Some(&Ports {
 			// shared: player_port + 1,
-			server: (player_port + 2, player_port + 3),
-			client: vec![(player_port + 4, player_port + 5)],
+			server: (args.start_port + 2, args.start_port + 3),
+			client: vec![(args.start_port + 4, args.start_port + 5)],
 		}),
 	)?;
 	bot.bot().borrow_mut().player_id = player_id;
@@ -450,17 +1282,190 @@ where
 	set_static_data(&mut bot.bot().borrow_mut())?;
 
 	debug!("Entered main loop");
-	// Main loop
-	let mut iteration = 0;
-	play_first_step(bot, false)?;
-	while play_step(bot, iteration, false)? {
-		iteration += 1;
-	}
+	play_game_loop(bot, args.realtime, false, &GameLimits::default())?;
 	debug!("Game finished");
 
 	Ok(())
 }
 
+/// Arguments passed to bots by ladder managers, e.g.
+/// `--LadderServer 127.0.0.1 --GamePort 5677 --StartPort 5690 --OpponentId id --RealTime`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LadderArgs {
+	/// Host of ladder server. Default: `127.0.0.1`.
+	pub ladder_server: String,
+	/// Port of SC2 client to connect to.
+	pub game_port: i32,
+	/// Base port used to compute ports of the game.
+	pub start_port: i32,
+	/// Id of opponent, can be used to adapt strategy against known bots.
+	pub opponent_id: Option<String>,
+	/// Game is played in real time mode.
+	pub realtime: bool,
+	/// Timeouts and retry policy of connection to ladder server.
+	/// Not passed by ladder managers, so it's always default after parsing.
+	pub connect_options: ConnectOptions,
+}
+impl LadderArgs {
+	/// Parses arguments of current process.
+	///
+	/// Returns `None` if process was launched without ladder arguments.
+	pub fn from_env() -> SC2Result<Option<Self>> {
+		Self::parse(env::args().skip(1))
+	}
+	/// Parses given arguments, both `--Arg value` and `--Arg=value` forms are supported.
+	/// Unknown arguments are ignored, so bots can have their own ones.
+	///
+	/// Returns `None` if there're no ladder arguments
+	/// and error if some of the required ones are missing or invalid.
+	pub fn parse<I, S>(args: I) -> SC2Result<Option<Self>>
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		let mut ladder_server = None;
+		let mut game_port = None;
+		let mut start_port = None;
+		let mut opponent_id = None;
+		let mut realtime = false;
+
+		let mut args = args.into_iter();
+		while let Some(arg) = args.next() {
+			let arg = arg.as_ref();
+			let (name, inline_value) = match arg.find('=') {
+				Some(i) => (&arg[..i], Some(arg[i + 1..].to_string())),
+				None => (arg, None),
+			};
+			let slot = match name {
+				"--LadderServer" => &mut ladder_server,
+				"--GamePort" => &mut game_port,
+				"--StartPort" => &mut start_port,
+				"--OpponentId" => &mut opponent_id,
+				"--RealTime" => {
+					realtime = true;
+					continue;
+				}
+				_ => continue,
+			};
+			*slot = Some(match inline_value {
+				Some(value) => value,
+				None => args
+					.next()
+					.map(|value| value.as_ref().to_string())
+					.ok_or_else(|| SC2Error::InvalidArgument(format!("Missing value of {}", name)))?,
+			});
+		}
+
+		if ladder_server.is_none() && game_port.is_none() && start_port.is_none() {
+			return Ok(None);
+		}
+		let port = |port: Option<String>, name: &str| {
+			port.ok_or_else(|| SC2Error::InvalidArgument(format!("{} must be specified", name)))?
+				.parse::<i32>()
+				.map_err(SC2Error::from)
+		};
+		Ok(Some(Self {
+			ladder_server: ladder_server.unwrap_or_else(|| HOST.to_string()),
+			game_port: port(game_port, "--GamePort")?,
+			start_port: port(start_port, "--StartPort")?,
+			opponent_id,
+			realtime,
+			connect_options: ConnectOptions::default(),
+		}))
+	}
+}
+
+/// Joins ladder game with given arguments.
+pub fn run_ladder_game_with_args<B>(bot: &mut B, args: &LadderArgs) -> SC2Result<()>
+where
+	B: Player
+{
+	ladder_game(bot, args, None)
+}
+
+/// Options of local game for [`run_ladder_or_local`].
+pub struct LocalOptions<'a> {
+	/// Computer opponent.
+	pub computer: Computer,
+	/// Map to play on.
+	pub map: &'a str,
+	/// Other options of the game.
+	///
+	/// It's [`connect_options`](LaunchOptions::connect_options) are used in ladder games too.
+	pub launch: LaunchOptions<'a>,
+}
+impl<'a> LocalOptions<'a> {
+	/// Constructs new options with given opponent, map and default launch options.
+	pub fn new(computer: Computer, map: &'a str) -> Self {
+		Self {
+			computer,
+			map,
+			launch: Default::default(),
+		}
+	}
+}
+
+/// Joins ladder game if process was launched with ladder arguments (see [`LadderArgs`]),
+/// otherwise runs local game vs built-in AI with given options.
+///
+/// Makes the same binary work both on ladder and locally:
+/// ```
+/// fn main() -> SC2Result<()> {
+///     let mut bot = MyBot::default();
+///     run_ladder_or_local(
+///         &mut bot,
+///         LocalOptions::new(Computer::new(Race::Random, Difficulty::Hard, None), "EverDreamLE"),
+///     )
+/// }
+/// ```
+pub fn run_ladder_or_local<B>(bot: &mut B, local_options: LocalOptions) -> SC2Result<()>
+where
+	B: Player
+{
+	match LadderArgs::from_env()? {
+		Some(mut args) => {
+			args.connect_options = local_options.launch.connect_options;
+			run_ladder_game_with_args(bot, &args)
+		}
+		None => run_vs_computer(
+			bot,
+			local_options.computer,
+			local_options.map,
+			local_options.launch,
+		),
+	}
+}
+
+/// Plays back game recorded with `record_as` option or [`run_ladder_game_recorded`].
+///
+/// Recorded responses are fed to the bot instead of SC2 client,
+/// so [`on_start`], [`on_step`], [`on_event`] and [`on_end`] are called exactly as in the recorded game.
+/// Returns error if bot sends requests different from recorded ones.
+///
+/// [`on_start`]: crate::Player::on_start
+/// [`on_step`]: crate::Player::on_step
+/// [`on_event`]: crate::Player::on_event
+/// [`on_end`]: crate::Player::on_end
+pub fn run_recorded_game<B>(bot: &mut B, path: &str) -> SC2Result<()>
+where
+	B: Player
+{
+	debug!("Starting recorded game");
+	bot.bot().borrow_mut().api = Some(API::playback(path)?);
+
+	debug!("Sending JoinGame request");
+	let player_id = join_game(&bot.get_player_settings(), bot.bot().borrow_mut().api(), None)?;
+	bot.bot().borrow_mut().player_id = player_id;
+
+	set_static_data(&mut bot.bot().borrow_mut())?;
+
+	debug!("Entered main loop");
+	play_game_loop(bot, false, false, &GameLimits::default())?;
+	debug!("Recorded game finished");
+
+	Ok(())
+}
+
 /// Simple function to run game vs human.
 pub fn run_vs_human<B>(
 	bot: &mut B,
@@ -471,22 +1476,27 @@ pub fn run_vs_human<B>(
 where
 	B: Player + DerefMut<Target = Bot> + Deref<Target = Bot>,
 {
-	let mut runner = RunnerMulti::new(bot, human_settings, map_name, options.sc2_version);
+	let mut runner = RunnerMulti::new(bot, human_settings, map_name, options.sc2_version)?;
+	runner.connect_options = options.connect_options;
+	runner.launcher = options.launcher;
 	runner.launch()?;
 	runner.realtime = options.realtime;
 	runner.save_replay_as = options.save_replay_as;
+	runner.record_as = options.record_as;
+	runner.pipelined = options.pipelined;
+	runner.limits = options.limits;
 	runner.run_game()?;
 	Ok(())
 }
 
 // Portpicker
-fn get_unused_port() -> i32 {
+pub(crate) fn get_unused_port() -> i32 {
 	(5000..65535)
 		.find(|port| TcpListener::bind((HOST, *port)).is_ok())
 		.unwrap() as i32
 }
 
-fn get_unused_ports(n: usize) -> Vec<i32> {
+pub(crate) fn get_unused_ports(n: usize) -> Vec<i32> {
 	let mut ports = Vec::with_capacity(n);
 	for port in 5000..65535 {
 		if TcpListener::bind((HOST, port)).is_ok() {
@@ -500,9 +1510,17 @@ fn get_unused_ports(n: usize) -> Vec<i32> {
 }
 
 // Helpers
-fn set_static_data(bot: &mut Bot) -> SC2Result<()> {
+pub(crate) fn set_static_data(bot: &mut Bot) -> SC2Result<()> {
 	let api = &mut bot.api.as_mut().expect("API is not initialized");
 
+	// Ids depend on version of the game, so it's determined before any of them are received
+	debug!("Requesting Ping");
+	let mut req = Request::new();
+	req.mut_ping();
+	let res = api.send(req)?;
+	bot.data_version = DataVersion::from_base_build(res.get_ping().get_base_build());
+	debug!("Using ids of {:?} data version", bot.data_version);
+
 	debug!("Requesting GameInfo");
 	let mut req = Request::new();
 	req.mut_game_info();
@@ -518,11 +1536,11 @@ fn set_static_data(bot: &mut Bot) -> SC2Result<()> {
 	req_game_data.set_buff_id(true);
 	req_game_data.set_effect_id(true);
 	let mut res = api.send(req)?;
-	bot.game_data = Rs::new(res.take_data().into_sc2());
+	bot.game_data = Rs::new((res.take_data(), bot.data_version).into_sc2());
 	Ok(())
 }
 
-fn create_player_setup(settings: &PlayerSettings, req_create_game: &mut RequestCreateGame) {
+pub(crate) fn create_player_setup(settings: &PlayerSettings, req_create_game: &mut RequestCreateGame) {
 	let mut setup = PlayerSetup::new();
 
 	setup.set_race(settings.race.into_proto());
@@ -533,7 +1551,7 @@ fn create_player_setup(settings: &PlayerSettings, req_create_game: &mut RequestC
 	req_create_game.mut_player_setup().push(setup);
 }
 
-fn create_computer_setup(computer: &Computer, req_create_game: &mut RequestCreateGame) {
+pub(crate) fn create_computer_setup(computer: &Computer, req_create_game: &mut RequestCreateGame) {
 	let mut setup = PlayerSetup::new();
 
 	setup.set_race(computer.race.into_proto());
@@ -550,21 +1568,19 @@ fn join_game(settings: &PlayerSettings, api: &mut API, ports: Option<&Ports>) ->
 	wait_join(api)
 }
 fn join_game2(settings: &PlayerSettings, api: &mut API, ports: Option<&Ports>) -> SC2Result<()> {
+	api.send_only(join_request(settings, ports))?;
+	Ok(())
+}
+fn wait_join(api: &mut API) -> SC2Result<u32> {
+	let res = api.wait_response()?;
+	join_result(&res)
+}
+pub(crate) fn join_request(settings: &PlayerSettings, ports: Option<&Ports>) -> Request {
 	let mut req = Request::new();
 	let req_join_game = req.mut_join_game();
 
 	req_join_game.set_race(settings.race.into_proto());
-
-	let options = req_join_game.mut_options();
-	options.set_raw(true);
-	options.set_score(true);
-	// options.mut_feature_layer()
-	// options.mut_render();
-	options.set_show_cloaked(true);
-	options.set_show_burrowed_shadows(true);
-	options.set_show_placeholders(true);
-	options.set_raw_affects_selection(settings.raw_affects_selection);
-	options.set_raw_crop_to_playable_area(settings.raw_crop_to_playable_area);
+	req_join_game.set_options(interface_options(settings));
 	if let Some(name) = &settings.name {
 		req_join_game.set_player_name(name.to_string());
 	}
@@ -585,114 +1601,393 @@ fn join_game2(settings: &PlayerSettings, api: &mut API, ports: Option<&Ports>) -
 		});
 	}
 
-	api.send_only(req)?;
-	Ok(())
+	req
 }
-fn wait_join(api: &mut API) -> SC2Result<u32> {
-	let res = api.wait_response()?;
-
+pub(crate) fn interface_options(settings: &PlayerSettings) -> InterfaceOptions {
+	let mut options = InterfaceOptions::new();
+	options.set_raw(true);
+	options.set_score(true);
+	if let Some(feature_layer) = &settings.feature_layer {
+		options.set_feature_layer(spatial_setup(feature_layer));
+	}
+	if let Some(render) = &settings.render {
+		let mut setup = spatial_setup(render);
+		// Rendered images don't have width and cheating layers
+		setup.clear_width();
+		setup.clear_allow_cheating_layers();
+		options.set_render(setup);
+	}
+	options.set_show_cloaked(true);
+	options.set_show_burrowed_shadows(true);
+	options.set_show_placeholders(true);
+	options.set_raw_affects_selection(settings.raw_affects_selection);
+	options.set_raw_crop_to_playable_area(settings.raw_crop_to_playable_area);
+	options
+}
+fn spatial_setup(options: &SpatialOptions) -> SpatialCameraSetup {
+	let mut setup = SpatialCameraSetup::new();
+	let resolution = setup.mut_resolution();
+	resolution.set_x(options.screen.0);
+	resolution.set_y(options.screen.1);
+	let minimap_resolution = setup.mut_minimap_resolution();
+	minimap_resolution.set_x(options.minimap.0);
+	minimap_resolution.set_y(options.minimap.1);
+	setup.set_width(options.camera_width);
+	setup.set_crop_to_playable_area(options.crop_to_playable_area);
+	setup.set_allow_cheating_layers(options.allow_cheating_layers);
+	setup
+}
+pub(crate) fn join_result(res: &Response) -> SC2Result<u32> {
 	let res_join_game = res.get_join_game();
 	if res_join_game.has_error() {
 		let err = ProtoError::new(res_join_game.get_error(), res_join_game.get_error_details());
 		error!("{}", err);
-		Err(Box::new(err))
+		Err(err.into())
 	} else {
 		Ok(res_join_game.get_player_id())
 	}
 }
+pub(crate) fn response_result(res: Response) -> SC2Result<Response> {
+	if res.get_error().is_empty() {
+		Ok(res)
+	} else {
+		Err(SC2Error::Response(res.get_status(), res.get_error().to_vec()))
+	}
+}
+pub(crate) fn create_game_result(res: &Response) -> SC2Result<()> {
+	let res_create_game = res.get_create_game();
+	if res_create_game.has_error() {
+		let err = ProtoError::new(res_create_game.get_error(), res_create_game.get_error_details());
+		error!("{}", err);
+		Err(err.into())
+	} else {
+		Ok(())
+	}
+}
 
-fn play_first_step<B>(bot: &mut B, realtime: bool) -> SC2Result<()>
+pub(crate) fn observation_request(bot: &Bot) -> Request {
+	let mut req = Request::new();
+	req.mut_observation().set_disable_fog(bot.disable_fog);
+	req
+}
+pub(crate) fn step_request(bot: &Bot) -> Request {
+	let mut req = Request::new();
+	req.mut_step().set_count(bot.game_step.get_locked());
+	req
+}
+pub(crate) fn actions_request(bot: &mut Bot) -> Option<Request> {
+	let version = bot.data_version;
+	let bot_actions = bot.get_actions();
+	if bot_actions.is_empty() {
+		return None;
+	}
+	let mut req = Request::new();
+	let actions = req.mut_action().mut_actions();
+	bot_actions
+		.iter()
+		.for_each(|a| actions.push((a, version).into_proto()));
+	bot.clear_actions();
+	Some(req)
+}
+pub(crate) fn debug_request(bot: &mut Bot) -> Option<Request> {
+	let version = bot.data_version;
+	let bot_debug_commands = bot.get_debug_commands();
+	if bot_debug_commands.is_empty() {
+		return None;
+	}
+	let mut req = Request::new();
+	let debug_commands = req.mut_debug().mut_debug();
+	bot_debug_commands
+		.iter()
+		.for_each(|cmd| debug_commands.push((cmd, version).into_proto()));
+	bot.clear_debug_commands();
+	Some(req)
+}
+pub(crate) fn game_result(res: &Response, player_id: u32) -> GameResult {
+	res.get_observation()
+		.get_player_result()
+		.iter()
+		.find(|r| r.get_player_id() == player_id)
+		.map_or(GameResult::Undecided, |r| r.get_result().into_sc2())
+}
+
+fn play_first_step<B>(bot: &mut B, realtime: bool, pipelined: bool) -> SC2Result<()>
 where
 	B: Player
 {
-	let mut req = Request::new();
-	req.mut_observation().set_disable_fog(bot.bot().borrow().disable_fog);
+	let req = observation_request(&bot.bot().borrow());
 	let res = bot.bot().borrow_mut().api().send(req)?;
 
+	bot.bot().borrow_mut().step_stats = StepStats::default();
 	bot.bot().borrow_mut().init_data_for_unit();
 	update_state(bot, res.get_observation())?;
 	bot.bot().borrow_mut().prepare_start();
 
 	bot.on_start()?;
 
-	let bot = bot.bot();
-	let mut borrowed_bot = bot.borrow_mut();
-	let bot_actions = borrowed_bot.get_actions();
-	if !bot_actions.is_empty() {
-		let mut req = Request::new();
-		let actions = req.mut_action().mut_actions();
-		bot_actions.iter().for_each(|a| actions.push(a.into_proto()));
-		borrowed_bot.clear_actions();
-		borrowed_bot.api().send_request(req)?;
-	}
-	if !realtime {
-		let mut req = Request::new();
-		req.mut_step().set_count(borrowed_bot.game_step.get_locked());
-		borrowed_bot.api().send_request(req)?;
+	send_step_requests(bot, false, realtime, pipelined)
+}
+
+// Plays the game from the first step to the end, returns result of the bot.
+// If game is stopped by limits, it should be left with `LeaveGame` request (after saving replay),
+// in this case `true` is returned together with result.
+fn play_game_loop<B>(
+	bot: &mut B,
+	realtime: bool,
+	pipelined: bool,
+	limits: &GameLimits,
+) -> SC2Result<(GameResult, bool)>
+where
+	B: Player
+{
+	let start = Instant::now();
+	play_first_step(bot, realtime, pipelined)?;
+	let mut iteration = 0;
+	let mut ending = false;
+	loop {
+		if let Some(result) = play_step(bot, iteration, realtime, pipelined)? {
+			return Ok((result, false));
+		}
+		iteration += 1;
+
+		let game_loop = bot.bot().borrow().state.observation.game_loop;
+		if !ending && limits.is_reached(game_loop, start.elapsed()) {
+			info!(
+				"Game limits reached on {} game loop, stopping with {:?}",
+				game_loop, limits.action
+			);
+			let result = match limits.action {
+				LimitAction::Leave => GameResult::Defeat,
+				LimitAction::Tie => GameResult::Tie,
+				LimitAction::EndGame(victory) => {
+					let shared_bot = bot.bot();
+					let mut borrowed_bot = shared_bot.borrow_mut();
+					if victory {
+						borrowed_bot.debug.win_game();
+					} else {
+						borrowed_bot.debug.end_game();
+					}
+					ending = true;
+					continue;
+				}
+			};
+			// Observation is already requested in pipelined mode
+			if pipelined {
+				bot.bot().borrow_mut().api().wait_response()?;
+			}
+			bot.on_end(result)?;
+			return Ok((result, true));
+		}
 	}
-	Ok(())
 }
 
-fn play_step<B>(bot: &mut B, iteration: usize, realtime: bool) -> SC2Result<bool>
+// Returns result of the bot if game is ended
+fn play_step<B>(
+	bot: &mut B,
+	iteration: usize,
+	realtime: bool,
+	pipelined: bool,
+) -> SC2Result<Option<GameResult>>
 where
 	B: Player
 {
-	let mut req = Request::new();
-	req.mut_observation().set_disable_fog(bot.bot().borrow().disable_fog);
-	let res = bot.bot().borrow_mut().api().send(req)?;
+	let start = Instant::now();
+	// In pipelined mode observation is already requested on previous step
+	let res = if pipelined {
+		bot.bot().borrow_mut().api().wait_response()?
+	} else {
+		let req = observation_request(&bot.bot().borrow());
+		bot.bot().borrow_mut().api().send(req)?
+	};
 
 	if matches!(res.get_status(), Status::ended) {
-		let result = res.get_observation().get_player_result()[bot.bot().borrow().player_id as usize - 1]
-			.get_result()
-			.into_sc2();
+		let result = game_result(&res, bot.bot().borrow().player_id);
 		debug!("Result for bot: {:?}", result);
 		bot.on_end(result)?;
-		return Ok(false);
+		return Ok(Some(result));
 	}
 
+	let bot_start = Instant::now();
 	update_state(bot, res.get_observation())?;
 	bot.bot().borrow_mut().prepare_step();
 
 	bot.on_step(iteration)?;
+	let bot_time = bot_start.elapsed();
 
-	let bot = bot.bot();
-	let mut borrowed_bot = bot.borrow_mut();
-	let bot_actions = borrowed_bot.get_actions();
-	if !bot_actions.is_empty() {
-		// println!("{:?}: {:?}", iteration, bot_actions);
-		let mut req = Request::new();
-		let actions = req.mut_action().mut_actions();
-		bot_actions.iter().for_each(|a| actions.push(a.into_proto()));
-		borrowed_bot.clear_actions();
-		borrowed_bot.api().send_request(req)?;
-		/*
-		let res = api.send(req);
-		let results = res.get_action().get_result();
-		if !results.is_empty() {
-			println!("action_results: {:?}", results);
+	send_step_requests(bot, true, realtime, pipelined)?;
+
+	bot.bot()
+		.borrow_mut()
+		.step_stats
+		.record(start.elapsed(), bot_time);
+	Ok(None)
+}
+
+// Sends requests made after step of the bot.
+// In pipelined mode all requests are sent at once together with request of the next observation,
+// and responses are awaited after, except the observation, which is received on the next step.
+fn send_step_requests<B>(bot: &mut B, debug: bool, realtime: bool, pipelined: bool) -> SC2Result<()>
+where
+	B: Player,
+{
+	let shared_bot = bot.bot();
+	shared_bot.borrow_mut().action_results.clear();
+
+	if !pipelined {
+		send_actions(bot)?;
+		let mut borrowed_bot = shared_bot.borrow_mut();
+		if debug {
+			if let Some(req) = debug_request(&mut borrowed_bot) {
+				borrowed_bot.api().send_request(req)?;
+			}
+		}
+		if !realtime {
+			let req = step_request(&borrowed_bot);
+			borrowed_bot.api().send_request(req)?;
 		}
-		*/
+		return Ok(());
 	}
 
-	let bot_debug_commands = borrowed_bot.get_debug_commands();
-	if !bot_debug_commands.is_empty() {
-		let mut req = Request::new();
-		let debug_commands = req.mut_debug().mut_debug();
-		bot_debug_commands
+	let res_actions = {
+		let mut borrowed_bot = shared_bot.borrow_mut();
+		let mut requests = Vec::with_capacity(4);
+		requests.extend(actions_request(&mut borrowed_bot));
+		let has_actions = !requests.is_empty();
+		if debug {
+			requests.extend(debug_request(&mut borrowed_bot));
+		}
+		if !realtime {
+			requests.push(step_request(&borrowed_bot));
+		}
+		let count = requests.len();
+		requests.push(observation_request(&borrowed_bot));
+
+		let api = borrowed_bot.api();
+		for req in requests {
+			api.send_only(req)?;
+		}
+		let mut res_actions = None;
+		for i in 0..count {
+			let res = api.wait_response()?;
+			if i == 0 && has_actions {
+				res_actions = Some(res);
+			}
+		}
+		res_actions
+	};
+	if let Some(res) = res_actions {
+		process_action_results(bot, &res)?;
+	}
+	Ok(())
+}
+
+// Commands given by the bot in response to failed actions are sent immediately,
+// but not more than this number of times on one step
+const MAX_ACTION_ROUNDS: usize = 4;
+
+// Sends actions of the bot and processes results of them.
+fn send_actions<B>(bot: &mut B) -> SC2Result<()>
+where
+	B: Player,
+{
+	let shared_bot = bot.bot();
+	for _ in 0..MAX_ACTION_ROUNDS {
+		let req = match actions_request(&mut shared_bot.borrow_mut()) {
+			Some(req) => req,
+			None => break,
+		};
+		let res = shared_bot.borrow_mut().api().send(req)?;
+		process_action_results(bot, &res)?;
+	}
+	Ok(())
+}
+
+// Matches results of actions with sent actions and reports failed ones to the bot.
+fn process_action_results<B>(bot: &mut B, res: &Response) -> SC2Result<()>
+where
+	B: Player,
+{
+	let failed = {
+		let shared_bot = bot.bot();
+		let mut borrowed_bot = shared_bot.borrow_mut();
+		let outcomes = borrowed_bot
+			.take_sent_actions()
+			.into_iter()
+			.zip(res.get_action().get_result())
+			.map(|(action, result)| ActionOutcome {
+				action,
+				result: ActionResult::from_proto(*result),
+			})
+			.collect::<Vec<_>>();
+		let failed = outcomes
 			.iter()
-			.for_each(|cmd| debug_commands.push(cmd.into_proto()));
-			borrowed_bot.clear_debug_commands();
+			.filter(|o| !o.is_success())
+			.cloned()
+			.collect::<Vec<_>>();
+		borrowed_bot.action_results.extend(outcomes);
+		failed
+	};
+	for outcome in failed {
+		bot.on_event(Event::ActionFailed(outcome))?;
+	}
+	Ok(())
+}
+
+// Plays one step of the game, where step request is only sent, but response isn't awaited.
+// Used to advance several bots in the same game together.
+// `iteration` is `None` on the first step.
+// Returns result of the bot if game is ended.
+fn play_step_lockstep<B>(
+	bot: &mut B,
+	iteration: Option<usize>,
+	realtime: bool,
+) -> SC2Result<Option<GameResult>>
+where
+	B: Player,
+{
+	let req = observation_request(&bot.bot().borrow());
+	let res = bot.bot().borrow_mut().api().send(req)?;
+
+	if matches!(res.get_status(), Status::ended) {
+		let result = game_result(&res, bot.bot().borrow().player_id);
+		debug!("Result for bot: {:?}", result);
+		bot.on_end(result)?;
+		return Ok(Some(result));
+	}
+
+	match iteration {
+		Some(iteration) => {
+			update_state(bot, res.get_observation())?;
+			bot.bot().borrow_mut().prepare_step();
+			bot.on_step(iteration)?;
+		}
+		None => {
+			bot.bot().borrow_mut().init_data_for_unit();
+			update_state(bot, res.get_observation())?;
+			bot.bot().borrow_mut().prepare_start();
+			bot.on_start()?;
+		}
+	}
+
+	bot.bot().borrow_mut().action_results.clear();
+	send_actions(bot)?;
+
+	let bot = bot.bot();
+	let mut borrowed_bot = bot.borrow_mut();
+	// Like in other runners, debug commands aren't sent on the first step
+	if iteration.is_some() {
+		if let Some(req) = debug_request(&mut borrowed_bot) {
 			borrowed_bot.api().send_request(req)?;
+		}
 	}
 	if !realtime {
-		let mut req = Request::new();
-		req.mut_step().set_count(borrowed_bot.game_step.get_locked());
-		borrowed_bot.api().send_request(req)?;
+		let req = step_request(&borrowed_bot);
+		borrowed_bot.api().send_only(req)?;
 	}
-	Ok(true)
+	Ok(None)
 }
 
-fn save_replay(api: &mut API, path: &str) -> SC2Result<()> {
+pub(crate) fn save_replay(api: &mut API, path: &str) -> SC2Result<()> {
 	let mut req = Request::new();
 	req.mut_save_replay();
 
@@ -707,37 +2002,57 @@ fn save_replay(api: &mut API, path: &str) -> SC2Result<()> {
 	Ok(())
 }
 
-fn launch_client(sc2_path: &str, port: i32, sc2_version: Option<&str>) -> SC2Result<Child> {
-	let (base_version, data_hash) = match sc2_version {
-		Some(ver) => get_version_info(ver),
-		None => (get_latest_base_version(sc2_path), ""),
-	};
+pub(crate) fn launch_client(
+	sc2_path: &str,
+	port: i32,
+	sc2_version: Option<&str>,
+	launcher: &Launcher,
+) -> SC2Result<SC2Process> {
+	SC2Process::spawn(launcher.command(sc2_path, port, sc2_version)?, port, launcher)
+}
 
-	let mut process = Command::new(format!(
-		"{}/Versions/Base{}/{}",
-		sc2_path, base_version, SC2_BINARY
-	));
-	process
-		.current_dir(format!("{}/{}", sc2_path, SC2_SUPPORT))
-		.arg("-listen")
-		.arg(HOST)
-		.arg("-port")
-		.arg(port.to_string())
-		// 0 - windowed, 1 - fullscreen
-		.arg("-displayMode")
-		.arg("0");
-	if !data_hash.is_empty() {
-		process.arg("-dataVersion").arg(data_hash);
-	}
-	Ok(process.spawn().expect("Can't launch SC2 process."))
-}
-
-fn connect_to_websocket(host: &str, port: i32) -> SC2Result<WS> {
+// Connection attempts are stopped early if given SC2 process exits
+pub(crate) fn connect_to_websocket(
+	host: &str,
+	port: i32,
+	options: &ConnectOptions,
+	mut process: Option<&mut SC2Process>,
+) -> SC2Result<WS> {
 	let url = Url::parse(&format!("ws://{}:{}/sc2api", host, port))?;
-	let (ws, _rs) = loop {
-		if let Ok(result) = connect(&url) {
-			break result;
+	let addr = (host, port as u16)
+		.to_socket_addrs()?
+		.next()
+		.ok_or_else(|| SC2Error::InvalidArgument(format!("Can't resolve host: {}", host)))?;
+
+	let mut backoff = options.backoff;
+	for attempt in 1..=options.max_attempts {
+		let stream = TcpStream::connect_timeout(&addr, options.timeout).and_then(|stream| {
+			// Limiting handshake time too
+			stream.set_read_timeout(Some(options.timeout))?;
+			Ok(stream)
+		});
+		match stream {
+			Ok(stream) => match client(url.clone(), Stream::Plain(stream)) {
+				Ok((ws, _rs)) => {
+					if let Stream::Plain(stream) = ws.get_ref() {
+						stream.set_read_timeout(options.read_timeout)?;
+					}
+					return Ok(ws);
+				}
+				Err(e) => debug!("Websocket handshake attempt {} failed: {}", attempt, e),
+			},
+			Err(e) => debug!("Connection attempt {} failed: {}", attempt, e),
 		}
-	};
-	Ok(ws)
+		if let Some(process) = &mut process {
+			process.check_running()?;
+		}
+		if attempt < options.max_attempts {
+			thread::sleep(backoff);
+			backoff = options.next_backoff(backoff);
+		}
+	}
+	Err(SC2Error::Timeout(format!(
+		"Can't connect to {} in {} attempts",
+		url, options.max_attempts
+	)))
 }
@@ -16,16 +16,14 @@ pub const FRAMES_PER_SECOND: f32 = 22.4;
 
 /// Units under effect of raven's anit-armor missile have this buff.
 /// It reduces armor and shield armor by 3 (armor can be negative at this point).
-#[cfg(windows)]
+///
+/// On game versions `4.10.x` it's `BuffId::RavenShredderMissileArmorReduction` instead.
 pub const ANTI_ARMOR_BUFF: BuffId = BuffId::RavenShredderMissileArmorReductionUISubtruct;
-#[cfg(unix)]
-pub const ANTI_ARMOR_BUFF: BuffId = BuffId::RavenShredderMissileArmorReduction;
 /// Unit targeted by raven's anit-armor missile have this buff.
 pub const ANTI_ARMOR_TARGET: BuffId = BuffId::RavenShredderMissileTint;
 /// Units disabled by raven's interference matrix have this buff.
 pub const INTERFERENCE_MATRIX_BUFF: BuffId = BuffId::RavenScramblerMissile;
 
-#[cfg(windows)]
 pub(crate) const INHIBITOR_IDS: [UnitTypeId; 6] = [
 	UnitTypeId::InhibitorZoneSmall,
 	UnitTypeId::InhibitorZoneMedium,
@@ -34,12 +32,14 @@ pub(crate) const INHIBITOR_IDS: [UnitTypeId; 6] = [
 	UnitTypeId::InhibitorZoneFlyingMedium,
 	UnitTypeId::InhibitorZoneFlyingLarge,
 ];
-#[cfg(unix)]
-pub(crate) const INHIBITOR_IDS: [UnitTypeId; 3] = [
-	UnitTypeId::InhibitorZoneSmall,
-	UnitTypeId::InhibitorZoneMedium,
-	UnitTypeId::InhibitorZoneLarge,
-];
+
+// Returns buff of raven's anti-armor missile for given version of game data
+pub(crate) fn anti_armor_buff(version: DataVersion) -> BuffId {
+	match version {
+		DataVersion::V4_10 => BuffId::RavenShredderMissileArmorReduction,
+		DataVersion::Latest => ANTI_ARMOR_BUFF,
+	}
+}
 
 /// Structured values, specific for each race.
 #[derive(Clone)]
@@ -682,12 +682,8 @@ lazy_static! {
 			UpgradeId::ZergMissileWeaponsLevel1 => UnitTypeId::EvolutionChamber,
 			UpgradeId::ZergMissileWeaponsLevel2 => UnitTypeId::EvolutionChamber,
 			UpgradeId::ZergMissileWeaponsLevel3 => UnitTypeId::EvolutionChamber,
+			UpgradeId::EnhancedShockwaves => UnitTypeId::GhostAcademy,
 		];
-		#[cfg(windows)]
-		{
-			map.reserve(1);
-			map.insert(UpgradeId::EnhancedShockwaves, UnitTypeId::GhostAcademy);
-		}
 		map
 	};
 
@@ -760,11 +756,10 @@ lazy_static! {
 			UnitTypeId::Roach => (UpgradeId::GlialReconstitution, 1.333_333_4),
 			UnitTypeId::LurkerMP => (UpgradeId::DiggingClaws, 1.1),
 		];
-		if cfg!(windows) {
-			map.reserve(2);
-			map.insert(UnitTypeId::Medivac, (UpgradeId::MedivacRapidDeployment, 1.18));
-			map.insert(UnitTypeId::VoidRay, (UpgradeId::VoidRaySpeedUpgrade, 1.328));
-		}
+		// Upgrades added in 4.11
+		map.reserve(2);
+		map.insert(UnitTypeId::Medivac, (UpgradeId::MedivacRapidDeployment, 1.18));
+		map.insert(UnitTypeId::VoidRay, (UpgradeId::VoidRaySpeedUpgrade, 1.328));
 		map
 	};
 	pub(crate) static ref OFF_CREEP_SPEED_UPGRADES: HashMap<UnitTypeId, (UpgradeId, f32)> = hashmap![
@@ -880,32 +875,29 @@ lazy_static! {
 			UnitTypeId::InhibitorZoneMedium => 5.0,
 			UnitTypeId::InhibitorZoneLarge => 6.0,
 		];
-		#[cfg(windows)]
-		{
-			map.reserve(3);
-			map.insert(UnitTypeId::InhibitorZoneFlyingSmall, 4.0);
-			map.insert(UnitTypeId::InhibitorZoneFlyingMedium, 5.0);
-			map.insert(UnitTypeId::InhibitorZoneFlyingLarge, 6.0);
-		}
+		// Flying zones added in 4.11
+		map.reserve(3);
+		map.insert(UnitTypeId::InhibitorZoneFlyingSmall, 4.0);
+		map.insert(UnitTypeId::InhibitorZoneFlyingMedium, 5.0);
+		map.insert(UnitTypeId::InhibitorZoneFlyingLarge, 6.0);
 		map
 	};
 	pub(crate) static ref SPEED_BUFFS: HashMap<BuffId, f32> = {
 		let mut map = hashmap![
 			BuffId::Stimpack => 1.5,
 			BuffId::StimpackMarauder => 1.5,
-			BuffId::ChargeUp => if cfg!(windows) { 2.8 } else { 2.2 },
+			// 2.2 on versions 4.10.x, handled in `Unit::speed`
+			BuffId::ChargeUp => 2.8,
 			BuffId::DutchMarauderSlow => 0.5,
 			BuffId::TimeWarpProduction => 0.5,
 			BuffId::FungalGrowth => 0.25,
 			BuffId::InhibitorZoneTemporalField => 0.65,
 		];
-		#[cfg(windows)]
-		{
-			map.reserve(3);
-			map.insert(BuffId::InhibitorZoneFlyingTemporalField, 0.65);
-			map.insert(BuffId::AccelerationZoneTemporalField, 1.35);
-			map.insert(BuffId::AccelerationZoneFlyingTemporalField, 1.35);
-		}
+		// Zones added in 4.11
+		map.reserve(3);
+		map.insert(BuffId::InhibitorZoneFlyingTemporalField, 0.65);
+		map.insert(BuffId::AccelerationZoneTemporalField, 1.35);
+		map.insert(BuffId::AccelerationZoneFlyingTemporalField, 1.35);
 		map
 	};
 }
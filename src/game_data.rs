@@ -2,11 +2,10 @@
 #![allow(missing_docs)]
 
 use crate::{
-	ids::{AbilityId, BuffId, EffectId, UnitTypeId, UpgradeId},
+	ids::{AbilityId, BuffId, DataVersion, EffectId, UnitTypeId, UpgradeId},
 	player::Race,
 	FromProto, TryFromProto,
 };
-use num_traits::FromPrimitive;
 use rustc_hash::FxHashMap;
 use sc2_proto::{
 	data::{
@@ -32,33 +31,33 @@ pub struct GameData {
 	/// Information about effects mapped to `EffectId`s.
 	pub effects: FxHashMap<EffectId, EffectData>,
 }
-impl FromProto<ResponseData> for GameData {
-	fn from_proto(data: ResponseData) -> Self {
+impl FromProto<(ResponseData, DataVersion)> for GameData {
+	fn from_proto((data, version): (ResponseData, DataVersion)) -> Self {
 		Self {
 			abilities: data
 				.get_abilities()
 				.iter()
-				.filter_map(|a| AbilityData::try_from_proto(a).map(|data| (data.id, data)))
+				.filter_map(|a| AbilityData::try_from_proto((a, version)).map(|data| (data.id, data)))
 				.collect(),
 			units: data
 				.get_units()
 				.iter()
-				.filter_map(|u| UnitTypeData::try_from_proto(u).map(|data| (data.id, data)))
+				.filter_map(|u| UnitTypeData::try_from_proto((u, version)).map(|data| (data.id, data)))
 				.collect(),
 			upgrades: data
 				.get_upgrades()
 				.iter()
-				.filter_map(|u| UpgradeData::try_from_proto(u).map(|data| (data.id, data)))
+				.filter_map(|u| UpgradeData::try_from_proto((u, version)).map(|data| (data.id, data)))
 				.collect(),
 			buffs: data
 				.get_buffs()
 				.iter()
-				.filter_map(|b| BuffData::try_from_proto(b).map(|data| (data.id, data)))
+				.filter_map(|b| BuffData::try_from_proto((b, version)).map(|data| (data.id, data)))
 				.collect(),
 			effects: data
 				.get_effects()
 				.iter()
-				.filter_map(|e| EffectData::try_from_proto(e).map(|data| (data.id, data)))
+				.filter_map(|e| EffectData::try_from_proto((e, version)).map(|data| (data.id, data)))
 				.collect(),
 		}
 	}
@@ -205,16 +204,18 @@ pub struct AbilityData {
 	/// Maximum range to target of the ability.
 	pub cast_range: Option<f32>,
 }
-impl TryFromProto<&ProtoAbilityData> for AbilityData {
-	fn try_from_proto(a: &ProtoAbilityData) -> Option<Self> {
+impl TryFromProto<(&ProtoAbilityData, DataVersion)> for AbilityData {
+	fn try_from_proto((a, version): (&ProtoAbilityData, DataVersion)) -> Option<Self> {
 		Some(Self {
-			id: AbilityId::from_u32(a.get_ability_id())?,
+			id: AbilityId::from_raw(a.get_ability_id(), version)?,
 			link_name: a.get_link_name().to_string(),
 			link_index: a.get_link_index(),
 			button_name: a.button_name.as_ref().cloned(),
 			friendly_name: a.friendly_name.as_ref().cloned(),
 			hotkey: a.hotkey.as_ref().cloned(),
-			remaps_to_ability_id: a.remaps_to_ability_id.and_then(AbilityId::from_u32),
+			remaps_to_ability_id: a
+				.remaps_to_ability_id
+				.and_then(|id| AbilityId::from_raw(id, version)),
 			available: a.get_available(),
 			target: AbilityTarget::from_proto(a.get_target()),
 			allow_minimap: a.get_allow_minimap(),
@@ -269,10 +270,10 @@ impl UnitTypeData {
 		}
 	}
 }
-impl TryFromProto<&ProtoUnitTypeData> for UnitTypeData {
-	fn try_from_proto(u: &ProtoUnitTypeData) -> Option<Self> {
+impl TryFromProto<(&ProtoUnitTypeData, DataVersion)> for UnitTypeData {
+	fn try_from_proto((u, version): (&ProtoUnitTypeData, DataVersion)) -> Option<Self> {
 		Some(Self {
-			id: UnitTypeId::from_u32(u.get_unit_id())?,
+			id: UnitTypeId::from_raw(u.get_unit_id(), version)?,
 			name: u.get_name().to_string(),
 			available: u.get_available(),
 			cargo_size: u.get_cargo_size(),
@@ -280,7 +281,7 @@ impl TryFromProto<&ProtoUnitTypeData> for UnitTypeData {
 			vespene_cost: u.get_vespene_cost(),
 			food_required: u.get_food_required(),
 			food_provided: u.get_food_provided(),
-			ability: u.ability_id.and_then(AbilityId::from_u32),
+			ability: u.ability_id.and_then(|id| AbilityId::from_raw(id, version)),
 			race: Race::from_proto(u.get_race()),
 			build_time: u.get_build_time(),
 			has_vespene: u.get_has_vespene(),
@@ -289,10 +290,12 @@ impl TryFromProto<&ProtoUnitTypeData> for UnitTypeData {
 			tech_alias: u
 				.get_tech_alias()
 				.iter()
-				.filter_map(|a| UnitTypeId::from_u32(*a))
+				.filter_map(|a| UnitTypeId::from_raw(*a, version))
 				.collect(),
-			unit_alias: u.unit_alias.and_then(UnitTypeId::from_u32),
-			tech_requirement: u.tech_requirement.and_then(UnitTypeId::from_u32),
+			unit_alias: u.unit_alias.and_then(|id| UnitTypeId::from_raw(id, version)),
+			tech_requirement: u
+				.tech_requirement
+				.and_then(|id| UnitTypeId::from_raw(id, version)),
 			require_attached: u.get_require_attached(),
 			attributes: u
 				.get_attributes()
@@ -327,11 +330,11 @@ impl UpgradeData {
 		}
 	}
 }
-impl TryFromProto<&ProtoUpgradeData> for UpgradeData {
-	fn try_from_proto(u: &ProtoUpgradeData) -> Option<Self> {
+impl TryFromProto<(&ProtoUpgradeData, DataVersion)> for UpgradeData {
+	fn try_from_proto((u, version): (&ProtoUpgradeData, DataVersion)) -> Option<Self> {
 		Some(Self {
-			id: UpgradeId::from_u32(u.get_upgrade_id())?,
-			ability: AbilityId::from_u32(u.get_ability_id())?,
+			id: UpgradeId::from_raw(u.get_upgrade_id(), version)?,
+			ability: AbilityId::from_raw(u.get_ability_id(), version)?,
 			name: u.get_name().to_string(),
 			mineral_cost: u.get_mineral_cost(),
 			vespene_cost: u.get_vespene_cost(),
@@ -346,10 +349,10 @@ pub struct BuffData {
 	pub id: BuffId,
 	pub name: String,
 }
-impl TryFromProto<&ProtoBuffData> for BuffData {
-	fn try_from_proto(b: &ProtoBuffData) -> Option<Self> {
+impl TryFromProto<(&ProtoBuffData, DataVersion)> for BuffData {
+	fn try_from_proto((b, version): (&ProtoBuffData, DataVersion)) -> Option<Self> {
 		Some(Self {
-			id: BuffId::from_u32(b.get_buff_id())?,
+			id: BuffId::from_raw(b.get_buff_id(), version)?,
 			name: b.get_name().to_string(),
 		})
 	}
@@ -367,9 +370,9 @@ pub struct EffectData {
 	/// `true` if effect affects allied units.
 	pub friendly_fire: bool,
 }
-impl TryFromProto<&ProtoEffectData> for EffectData {
-	fn try_from_proto(e: &ProtoEffectData) -> Option<Self> {
-		EffectId::from_u32(e.get_effect_id()).map(|id| Self {
+impl TryFromProto<(&ProtoEffectData, DataVersion)> for EffectData {
+	fn try_from_proto((e, version): (&ProtoEffectData, DataVersion)) -> Option<Self> {
+		EffectId::from_raw(e.get_effect_id(), version).map(|id| Self {
 			id,
 			name: e.get_name().to_string(),
 			friendly_name: e.get_friendly_name().to_string(),
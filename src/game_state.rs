@@ -5,18 +5,18 @@ use crate::{
 	bot::{Bot, Locked, Rs, Rw},
 	geometry::Point2,
 	ids::*,
-	pixel_map::{PixelMap, VisibilityMap},
+	pixel_map::{ByteMap, IntMap, PixelMap, RgbMap, VisibilityMap},
 	score::Score,
 	unit::Unit,
 	units::Units,
 	Event, FromProto, Player, SC2Result,
 };
-use num_traits::FromPrimitive;
 use rustc_hash::FxHashSet;
 use sc2_proto::{
 	query::RequestQueryAvailableAbilities,
 	raw::{Alliance as ProtoAlliance, PowerSource as ProtoPowerSource},
 	sc2api::{Alert as ProtoAlert, Request, ResponseObservation},
+	spatial::{FeatureLayers, FeatureLayersMinimap, ObservationRender},
 };
 use std::ops::{Deref, DerefMut};
 
@@ -43,18 +43,19 @@ where
 	// Game state
 	let _bot = bot.bot();
 	let mut borrowed_bot = _bot.borrow_mut();
+	let version = borrowed_bot.data_version;
 	let state = &mut borrowed_bot.state;
 
 	// let player_result = response_observation.get_player_result();
 	state.actions = response_observation
 		.get_actions()
 		.iter()
-		.filter_map(|a| Option::<Action>::from_proto(a))
+		.filter_map(|a| Option::<Action>::from_proto((a, version)))
 		.collect();
 	state.action_errors = response_observation
 		.get_action_errors()
 		.iter()
-		.map(|e| ActionError::from_proto(e))
+		.map(|e| ActionError::from_proto((e, version)))
 		.collect();
 	state.chat = response_observation
 		.get_chat()
@@ -79,11 +80,25 @@ where
 		.get_abilities()
 		.iter()
 		.map(|a| AvailableAbility {
-			id: AbilityId::from_i32(a.get_ability_id()).unwrap(),
+			id: AbilityId::from_raw(a.get_ability_id() as u32, version).unwrap(),
 			requires_point: a.get_requires_point(),
 		})
 		.collect();
 	obs.score = Score::from_proto(res_obs.get_score());
+	obs.feature_layer = if res_obs.has_feature_layer_data() {
+		let data = res_obs.get_feature_layer_data();
+		Some(FeatureLayerData {
+			screen: ScreenLayers::from_proto(data.get_renders()),
+			minimap: MinimapLayers::from_proto(data.get_minimap_renders()),
+		})
+	} else {
+		None
+	};
+	obs.render = if res_obs.has_render_data() {
+		Some(RenderData::from_proto(res_obs.get_render_data()))
+	} else {
+		None
+	};
 
 	// Common
 	let common = res_obs.get_player_common();
@@ -116,7 +131,7 @@ where
 		.get_effects()
 		.iter()
 		.map(|e| Effect {
-			id: EffectId::from_u32(e.get_effect_id()).unwrap(),
+			id: EffectId::from_raw(e.get_effect_id(), version).unwrap(),
 			positions: e.get_pos().iter().map(Point2::from_proto).collect(),
 			alliance: Alliance::from_proto(e.get_alliance()),
 			owner: e.get_owner() as u32,
@@ -176,7 +191,7 @@ where
 	*raw.upgrades.write_lock() = raw_player
 		.get_upgrade_ids()
 		.iter()
-		.map(|u| UpgradeId::from_u32(*u).unwrap())
+		.map(|u| UpgradeId::from_raw(*u, version).unwrap())
 		.collect::<FxHashSet<_>>();
 
 	// Map
@@ -205,7 +220,7 @@ where
 				a.get_unit_tag(),
 				a.get_abilities()
 					.iter()
-					.filter_map(|ab| AbilityId::from_i32(ab.get_ability_id()))
+					.filter_map(|ab| AbilityId::from_raw(ab.get_ability_id() as u32, version))
 					.collect(),
 			)
 		})
@@ -309,6 +324,12 @@ pub struct Observation {
 	pub score: Score,
 	/// Data of raw interface.
 	pub raw: RawData,
+	/// Feature layers, `None` if not enabled with
+	/// [`PlayerSettings::with_feature_layer`](crate::PlayerSettings::with_feature_layer).
+	pub feature_layer: Option<FeatureLayerData>,
+	/// Rendered images, `None` if not enabled with
+	/// [`PlayerSettings::with_render`](crate::PlayerSettings::with_render).
+	pub render: Option<RenderData>,
 }
 
 /// Bot's observation stored here.
@@ -333,6 +354,142 @@ pub struct RawData {
 	pub radars: Vec<Radar>,
 }
 
+/// Spatial observation in the form of feature layers (like in `pysc2`).
+/// Can be accessed through [`state.observation.feature_layer`](Observation::feature_layer).
+///
+/// Layers are indexed by `(x, y)` pixel of screen or minimap.
+/// 1-bit layers are stored as [`PixelMap`], where [`Empty`](crate::pixel_map::Pixel::Empty)
+/// means bit is set (the same as [`creep`](RawData::creep)).
+/// Layers which are absent in observation are empty arrays.
+#[derive(Default, Clone)]
+pub struct FeatureLayerData {
+	/// Layers of camera view.
+	pub screen: ScreenLayers,
+	/// Layers of minimap.
+	pub minimap: MinimapLayers,
+}
+
+/// Feature layers of camera view.
+#[allow(missing_docs)]
+#[derive(Default, Clone)]
+pub struct ScreenLayers {
+	pub height_map: ByteMap,
+	pub visibility_map: ByteMap,
+	pub creep: PixelMap,
+	pub power: PixelMap,
+	pub player_id: ByteMap,
+	pub player_relative: ByteMap,
+	/// Unit type ids, can be converted with `UnitTypeId::from_raw` using bot's `data_version`.
+	pub unit_type: IntMap,
+	pub selected: PixelMap,
+	pub unit_hit_points: IntMap,
+	pub unit_hit_points_ratio: ByteMap,
+	pub unit_energy: IntMap,
+	pub unit_energy_ratio: ByteMap,
+	pub unit_shields: IntMap,
+	pub unit_shields_ratio: ByteMap,
+	pub unit_density: ByteMap,
+	pub unit_density_aa: ByteMap,
+	pub effects: ByteMap,
+	pub hallucinations: PixelMap,
+	pub cloaked: PixelMap,
+	pub blip: PixelMap,
+	pub buffs: IntMap,
+	pub buff_duration: ByteMap,
+	pub active: PixelMap,
+	pub build_progress: ByteMap,
+	pub buildable: PixelMap,
+	pub pathable: PixelMap,
+	pub placeholder: PixelMap,
+}
+impl FromProto<&FeatureLayers> for ScreenLayers {
+	fn from_proto(layers: &FeatureLayers) -> Self {
+		Self {
+			height_map: ByteMap::from_proto(layers.get_height_map()),
+			visibility_map: ByteMap::from_proto(layers.get_visibility_map()),
+			creep: PixelMap::from_proto(layers.get_creep()),
+			power: PixelMap::from_proto(layers.get_power()),
+			player_id: ByteMap::from_proto(layers.get_player_id()),
+			player_relative: ByteMap::from_proto(layers.get_player_relative()),
+			unit_type: IntMap::from_proto(layers.get_unit_type()),
+			selected: PixelMap::from_proto(layers.get_selected()),
+			unit_hit_points: IntMap::from_proto(layers.get_unit_hit_points()),
+			unit_hit_points_ratio: ByteMap::from_proto(layers.get_unit_hit_points_ratio()),
+			unit_energy: IntMap::from_proto(layers.get_unit_energy()),
+			unit_energy_ratio: ByteMap::from_proto(layers.get_unit_energy_ratio()),
+			unit_shields: IntMap::from_proto(layers.get_unit_shields()),
+			unit_shields_ratio: ByteMap::from_proto(layers.get_unit_shields_ratio()),
+			unit_density: ByteMap::from_proto(layers.get_unit_density()),
+			unit_density_aa: ByteMap::from_proto(layers.get_unit_density_aa()),
+			effects: ByteMap::from_proto(layers.get_effects()),
+			hallucinations: PixelMap::from_proto(layers.get_hallucinations()),
+			cloaked: PixelMap::from_proto(layers.get_cloaked()),
+			blip: PixelMap::from_proto(layers.get_blip()),
+			buffs: IntMap::from_proto(layers.get_buffs()),
+			buff_duration: ByteMap::from_proto(layers.get_buff_duration()),
+			active: PixelMap::from_proto(layers.get_active()),
+			build_progress: ByteMap::from_proto(layers.get_build_progress()),
+			buildable: PixelMap::from_proto(layers.get_buildable()),
+			pathable: PixelMap::from_proto(layers.get_pathable()),
+			placeholder: PixelMap::from_proto(layers.get_placeholder()),
+		}
+	}
+}
+
+/// Feature layers of minimap.
+#[allow(missing_docs)]
+#[derive(Default, Clone)]
+pub struct MinimapLayers {
+	pub height_map: ByteMap,
+	pub visibility_map: ByteMap,
+	pub creep: PixelMap,
+	pub camera: PixelMap,
+	pub player_id: ByteMap,
+	pub player_relative: ByteMap,
+	pub selected: PixelMap,
+	/// Available only with [`allow_cheating_layers`](crate::SpatialOptions::allow_cheating_layers).
+	pub alerts: PixelMap,
+	pub buildable: PixelMap,
+	pub pathable: PixelMap,
+	/// Unit type ids, can be converted with `UnitTypeId::from_raw` using bot's `data_version`.
+	pub unit_type: IntMap,
+}
+impl FromProto<&FeatureLayersMinimap> for MinimapLayers {
+	fn from_proto(layers: &FeatureLayersMinimap) -> Self {
+		Self {
+			height_map: ByteMap::from_proto(layers.get_height_map()),
+			visibility_map: ByteMap::from_proto(layers.get_visibility_map()),
+			creep: PixelMap::from_proto(layers.get_creep()),
+			camera: PixelMap::from_proto(layers.get_camera()),
+			player_id: ByteMap::from_proto(layers.get_player_id()),
+			player_relative: ByteMap::from_proto(layers.get_player_relative()),
+			selected: PixelMap::from_proto(layers.get_selected()),
+			alerts: PixelMap::from_proto(layers.get_alerts()),
+			buildable: PixelMap::from_proto(layers.get_buildable()),
+			pathable: PixelMap::from_proto(layers.get_pathable()),
+			unit_type: IntMap::from_proto(layers.get_unit_type()),
+		}
+	}
+}
+
+/// Rendered images of the game.
+/// Can be accessed through [`state.observation.render`](Observation::render).
+#[derive(Default, Clone)]
+pub struct RenderData {
+	/// Image of camera view.
+	pub map: RgbMap,
+	/// Image of minimap.
+	pub minimap: RgbMap,
+}
+impl FromProto<&ObservationRender> for RenderData {
+	fn from_proto(render: &ObservationRender) -> Self {
+		Self {
+			map: RgbMap::from_proto(render.get_map()),
+			minimap: RgbMap::from_proto(render.get_minimap()),
+		}
+	}
+}
+
 /// Power matrix from the pylon or warp prism, used to give power to buildings and warp units on it.
 #[derive(Clone)]
 pub struct PsionicMatrix {
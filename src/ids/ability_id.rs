@@ -3,9 +3,8 @@
 #[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
-#[cfg(target_os = "windows")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, FromPrimitive, ToPrimitive, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, FromPrimitive, ToPrimitive, FromStr, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum AbilityId {
 	NullNull = 0,
 	Smart = 1,
@@ -1288,1260 +1287,3 @@ pub enum AbilityId {
 	ShieldBatteryRechargeEx5ShieldBatteryRecharge = 4111,
 	ShieldBatteryRechargeEx5Stop = 4112,
 }
-
-#[cfg(target_os = "linux")]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, FromPrimitive, ToPrimitive, Copy, Clone, PartialEq, Eq, Hash)]
-pub enum AbilityId {
-	NullNull = 0,
-	Smart = 1,
-	TauntTaunt = 2,
-	StopStop = 4,
-	StopHoldFireSpecial = 5,
-	StopCheer = 6,
-	StopDance = 7,
-	HoldFireStopSpecial = 10,
-	HoldFireHoldFire = 11,
-	MoveMove = 16,
-	PatrolPatrol = 17,
-	HoldPositionHold = 18,
-	ScanMove = 19,
-	MoveTurn = 20,
-	BeaconCancel = 21,
-	BeaconBeaconMove = 22,
-	AttackAttack = 23,
-	AttackAttackTowards = 24,
-	AttackAttackBarrage = 25,
-	EffectSprayTerran = 26,
-	EffectSprayZerg = 28,
-	EffectSprayProtoss = 30,
-	EffectSalvage = 32,
-	CorruptionCorruptionAbility = 34,
-	CorruptionCancel = 35,
-	BehaviorHoldFireOnGhost = 36,
-	BehaviorHoldFireOffGhost = 38,
-	MorphToInfestedTerranInfestedTerrans = 40,
-	ExplodeExplode = 42,
-	ResearchInterceptorGravitonCatapult = 44,
-	FleetBeaconResearchInterceptorLaunchSpeedUpgrade = 45,
-	ResearchPhoenixAnionPulseCrystals = 46,
-	FleetBeaconResearchTempestRangeUpgrade = 47,
-	FungalGrowthFungalGrowth = 74,
-	GuardianShieldGuardianShield = 76,
-	EffectRepairMule = 78,
-	MorphZerglingToBanelingBaneling = 80,
-	NexusTrainMothershipMothership = 110,
-	FeedbackFeedback = 140,
-	EffectMassRecallStrategicRecall = 142,
-	PlacePointDefenseDronePointDefenseDrone = 144,
-	HallucinationArchon = 146,
-	HallucinationColossus = 148,
-	HallucinationHighTemplar = 150,
-	HallucinationImmortal = 152,
-	HallucinationPhoenix = 154,
-	HallucinationProbe = 156,
-	HallucinationStalker = 158,
-	HallucinationVoidRay = 160,
-	HallucinationWarpPrism = 162,
-	HallucinationZealot = 164,
-	HarvestGatherMule = 166,
-	HarvestReturnMule = 167,
-	SeekerMissileHunterSeekerMissile = 169,
-	CalldownMULECalldownMULE = 171,
-	GravitonBeamGravitonBeam = 173,
-	CancelGravitonBeam = 174,
-	BuildinProgressNydusCanalCancel = 176,
-	SiphonSiphon = 177,
-	SiphonCancel = 178,
-	LeechLeech = 179,
-	SpawnChangelingSpawnChangeling = 181,
-	DisguiseAsZealotZealot = 183,
-	DisguiseAsMarineWithShieldMarine = 185,
-	DisguiseAsMarineWithoutShieldMarine = 187,
-	DisguiseAsZerglingWithWingsZergling = 189,
-	DisguiseAsZerglingWithoutWingsZergling = 191,
-	PhaseShiftPhaseShift = 193,
-	RallyBuilding = 195,
-	RallyMorphingUnit = 199,
-	RallyCommandCenter = 203,
-	RallyNexus = 207,
-	RallyHatcheryUnits = 211,
-	RallyHatcheryWorkers = 212,
-	ResearchGlialRegeneration = 216,
-	ResearchTunnelingClaws = 217,
-	RoachWarrenResearchRoachSupply = 218,
-	SapStructureSapStructure = 245,
-	InfestedTerransInfestedTerrans = 247,
-	NeuralParasiteNeuralParasite = 249,
-	CancelNeuralParasite = 250,
-	EffectInjectLarva = 251,
-	EffectStimMarauder = 253,
-	SupplyDropSupplyDrop = 255,
-	_250mmStrikeCannons250mmStrikeCannons = 257,
-	_250mmStrikeCannonsCancel = 258,
-	TemporalRiftTemporalRift = 259,
-	EffectChronoBoost = 261,
-	ResearchAnabolicSynthesis = 263,
-	ResearchChitinousPlating = 265,
-	WormholeTransitWormholeTransit = 293,
-	HarvestGatherSCV = 295,
-	HarvestReturnSCV = 296,
-	HarvestGatherProbe = 298,
-	HarvestReturnProbe = 299,
-	AttackWarpPrismAttackWarpPrism = 301,
-	AttackWarpPrismAttackTowards = 302,
-	AttackWarpPrismAttackBarrage = 303,
-	CancelQueue1 = 304,
-	CancelSlotQueue1 = 305,
-	CancelQueue5 = 306,
-	CancelSlotQueue5 = 307,
-	CancelQueueCancelToSelection = 308,
-	CancelSlotQueueCancelToSelection = 309,
-	Que5LongBlendCancel = 310,
-	Que5LongBlendCancelSlot = 311,
-	CancelQueueAddOn = 312,
-	CancelSlotAddon = 313,
-	CancelBuildInProgress = 314,
-	HaltBuilding = 315,
-	EffectRepairSCV = 316,
-	TerranBuildCommandCenter = 318,
-	TerranBuildSupplyDepot = 319,
-	TerranBuildRefinery = 320,
-	TerranBuildBarracks = 321,
-	TerranBuildEngineeringBay = 322,
-	TerranBuildMissileTurret = 323,
-	TerranBuildBunker = 324,
-	TerranBuildSensorTower = 326,
-	TerranBuildGhostAcademy = 327,
-	TerranBuildFactory = 328,
-	TerranBuildStarport = 329,
-	TerranBuildArmory = 331,
-	TerranBuildFusionCore = 333,
-	HaltTerranBuild = 348,
-	RavenBuildAutoTurret = 349,
-	RavenBuildCancel = 379,
-	EffectStimMarine = 380,
-	BehaviorCloakOnGhost = 382,
-	BehaviorCloakOffGhost = 383,
-	SnipeSnipe = 384,
-	MedivacHealHeal = 386,
-	SiegeModeSiegeMode = 388,
-	UnsiegeUnsiege = 390,
-	BehaviorCloakOnBanshee = 392,
-	BehaviorCloakOffBanshee = 393,
-	LoadMedivac = 394,
-	UnloadAllAtMedivac = 396,
-	UnloadUnitMedivac = 397,
-	ScannerSweepScan = 399,
-	YamatoYamatoGun = 401,
-	MorphVikingAssaultMode = 403,
-	MorphVikingFighterMode = 405,
-	LoadBunker = 407,
-	UnloadAllBunker = 408,
-	UnloadUnitBunker = 410,
-	UnloadAllCommandCenter = 413,
-	UnloadUnitCommandCenter = 415,
-	LoadAllCommandCenter = 416,
-	LiftCommandCenter = 417,
-	LandCommandCenter = 419,
-	BuildTechLabBarracks = 421,
-	BuildReactorBarracks = 422,
-	CancelBarracksAddOn = 451,
-	LiftBarracks = 452,
-	BuildTechLabFactory = 454,
-	BuildReactorFactory = 455,
-	CancelFactoryAddOn = 484,
-	LiftFactory = 485,
-	BuildTechLabStarport = 487,
-	BuildReactorStarport = 488,
-	CancelStarportAddOn = 517,
-	LiftStarport = 518,
-	LandFactory = 520,
-	LandStarport = 522,
-	CommandCenterTrainSCV = 524,
-	LandBarracks = 554,
-	MorphSupplyDepotLower = 556,
-	MorphSupplyDepotRaise = 558,
-	BarracksTrainMarine = 560,
-	BarracksTrainReaper = 561,
-	BarracksTrainGhost = 562,
-	BarracksTrainMarauder = 563,
-	FactoryTrainSiegeTank = 591,
-	FactoryTrainThor = 594,
-	FactoryTrainHellion = 595,
-	TrainHellbat = 596,
-	TrainCyclone = 597,
-	FactoryTrainWidowMine = 614,
-	StarportTrainMedivac = 620,
-	StarportTrainBanshee = 621,
-	StarportTrainRaven = 622,
-	StarportTrainBattlecruiser = 623,
-	StarportTrainVikingFighter = 624,
-	StarportTrainLiberator = 626,
-	ResearchHiSecAutoTracking = 650,
-	ResearchTerranStructureArmorUpgrade = 651,
-	EngineeringBayResearchTerranInfantryWeaponsLevel1 = 652,
-	EngineeringBayResearchTerranInfantryWeaponsLevel2 = 653,
-	EngineeringBayResearchTerranInfantryWeaponsLevel3 = 654,
-	ResearchNeosteelFrame = 655,
-	EngineeringBayResearchTerranInfantryArmorLevel1 = 656,
-	EngineeringBayResearchTerranInfantryArmorLevel2 = 657,
-	EngineeringBayResearchTerranInfantryArmorLevel3 = 658,
-	MercCompoundResearchReaperSpeed = 683,
-	BuildNuke = 710,
-	BarracksTechLabResearchStimpack = 730,
-	ResearchCombatShield = 731,
-	ResearchConcussiveShells = 732,
-	ResearchInfernalPreigniter = 761,
-	FactoryTechLabResearchTransformationServos = 763,
-	ResearchDrillingClaws = 764,
-	FactoryTechLabResearchLockOnRangeUpgrade = 765,
-	ResearchSmartServos = 766,
-	FactoryTechLabResearchArmorPiercingRockets = 767,
-	ResearchCycloneRapidFireLaunchers = 768,
-	ResearchCycloneLockOnDamage = 769,
-	ResearchBansheeCloakingField = 790,
-	StarportTechLabResearchMedivacEnergyUpgrade = 792,
-	ResearchRavenCorvidReactor = 793,
-	StarportTechLabResearchSeekerMissile = 796,
-	StarportTechLabResearchDurableMaterials = 797,
-	ResearchBansheeHyperflightRotors = 799,
-	StarportTechLabResearchLiberatorAGMode = 800,
-	StarportTechLabResearchRapidDeployment = 802,
-	ResearchRavenRecalibratedExplosives = 803,
-	ResearchHighCapacityFuelTanks = 804,
-	ResearchAdvancedBallistics = 805,
-	StarportTechLabResearchRavenResearchEnhancedMunitions = 806,
-	ResearchPersonalCloaking = 820,
-	GhostAcademyResearchGhostEnergyUpgrade = 821,
-	ArmoryResearchTerranVehiclePlatingLevel1 = 852,
-	ArmoryResearchTerranVehiclePlatingLevel2 = 853,
-	ArmoryResearchTerranVehiclePlatingLevel3 = 854,
-	ArmoryResearchTerranVehicleWeaponsLevel1 = 855,
-	ArmoryResearchTerranVehicleWeaponsLevel2 = 856,
-	ArmoryResearchTerranVehicleWeaponsLevel3 = 857,
-	ArmoryResearchTerranShipPlatingLevel1 = 858,
-	ArmoryResearchTerranShipPlatingLevel2 = 859,
-	ArmoryResearchTerranShipPlatingLevel3 = 860,
-	ArmoryResearchTerranShipWeaponsLevel1 = 861,
-	ArmoryResearchTerranShipWeaponsLevel2 = 862,
-	ArmoryResearchTerranShipWeaponsLevel3 = 863,
-	ArmoryResearchTerranVehicleAndShipPlatingLevel1 = 864,
-	ArmoryResearchTerranVehicleAndShipPlatingLevel2 = 865,
-	ArmoryResearchTerranVehicleAndShipPlatingLevel3 = 866,
-	ProtossBuildNexus = 880,
-	ProtossBuildPylon = 881,
-	ProtossBuildAssimilator = 882,
-	ProtossBuildGateway = 883,
-	ProtossBuildForge = 884,
-	ProtossBuildFleetBeacon = 885,
-	ProtossBuildTwilightCouncil = 886,
-	ProtossBuildPhotonCannon = 887,
-	ProtossBuildStargate = 889,
-	ProtossBuildTemplarArchive = 890,
-	ProtossBuildDarkShrine = 891,
-	ProtossBuildRoboticsBay = 892,
-	ProtossBuildRoboticsFacility = 893,
-	ProtossBuildCyberneticsCore = 894,
-	BuildShieldBattery = 895,
-	ProtossBuildCancel = 910,
-	LoadWarpPrism = 911,
-	UnloadAllWarpPrism = 912,
-	UnloadAllAtWarpPrism = 913,
-	UnloadUnitWarpPrism = 914,
-	GatewayTrainZealot = 916,
-	GatewayTrainStalker = 917,
-	GatewayTrainHighTemplar = 919,
-	GatewayTrainDarkTemplar = 920,
-	GatewayTrainSentry = 921,
-	TrainAdept = 922,
-	StargateTrainPhoenix = 946,
-	StargateTrainCarrier = 948,
-	StargateTrainVoidRay = 950,
-	StargateTrainOracle = 954,
-	StargateTrainTempest = 955,
-	RoboticsFacilityTrainWarpPrism = 976,
-	RoboticsFacilityTrainObserver = 977,
-	RoboticsFacilityTrainColossus = 978,
-	RoboticsFacilityTrainImmortal = 979,
-	TrainDisruptor = 994,
-	NexusTrainProbe = 1006,
-	PsiStormPsiStorm = 1036,
-	CancelHangarQueue5 = 1038,
-	CancelSlotHangarQueue5 = 1039,
-	BroodLordQueue2Cancel = 1040,
-	BroodLordQueue2CancelSlot = 1041,
-	BuildInterceptors = 1042,
-	ForgeResearchProtossGroundWeaponsLevel1 = 1062,
-	ForgeResearchProtossGroundWeaponsLevel2 = 1063,
-	ForgeResearchProtossGroundWeaponsLevel3 = 1064,
-	ForgeResearchProtossGroundArmorLevel1 = 1065,
-	ForgeResearchProtossGroundArmorLevel2 = 1066,
-	ForgeResearchProtossGroundArmorLevel3 = 1067,
-	ForgeResearchProtossShieldsLevel1 = 1068,
-	ForgeResearchProtossShieldsLevel2 = 1069,
-	ForgeResearchProtossShieldsLevel3 = 1070,
-	ResearchGraviticBooster = 1093,
-	ResearchGraviticDrive = 1094,
-	ResearchExtendedThermalLance = 1097,
-	RoboticsBayResearchImmortalRevive = 1099,
-	ResearchPsiStorm = 1126,
-	ZergBuildHatchery = 1152,
-	ZergBuildCreepTumor = 1153,
-	ZergBuildExtractor = 1154,
-	ZergBuildSpawningPool = 1155,
-	ZergBuildEvolutionChamber = 1156,
-	ZergBuildHydraliskDen = 1157,
-	ZergBuildSpire = 1158,
-	ZergBuildUltraliskCavern = 1159,
-	ZergBuildInfestationPit = 1160,
-	ZergBuildNydusNetwork = 1161,
-	ZergBuildBanelingNest = 1162,
-	BuildLurkerDen = 1163,
-	ZergBuildRoachWarren = 1165,
-	ZergBuildSpineCrawler = 1166,
-	ZergBuildSporeCrawler = 1167,
-	ZergBuildCancel = 1182,
-	HarvestGatherDrone = 1183,
-	HarvestReturnDrone = 1184,
-	ResearchZergMeleeWeaponsLevel1 = 1186,
-	ResearchZergMeleeWeaponsLevel2 = 1187,
-	ResearchZergMeleeWeaponsLevel3 = 1188,
-	ResearchZergGroundArmorLevel1 = 1189,
-	ResearchZergGroundArmorLevel2 = 1190,
-	ResearchZergGroundArmorLevel3 = 1191,
-	ResearchZergMissileWeaponsLevel1 = 1192,
-	ResearchZergMissileWeaponsLevel2 = 1193,
-	ResearchZergMissileWeaponsLevel3 = 1194,
-	UpgradeToLairLair = 1216,
-	CancelMorphLair = 1217,
-	UpgradeToHiveHive = 1218,
-	CancelMorphHive = 1219,
-	UpgradeToGreaterSpireGreaterSpire = 1220,
-	CancelMorphGreaterSpire = 1221,
-	ResearchPneumatizedCarapace = 1223,
-	LairResearchEvolveVentralSacks = 1224,
-	ResearchBurrow = 1225,
-	ResearchZerglingAdrenalGlands = 1252,
-	ResearchZerglingMetabolicBoost = 1253,
-	ResearchGroovedSpines = 1282,
-	ResearchMuscularAugments = 1283,
-	HydraliskDenResearchLurkerRange = 1286,
-	ResearchZergFlyerAttackLevel1 = 1312,
-	ResearchZergFlyerAttackLevel2 = 1313,
-	ResearchZergFlyerAttackLevel3 = 1314,
-	ResearchZergFlyerArmorLevel1 = 1315,
-	ResearchZergFlyerArmorLevel2 = 1316,
-	ResearchZergFlyerArmorLevel3 = 1317,
-	LarvaTrainDrone = 1342,
-	LarvaTrainZergling = 1343,
-	LarvaTrainOverlord = 1344,
-	LarvaTrainHydralisk = 1345,
-	LarvaTrainMutalisk = 1346,
-	LarvaTrainUltralisk = 1348,
-	LarvaTrainRoach = 1351,
-	LarvaTrainInfestor = 1352,
-	LarvaTrainCorruptor = 1353,
-	LarvaTrainViper = 1354,
-	TrainSwarmHost = 1356,
-	MorphToBroodLordBroodLord = 1372,
-	CancelMorphBroodlord = 1373,
-	BurrowDownBaneling = 1374,
-	BurrowBanelingDownCancel = 1375,
-	BurrowUpBaneling = 1376,
-	BurrowDownDrone = 1378,
-	BurrowDroneDownCancel = 1379,
-	BurrowUpDrone = 1380,
-	BurrowDownHydralisk = 1382,
-	BurrowHydraliskDownCancel = 1383,
-	BurrowUpHydralisk = 1384,
-	BurrowDownRoach = 1386,
-	BurrowRoachDownCancel = 1387,
-	BurrowUpRoach = 1388,
-	BurrowDownZergling = 1390,
-	BurrowZerglingDownCancel = 1391,
-	BurrowUpZergling = 1392,
-	BurrowDownInfestorTerran = 1394,
-	BurrowUpInfestorTerran = 1396,
-	RedstoneLavaCritterBurrowBurrowDown = 1398,
-	RedstoneLavaCritterInjuredBurrowBurrowDown = 1400,
-	RedstoneLavaCritterUnburrowBurrowUp = 1402,
-	RedstoneLavaCritterInjuredUnburrowBurrowUp = 1404,
-	LoadOverlord = 1406,
-	UnloadAllAtOverlord = 1408,
-	UnloadUnitOverlord = 1409,
-	MergeableCancel = 1411,
-	WarpableCancel = 1412,
-	WarpGateTrainZealot = 1413,
-	WarpGateTrainStalker = 1414,
-	WarpGateTrainHighTemplar = 1416,
-	WarpGateTrainDarkTemplar = 1417,
-	WarpGateTrainSentry = 1418,
-	TrainWarpAdept = 1419,
-	BurrowDownQueen = 1433,
-	BurrowQueenDownCancel = 1434,
-	BurrowUpQueen = 1435,
-	LoadNydusNetwork = 1437,
-	UnloadAllNydasNetwork = 1438,
-	UnloadUnitNydasNetwork = 1440,
-	EffectBlinkStalker = 1442,
-	BurrowDownInfestor = 1444,
-	BurrowInfestorDownCancel = 1445,
-	BurrowUpInfestor = 1446,
-	MorphOverseer = 1448,
-	CancelMorphOverseer = 1449,
-	UpgradeToPlanetaryFortressPlanetaryFortress = 1450,
-	CancelMorphPlanetaryFortress = 1451,
-	ResearchPathogenGlands = 1454,
-	ResearchNeuralParasite = 1455,
-	InfestationPitResearchLocustLifetimeIncrease = 1456,
-	ResearchCentrifugalHooks = 1482,
-	BurrowDownUltralisk = 1512,
-	BurrowUpUltralisk = 1514,
-	UpgradeToOrbitalOrbitalCommand = 1516,
-	CancelMorphOrbital = 1517,
-	MorphWarpGate = 1518,
-	UpgradeToWarpGateCancel = 1519,
-	MorphGateway = 1520,
-	MorphBackToGatewayCancel = 1521,
-	LiftOrbitalCommand = 1522,
-	LandOrbitalCommand = 1524,
-	ForceFieldForceField = 1526,
-	ForceFieldCancel = 1527,
-	MorphWarpPrismPhasingMode = 1528,
-	PhasingModeCancel = 1529,
-	MorphWarpPrismTransportMode = 1530,
-	TransportModeCancel = 1531,
-	ResearchBattlecruiserWeaponRefit = 1532,
-	CyberneticsCoreResearchProtossAirWeaponsLevel1 = 1562,
-	CyberneticsCoreResearchProtossAirWeaponsLevel2 = 1563,
-	CyberneticsCoreResearchProtossAirWeaponsLevel3 = 1564,
-	CyberneticsCoreResearchProtossAirArmorLevel1 = 1565,
-	CyberneticsCoreResearchProtossAirArmorLevel2 = 1566,
-	CyberneticsCoreResearchProtossAirArmorLevel3 = 1567,
-	ResearchWarpGate = 1568,
-	CyberneticsCoreResearchHallucination = 1571,
-	ResearchCharge = 1592,
-	ResearchBlink = 1593,
-	ResearchAdeptResonatingGlaives = 1594,
-	TacNukeStrikeNukeCalldown = 1622,
-	CancelNuke = 1623,
-	SalvageBunkerRefundSalvage = 1624,
-	SalvageBunkerSalvage = 1626,
-	EMPEMP = 1628,
-	VortexVortex = 1630,
-	TrainQueenQueen = 1632,
-	BurrowCreepTumorDownBurrowDown = 1662,
-	TransfusionTransfusion = 1664,
-	BarracksTechLabMorphTechLabBarracks = 1668,
-	FactoryTechLabMorphTechLabFactory = 1670,
-	StarportTechLabMorphTechLabStarport = 1672,
-	BarracksReactorMorphReactor = 1676,
-	FactoryReactorMorphReactor = 1678,
-	StarportReactorMorphReactor = 1680,
-	AttackRedirect = 1682,
-	EffectStimMarineRedirect = 1683,
-	EffectStimMarauderRedirect = 1684,
-	BurrowedStopStopRoachBurrowed = 1685,
-	BurrowedStopHoldFireSpecial = 1686,
-	StopRedirect = 1691,
-	BehaviorGenerateCreepOn = 1692,
-	BehaviorGenerateCreepOff = 1693,
-	BuildCreepTumorQueen = 1694,
-	QueenBuildCancel = 1724,
-	SpineCrawlerUprootSpineCrawlerUproot = 1725,
-	SpineCrawlerUprootCancel = 1726,
-	SporeCrawlerUprootSporeCrawlerUproot = 1727,
-	SporeCrawlerUprootCancel = 1728,
-	SpineCrawlerRootSpineCrawlerRoot = 1729,
-	CancelSpineCrawlerRoot = 1730,
-	SporeCrawlerRootSporeCrawlerRoot = 1731,
-	CancelSporeCrawlerRoot = 1732,
-	BuildCreepTumorTumor = 1733,
-	CancelCreepTumor = 1763,
-	BuildAutoTurretAutoTurret = 1764,
-	MorphArchon = 1766,
-	ArchonWarpTarget = 1767,
-	BuildNydusWorm = 1768,
-	BuildNydusCanalSummonNydusCanalAttacker = 1769,
-	BuildNydusCanalCancel = 1798,
-	EffectCharge = 1819,
-	HerdInteractHerd = 1821,
-	FrenzyFrenzy = 1823,
-	ContaminateContaminate = 1825,
-	InfestedTerransLayEggInfestedTerrans = 1829,
-	CancelQueuePasive = 1831,
-	CancelSlotQueuePassive = 1832,
-	CancelQueuePassiveCancelToSelection = 1833,
-	CancelSlotQueuePassiveCancelToSelection = 1834,
-	MorphToGhostAlternateMove = 1835,
-	MorphToGhostNovaMove = 1837,
-	DigesterCreepSprayDigesterCreepSpray = 1839,
-	MorphToCollapsibleTerranTowerDebrisCancel = 1842,
-	MorphToCollapsibleTerranTowerDebrisRampLeftCancel = 1844,
-	MorphToCollapsibleTerranTowerDebrisRampRightCancel = 1846,
-	MorphMothership = 1847,
-	CancelMorphMothership = 1848,
-	MothershipStasisMothershipStasis = 1849,
-	CancelMothershipStasis = 1850,
-	MothershipCoreWeaponMothershipStasis = 1851,
-	NexusTrainMothershipCoreMothershipCore = 1853,
-	MothershipCoreTeleportMothershipCoreTeleport = 1883,
-	SalvageDroneRefundSalvage = 1885,
-	SalvageDroneSalvage = 1887,
-	SalvageZerglingRefundSalvage = 1889,
-	SalvageZerglingSalvage = 1891,
-	SalvageQueenRefundSalvage = 1893,
-	SalvageQueenSalvage = 1895,
-	SalvageRoachRefundSalvage = 1897,
-	SalvageRoachSalvage = 1899,
-	SalvageBanelingRefundSalvage = 1901,
-	SalvageBanelingSalvage = 1903,
-	SalvageHydraliskRefundSalvage = 1905,
-	SalvageHydraliskSalvage = 1907,
-	SalvageInfestorRefundSalvage = 1909,
-	SalvageInfestorSalvage = 1911,
-	SalvageSwarmHostRefundSalvage = 1913,
-	SalvageSwarmHostSalvage = 1915,
-	SalvageUltraliskRefundSalvage = 1917,
-	SalvageUltraliskSalvage = 1919,
-	DigesterTransportLoadDigester = 1921,
-	SpectreShieldSpectreShield = 1926,
-	XelNagaHealingShrineXelNagaHealingShrine = 1928,
-	NexusInvulnerabilityNexusInvulnerability = 1930,
-	NexusPhaseShiftNexusPhaseShift = 1932,
-	SpawnChangelingTargetSpawnChangeling = 1934,
-	QueenLandQueenLand = 1936,
-	QueenFlyQueenFly = 1938,
-	OracleCloakFieldOracleCloakField = 1940,
-	FlyerShieldFlyerShield = 1942,
-	LocustTrainSwarmHost = 1944,
-	EffectMassRecallMothershipCore = 1974,
-	SingleRecallSingleRecall = 1976,
-	MorphHellion = 1978,
-	RestoreShieldsRestoreShields = 1980,
-	ScryerScryer = 1982,
-	LeechResourcesCancel = 1987,
-	SnipeDoTSnipeDoT = 1988,
-	SwarmHostSpawnLocustsLocustMP = 1990,
-	CloneClone = 1992,
-	BuildingShieldBuildingShield = 1994,
-	MorphToCollapsibleRockTowerDebrisCancel = 1997,
-	MorphHellbat = 1998,
-	BuildingStasisBuildingStasis = 2000,
-	MaxiumThrustMaximumThrust = 2006,
-	BurrowDownSwarmHost = 2014,
-	MorphToSwarmHostBurrowedMPCancel = 2015,
-	BurrowUpSwarmHost = 2016,
-	SpawnInfestedTerranLocustMP = 2018,
-	AttackProtossBuildingAttackBuilding = 2048,
-	AttackProtossBuildingAttackTowards = 2049,
-	AttackProtossBuildingAttackBarrage = 2050,
-	BurrowedBanelingStopStopRoachBurrowed = 2051,
-	BurrowedBanelingStopHoldFireSpecial = 2052,
-	StopBuilding = 2057,
-	StopProtossBuildingHoldFire = 2058,
-	StopProtossBuildingCheer = 2059,
-	StopProtossBuildingDance = 2060,
-	BlindingCloudBlindingCloud = 2063,
-	EyeStalkEyeStalk = 2065,
-	EyeStalkCancel = 2066,
-	EffectAbduct = 2067,
-	ViperConsumeViperConsume = 2069,
-	ViperConsumeMineralsViperConsume = 2071,
-	ViperConsumeStructureViperConsume = 2073,
-	CancelProtossBuildingQueue = 2075,
-	ProtossBuildingQueueCancelSlot = 2076,
-	Que8Cancel = 2077,
-	Que8CancelSlot = 2078,
-	TestZergCancel = 2080,
-	BehaviorBuildingAttackOn = 2081,
-	BehaviorBuildingAttackOff = 2082,
-	PickupScrapSmallPickupScrapSmall = 2083,
-	PickupScrapMediumPickupScrapMedium = 2085,
-	PickupScrapLargePickupScrapLarge = 2087,
-	PickupPalletGasPickupPalletGas = 2089,
-	PickupPalletMineralsPickupPalletMinerals = 2091,
-	MassiveKnockoverMassiveKnockover = 2093,
-	BurrowDownWidowMine = 2095,
-	WidowMineBurrowCancel = 2096,
-	BurrowUpWidowMine = 2097,
-	WidowMineAttackWidowMineAttack = 2099,
-	TornadoMissileTornadoMissile = 2101,
-	MothershipCoreEnergizeMothershipCoreEnergize = 2102,
-	MothershipCoreEnergizeCancel = 2103,
-	LurkerAspectMPFromHydraliskBurrowedLurkerMPFromHydraliskBurrowed = 2104,
-	LurkerAspectMPFromHydraliskBurrowedCancel = 2105,
-	LurkerAspectMPLurkerMP = 2106,
-	LurkerAspectMPCancel = 2107,
-	BurrowDownLurker = 2108,
-	BurrowLurkerMPDownCancel = 2109,
-	BurrowUpLurker = 2110,
-	MorphLurkerDen = 2112,
-	CancelMorphLurkerDen = 2113,
-	HallucinationOracle = 2114,
-	EffectMedivacIgniteAfterburners = 2116,
-	ExtendingBridgeNEWide8OutBridgeExtend = 2118,
-	ExtendingBridgeNEWide8BridgeRetract = 2120,
-	ExtendingBridgeNWWide8OutBridgeExtend = 2122,
-	ExtendingBridgeNWWide8BridgeRetract = 2124,
-	ExtendingBridgeNEWide10OutBridgeExtend = 2126,
-	ExtendingBridgeNEWide10BridgeRetract = 2128,
-	ExtendingBridgeNWWide10OutBridgeExtend = 2130,
-	ExtendingBridgeNWWide10BridgeRetract = 2132,
-	ExtendingBridgeNEWide12OutBridgeExtend = 2134,
-	ExtendingBridgeNEWide12BridgeRetract = 2136,
-	ExtendingBridgeNWWide12OutBridgeExtend = 2138,
-	ExtendingBridgeNWWide12BridgeRetract = 2140,
-	InvulnerabilityShieldInvulnerabilityShield = 2142,
-	CritterFleeCritterFlee = 2144,
-	OracleRevelationOracleRevelation = 2146,
-	OracleRevelationModeOracleRevelationMode = 2148,
-	OracleRevelationModeCancel = 2149,
-	OracleNormalModeOracleNormalMode = 2150,
-	OracleNormalModeCancel = 2151,
-	MorphToCollapsibleRockTowerDebrisRampRightCancel = 2153,
-	MorphToCollapsibleRockTowerDebrisRampLeftCancel = 2155,
-	VoidSiphonVoidSiphon = 2156,
-	UltraliskWeaponCooldownUltraliskWeaponCooldown = 2158,
-	MothershipCorePurifyNexusCancelCancel = 2160,
-	EffectPhotonOvercharge = 2162,
-	XelNagaCavernsDoorEXelNagaCavernsDoorDefaultClose = 2164,
-	XelNagaCavernsDoorEOpenedXelNagaCavernsDoorDefaultClose = 2166,
-	XelNagaCavernsDoorNXelNagaCavernsDoorDefaultClose = 2168,
-	XelNagaCavernsDoorNEXelNagaCavernsDoorDefaultClose = 2170,
-	XelNagaCavernsDoorNEOpenedXelNagaCavernsDoorDefaultOpen = 2172,
-	XelNagaCavernsDoorNOpenedXelNagaCavernsDoorDefaultOpen = 2174,
-	XelNagaCavernsDoorNWXelNagaCavernsDoorDefaultClose = 2176,
-	XelNagaCavernsDoorNWOpenedXelNagaCavernsDoorDefaultOpen = 2178,
-	XelNagaCavernsDoorSXelNagaCavernsDoorDefaultClose = 2180,
-	XelNagaCavernsDoorSEXelNagaCavernsDoorDefaultClose = 2182,
-	XelNagaCavernsDoorSEOpenedXelNagaCavernsDoorDefaultOpen = 2184,
-	XelNagaCavernsDoorSOpenedXelNagaCavernsDoorDefaultOpen = 2186,
-	XelNagaCavernsDoorSWXelNagaCavernsDoorDefaultClose = 2188,
-	XelNagaCavernsDoorSWOpenedXelNagaCavernsDoorDefaultOpen = 2190,
-	XelNagaCavernsDoorWXelNagaCavernsDoorDefaultClose = 2192,
-	XelNagaCavernsDoorWOpenedXelNagaCavernsDoorDefaultOpen = 2194,
-	XelNagaCavernsFloatingBridgeNE8OutBridgeExtend = 2196,
-	XelNagaCavernsFloatingBridgeNE8BridgeRetract = 2198,
-	XelNagaCavernsFloatingBridgeNW8OutBridgeExtend = 2200,
-	XelNagaCavernsFloatingBridgeNW8BridgeRetract = 2202,
-	XelNagaCavernsFloatingBridgeNE10OutBridgeExtend = 2204,
-	XelNagaCavernsFloatingBridgeNE10BridgeRetract = 2206,
-	XelNagaCavernsFloatingBridgeNW10OutBridgeExtend = 2208,
-	XelNagaCavernsFloatingBridgeNW10BridgeRetract = 2210,
-	XelNagaCavernsFloatingBridgeNE12OutBridgeExtend = 2212,
-	XelNagaCavernsFloatingBridgeNE12BridgeRetract = 2214,
-	XelNagaCavernsFloatingBridgeNW12OutBridgeExtend = 2216,
-	XelNagaCavernsFloatingBridgeNW12BridgeRetract = 2218,
-	XelNagaCavernsFloatingBridgeH8OutBridgeExtend = 2220,
-	XelNagaCavernsFloatingBridgeH8BridgeRetract = 2222,
-	XelNagaCavernsFloatingBridgeV8OutBridgeExtend = 2224,
-	XelNagaCavernsFloatingBridgeV8BridgeRetract = 2226,
-	XelNagaCavernsFloatingBridgeH10OutBridgeExtend = 2228,
-	XelNagaCavernsFloatingBridgeH10BridgeRetract = 2230,
-	XelNagaCavernsFloatingBridgeV10OutBridgeExtend = 2232,
-	XelNagaCavernsFloatingBridgeV10BridgeRetract = 2234,
-	XelNagaCavernsFloatingBridgeH12OutBridgeExtend = 2236,
-	XelNagaCavernsFloatingBridgeH12BridgeRetract = 2238,
-	XelNagaCavernsFloatingBridgeV12OutBridgeExtend = 2240,
-	XelNagaCavernsFloatingBridgeV12BridgeRetract = 2242,
-	EffectTimeWarp = 2244,
-	SnowRefineryTerranExtendingBridgeNEShort8OutBridgeExtend = 2246,
-	SnowRefineryTerranExtendingBridgeNEShort8BridgeRetract = 2248,
-	SnowRefineryTerranExtendingBridgeNWShort8OutBridgeExtend = 2250,
-	SnowRefineryTerranExtendingBridgeNWShort8BridgeRetract = 2252,
-	SnowRefineryTerranExtendingBridgeNEShort10OutBridgeExtend = 2254,
-	SnowRefineryTerranExtendingBridgeNEShort10BridgeRetract = 2256,
-	SnowRefineryTerranExtendingBridgeNWShort10OutBridgeExtend = 2258,
-	SnowRefineryTerranExtendingBridgeNWShort10BridgeRetract = 2260,
-	ArmoryResearchSwarmTerranVehicleAndShipWeaponsLevel1 = 2294,
-	ArmoryResearchSwarmTerranVehicleAndShipWeaponsLevel2 = 2295,
-	ArmoryResearchSwarmTerranVehicleAndShipWeaponsLevel3 = 2296,
-	ArmoryResearchSwarmTerranVehicleAndShipPlatingLevel1 = 2297,
-	ArmoryResearchSwarmTerranVehicleAndShipPlatingLevel2 = 2298,
-	ArmoryResearchSwarmTerranVehicleAndShipPlatingLevel3 = 2299,
-	CausticSprayCausticSpray = 2324,
-	OracleCloakingFieldTargetedOracleCloakingFieldTargeted = 2326,
-	EffectImmortalBarrier = 2328,
-	MorphToRavagerRavager = 2330,
-	CancelMorphRavager = 2331,
-	MorphLurker = 2332,
-	CancelMorphLurker = 2333,
-	OraclePhaseShiftOraclePhaseShift = 2334,
-	ReleaseInterceptorsReleaseInterceptors = 2336,
-	EffectCorrosiveBile = 2338,
-	BurrowDownRavager = 2340,
-	BurrowRavagerDownCancel = 2341,
-	BurrowUpRavager = 2342,
-	PurificationNovaPurificationNova = 2344,
-	EffectPurificationNova = 2346,
-	ImpaleImpale = 2348,
-	LockOnLockOn = 2350,
-	LockOnAirLockOnAir = 2352,
-	CancelLockOn = 2354,
-	CorruptionBombCorruptionBomb = 2356,
-	CorruptionBombCancel = 2357,
-	EffectTacticalJump = 2358,
-	OverchargeOvercharge = 2360,
-	MorphThorHighImpactMode = 2362,
-	ThorAPModeCancel = 2363,
-	MorphThorExplosiveMode = 2364,
-	CancelMorphThorExplosiveMode = 2365,
-	LightofAiurLightofAiur = 2366,
-	EffectMassRecallMothership = 2368,
-	LoadNydusWorm = 2370,
-	UnloadAllNydusWorm = 2371,
-	BehaviorPulsarBeamOn = 2375,
-	BehaviorPulsarBeamOff = 2376,
-	PulsarBeamRipField = 2377,
-	PulsarCannonPulsarCannon = 2379,
-	VoidSwarmHostSpawnLocustVoidSwarmHostSpawnLocust = 2381,
-	LocustMPFlyingMorphToGroundLocustMPFlyingSwoop = 2383,
-	LocustMPMorphToAirLocustMPFlyingSwoop = 2385,
-	EffectLocustSwoop = 2387,
-	HallucinationDisruptor = 2389,
-	HallucinationAdept = 2391,
-	EffectVoidRayPrismaticAlignment = 2393,
-	AiurLightBridgeNE8OutBridgeExtend = 2397,
-	AiurLightBridgeNE8BridgeRetract = 2399,
-	AiurLightBridgeNE10OutBridgeExtend = 2401,
-	AiurLightBridgeNE10BridgeRetract = 2403,
-	AiurLightBridgeNE12OutBridgeExtend = 2405,
-	AiurLightBridgeNE12BridgeRetract = 2407,
-	AiurLightBridgeNW8OutBridgeExtend = 2409,
-	AiurLightBridgeNW8BridgeRetract = 2411,
-	AiurLightBridgeNW10OutBridgeExtend = 2413,
-	AiurLightBridgeNW10BridgeRetract = 2415,
-	AiurLightBridgeNW12OutBridgeExtend = 2417,
-	AiurLightBridgeNW12BridgeRetract = 2419,
-	AiurTempleBridgeNE8OutBridgeExtend = 2421,
-	AiurTempleBridgeNE8BridgeRetract = 2423,
-	AiurTempleBridgeNE10OutBridgeExtend = 2425,
-	AiurTempleBridgeNE10BridgeRetract = 2427,
-	AiurTempleBridgeNE12OutBridgeExtend = 2429,
-	AiurTempleBridgeNE12BridgeRetract = 2431,
-	AiurTempleBridgeNW8OutBridgeExtend = 2433,
-	AiurTempleBridgeNW8BridgeRetract = 2435,
-	AiurTempleBridgeNW10OutBridgeExtend = 2437,
-	AiurTempleBridgeNW10BridgeRetract = 2439,
-	AiurTempleBridgeNW12OutBridgeExtend = 2441,
-	AiurTempleBridgeNW12BridgeRetract = 2443,
-	ShakurasLightBridgeNE8OutBridgeExtend = 2445,
-	ShakurasLightBridgeNE8BridgeRetract = 2447,
-	ShakurasLightBridgeNE10OutBridgeExtend = 2449,
-	ShakurasLightBridgeNE10BridgeRetract = 2451,
-	ShakurasLightBridgeNE12OutBridgeExtend = 2453,
-	ShakurasLightBridgeNE12BridgeRetract = 2455,
-	ShakurasLightBridgeNW8OutBridgeExtend = 2457,
-	ShakurasLightBridgeNW8BridgeRetract = 2459,
-	ShakurasLightBridgeNW10OutBridgeExtend = 2461,
-	ShakurasLightBridgeNW10BridgeRetract = 2463,
-	ShakurasLightBridgeNW12OutBridgeExtend = 2465,
-	ShakurasLightBridgeNW12BridgeRetract = 2467,
-	VoidMPImmortalReviveRebuildImmortal = 2469,
-	VoidMPImmortalReviveDeathImmortal = 2471,
-	ArbiterMPStasisFieldArbiterMPStasisField = 2473,
-	ArbiterMPRecallArbiterMPRecall = 2475,
-	CorsairMPDisruptionWebCorsairMPDisruptionWeb = 2477,
-	MorphToGuardianMPMorphToGuardianMP = 2479,
-	MorphToGuardianMPCancel = 2480,
-	MorphToDevourerMPMorphToDevourerMP = 2481,
-	MorphToDevourerMPCancel = 2482,
-	DefilerMPConsumeDefilerMPConsume = 2483,
-	DefilerMPDarkSwarmDefilerMPDarkSwarm = 2485,
-	DefilerMPPlagueDefilerMPPlague = 2487,
-	DefilerMPBurrowBurrowDown = 2489,
-	DefilerMPBurrowCancel = 2490,
-	DefilerMPUnburrowBurrowUp = 2491,
-	QueenMPEnsnareQueenMPEnsnare = 2493,
-	QueenMPSpawnBroodlingsQueenMPSpawnBroodlings = 2495,
-	QueenMPInfestCommandCenterQueenMPInfestCommandCenter = 2497,
-	LightningBombLightningBomb = 2499,
-	GrappleGrapple = 2501,
-	OracleStasisTrapOracleBuildStasisTrap = 2503,
-	BuildStasisTrap = 2505,
-	CancelStasisTrap = 2535,
-	OracleStasisTrapActivateActivateStasisWard = 2536,
-	SelfRepairSelfRepair = 2538,
-	SelfRepairCancel = 2539,
-	AggressiveMutationAggressiveMutation = 2540,
-	ParasiticBombParasiticBomb = 2542,
-	AdeptPhaseShiftAdeptPhaseShift = 2544,
-	PurificationNovaMorphPurificationNova = 2546,
-	PurificationNovaMorphBackPurificationNova = 2548,
-	BehaviorHoldFireOnLurker = 2550,
-	BehaviorHoldFireOffLurker = 2552,
-	LiberatorMorphtoAGLiberatorAGMode = 2554,
-	LiberatorMorphtoAALiberatorAAMode = 2556,
-	MorphLiberatorAGMode = 2558,
-	MorphLiberatorAAMode = 2560,
-	TimeStopTimeStop = 2562,
-	TimeStopCancel = 2563,
-	AiurLightBridgeAbandonedNE8OutBridgeExtend = 2564,
-	AiurLightBridgeAbandonedNE8BridgeRetract = 2566,
-	AiurLightBridgeAbandonedNE10OutBridgeExtend = 2568,
-	AiurLightBridgeAbandonedNE10BridgeRetract = 2570,
-	AiurLightBridgeAbandonedNE12OutBridgeExtend = 2572,
-	AiurLightBridgeAbandonedNE12BridgeRetract = 2574,
-	AiurLightBridgeAbandonedNW8OutBridgeExtend = 2576,
-	AiurLightBridgeAbandonedNW8BridgeRetract = 2578,
-	AiurLightBridgeAbandonedNW10OutBridgeExtend = 2580,
-	AiurLightBridgeAbandonedNW10BridgeRetract = 2582,
-	AiurLightBridgeAbandonedNW12OutBridgeExtend = 2584,
-	AiurLightBridgeAbandonedNW12BridgeRetract = 2586,
-	KD8ChargeKD8Charge = 2588,
-	PenetratingShotPenetratingShot = 2590,
-	CloakingDroneCloakingDrone = 2592,
-	CancelAdeptPhaseShift = 2594,
-	CancelAdeptShadePhaseShift = 2596,
-	SlaynElementalGrabSlaynElementalGrab = 2598,
-	MorphToCollapsiblePurifierTowerDebrisCancel = 2601,
-	PortCityBridgeUnitNE8OutBridgeExtend = 2602,
-	PortCityBridgeUnitNE8BridgeRetract = 2604,
-	PortCityBridgeUnitSE8OutBridgeExtend = 2606,
-	PortCityBridgeUnitSE8BridgeRetract = 2608,
-	PortCityBridgeUnitNW8OutBridgeExtend = 2610,
-	PortCityBridgeUnitNW8BridgeRetract = 2612,
-	PortCityBridgeUnitSW8OutBridgeExtend = 2614,
-	PortCityBridgeUnitSW8BridgeRetract = 2616,
-	PortCityBridgeUnitNE10OutBridgeExtend = 2618,
-	PortCityBridgeUnitNE10BridgeRetract = 2620,
-	PortCityBridgeUnitSE10OutBridgeExtend = 2622,
-	PortCityBridgeUnitSE10BridgeRetract = 2624,
-	PortCityBridgeUnitNW10OutBridgeExtend = 2626,
-	PortCityBridgeUnitNW10BridgeRetract = 2628,
-	PortCityBridgeUnitSW10OutBridgeExtend = 2630,
-	PortCityBridgeUnitSW10BridgeRetract = 2632,
-	PortCityBridgeUnitNE12OutBridgeExtend = 2634,
-	PortCityBridgeUnitNE12BridgeRetract = 2636,
-	PortCityBridgeUnitSE12OutBridgeExtend = 2638,
-	PortCityBridgeUnitSE12BridgeRetract = 2640,
-	PortCityBridgeUnitNW12OutBridgeExtend = 2642,
-	PortCityBridgeUnitNW12BridgeRetract = 2644,
-	PortCityBridgeUnitSW12OutBridgeExtend = 2646,
-	PortCityBridgeUnitSW12BridgeRetract = 2648,
-	PortCityBridgeUnitN8OutBridgeExtend = 2650,
-	PortCityBridgeUnitN8BridgeRetract = 2652,
-	PortCityBridgeUnitS8OutBridgeExtend = 2654,
-	PortCityBridgeUnitS8BridgeRetract = 2656,
-	PortCityBridgeUnitE8OutBridgeExtend = 2658,
-	PortCityBridgeUnitE8BridgeRetract = 2660,
-	PortCityBridgeUnitW8OutBridgeExtend = 2662,
-	PortCityBridgeUnitW8BridgeRetract = 2664,
-	PortCityBridgeUnitN10OutBridgeExtend = 2666,
-	PortCityBridgeUnitN10BridgeRetract = 2668,
-	PortCityBridgeUnitS10OutBridgeExtend = 2670,
-	PortCityBridgeUnitS10BridgeRetract = 2672,
-	PortCityBridgeUnitE10OutBridgeExtend = 2674,
-	PortCityBridgeUnitE10BridgeRetract = 2676,
-	PortCityBridgeUnitW10OutBridgeExtend = 2678,
-	PortCityBridgeUnitW10BridgeRetract = 2680,
-	PortCityBridgeUnitN12OutBridgeExtend = 2682,
-	PortCityBridgeUnitN12BridgeRetract = 2684,
-	PortCityBridgeUnitS12OutBridgeExtend = 2686,
-	PortCityBridgeUnitS12BridgeRetract = 2688,
-	PortCityBridgeUnitE12OutBridgeExtend = 2690,
-	PortCityBridgeUnitE12BridgeRetract = 2692,
-	PortCityBridgeUnitW12OutBridgeExtend = 2694,
-	PortCityBridgeUnitW12BridgeRetract = 2696,
-	TempestDisruptionBlastTempestDisruptionBlast = 2698,
-	CancelTempestDisruptionBlast = 2699,
-	EffectShadowStride = 2700,
-	EffectSpawnLocusts = 2704,
-	LocustMPFlyingSwoopAttackLocustMPFlyingSwoop = 2706,
-	MorphOverlordTransport = 2708,
-	CancelMorphOverlordTransport = 2709,
-	EffectGhostSnipe = 2714,
-	PurifyMorphPylonMothershipCoreWeapon = 2716,
-	PurifyMorphPylonBackMothershipCoreWeapon = 2718,
-	ResearchShadowStrike = 2720,
-	HealMedicHeal = 2750,
-	LurkerAspectLurker = 2752,
-	LurkerAspectCancel = 2753,
-	BurrowLurkerDownBurrowDown = 2754,
-	BurrowLurkerDownCancel = 2755,
-	BurrowLurkerUpBurrowUp = 2756,
-	D8ChargeD8Charge = 2758,
-	DefensiveMatrixDefensiveMatrix = 2760,
-	MissilePodsMissilePods = 2762,
-	LokiMissilePodsMissilePods = 2764,
-	HutTransportHutLoad = 2766,
-	HutTransportHutUnloadAll = 2767,
-	LeviathanSpawnBroodLordSpawnBroodLord = 2773,
-	SSCarrierBossAttackLaunchSSShooting = 2775,
-	SSCarrierSpawnInterceptorSSCarrierSpawnInterceptor = 2777,
-	SSCarrierBossAttackTargetSSShooting = 2779,
-	SSFighterBombSSFighterBomb = 2781,
-	SSPhoenixShootingSSShooting = 2785,
-	SSBattlecruiserMissileAttackSSShooting = 2789,
-	SSLeviathanSpawnBombsSSLeviathanSpawnBombs = 2791,
-	SSBattlecruiserHunterSeekerAttackSSShooting = 2793,
-	SSLeviathanTentacleAttackL1NoDelaySSLeviathanTentacleAttackL1NoDelay = 2797,
-	SSLeviathanTentacleAttackL2NoDelaySSLeviathanTentacleAttackL2NoDelay = 2799,
-	SSLeviathanTentacleAttackR1NoDelaySSLeviathanTentacleAttackR1NoDelay = 2801,
-	SSLeviathanTentacleAttackR2NoDelaySSLeviathanTentacleAttackR2NoDelay = 2803,
-	SSScienceVesselTeleportZeratulBlink = 2805,
-	SSTerraTronBeamAttackSSTerraTronBeamAttack = 2807,
-	SSTerraTronSawAttackSSTerraTronSawAttack = 2809,
-	SSWraithAttackSSShooting = 2811,
-	SSSwarmGuardianAttackSSShooting = 2813,
-	SSScoutAttackSSShooting = 2819,
-	SSInterceptorAttackSSShooting = 2821,
-	SSCorruptorAttackSSShooting = 2823,
-	SSLeviathanTentacleAttackL2SSLeviathanTentacleAttackL2 = 2825,
-	SSLeviathanTentacleAttackR1SSLeviathanTentacleAttackR1 = 2827,
-	SSLeviathanTentacleAttackL1SSLeviathanTentacleAttackL1 = 2829,
-	SSLeviathanTentacleAttackR2SSLeviathanTentacleAttackR2 = 2831,
-	SSScienceVesselAttackSSShooting = 2833,
-	LurkerAspectFromHydraliskBurrowedLurkerFromHydraliskBurrowed = 2836,
-	LurkerAspectFromHydraliskBurrowedCancel = 2837,
-	UpgradeToLurkerDenLurkerDen = 2838,
-	UpgradeToLurkerDenCancel = 2839,
-	AdvancedConstructionCancel = 2841,
-	BuildinProgressNonCancellableCancel = 2843,
-	InfestedVentSpawnCorruptorSpawnCorruptor = 2844,
-	InfestedVentSpawnBroodLordSpawnBroodLord = 2846,
-	IrradiateIrradiate = 2848,
-	IrradiateCancel = 2849,
-	InfestedVentSpawnMutaliskLeviathanSpawnMutalisk = 2850,
-	MakeVultureSpiderMinesSpiderMineReplenish = 2852,
-	MedivacDoubleBeamHealHeal = 2872,
-	MindControlMindControl = 2874,
-	ObliterateObliterate = 2876,
-	VoodooShieldVoodooShield = 2878,
-	ReleaseMinionReleaseMinion = 2880,
-	UltrasonicPulseUltrasonicPulse = 2882,
-	ArchiveSealArchiveSeal = 2884,
-	ArtanisVortexVortex = 2886,
-	ArtanisWormholeTransitWormholeTransit = 2888,
-	BunkerAttackBunkerAttack = 2890,
-	BunkerAttackAttackTowards = 2891,
-	BunkerAttackAttackBarrage = 2892,
-	BunkerStopStopBunker = 2893,
-	BunkerStopHoldFireSpecial = 2894,
-	CancelTerrazineHarvestCancel = 2899,
-	LeviathanSpawnMutaliskLeviathanSpawnMutalisk = 2901,
-	ParkColonistVehicleParkColonistVehicle = 2903,
-	StartColonistVehicleStartColonistVehicle = 2905,
-	ConsumptionConsumption = 2907,
-	ConsumeDNAConsumeDNA = 2909,
-	EggPopEggPop = 2911,
-	ExperimentalPlasmaGunExperimentalPlasmaGun = 2913,
-	GatherSpecialObjectGatherSpecialObject = 2915,
-	LokiUndockLift = 2919,
-	MindBlastMindBlast = 2921,
-	MorphToInfestedCivilianMorphToInfestedCivilian = 2923,
-	QueenShockwaveQueenShockwave = 2925,
-	TaurenOuthouseLiftoffTaurenOuthouseFly = 2927,
-	TaurenOuthouseTransportLoadTaurenOuthouse = 2929,
-	TaurenOuthouseTransportUnloadTaurenOuthouse = 2930,
-	Tychus03OmegaStormOmegaStorm = 2934,
-	RaynorSnipeRaynorSnipe = 2936,
-	BonesHealBonesHeal = 2938,
-	BonesTossGrenadeTossGrenadeTychus = 2940,
-	HerculesTransportMedivacLoad = 2942,
-	HerculesTransportMedivacUnloadAll = 2944,
-	SpecOpsDropshipTransportMedivacLoad = 2947,
-	SpecOpsDropshipTransportMedivacUnloadAll = 2949,
-	DuskWingBansheeCloakingFieldCloakOnBanshee = 2952,
-	DuskWingBansheeCloakingFieldCloakOff = 2953,
-	HyperionYamatoSpecialHyperionYamatoGun = 2954,
-	InfestableHutTransportHutLoad = 2956,
-	InfestableHutTransportHutUnloadAll = 2957,
-	DutchPlaceTurretDutchPlaceTurret = 2961,
-	BurrowInfestedCivilianDownBurrowDown = 2963,
-	BurrowInfestedCivilianUpBurrowUp = 2965,
-	SelendisHangarInterceptor = 2967,
-	SiegeBreakerSiegeSiegeMode = 2989,
-	SiegeBreakerUnsiegeUnsiege = 2991,
-	SoulChannelCancel = 2994,
-	SentryGunBurrowBurrowTurret = 2999,
-	SentryGunUnburrowUnburrowTurret = 3001,
-	GravitonPrisonGravitonPrison = 3005,
-	ImplosionImplosion = 3007,
-	OmegaStormOmegaStorm = 3009,
-	PsionicShockwavePsionicShockwave = 3011,
-	HybridFAoEStunHybridFAoEStun = 3013,
-	SummonMercenariesHireKelmorianMiners = 3015,
-	SummonMercenariesHireDevilDogs = 3016,
-	SummonMercenariesHireSpartanCompany = 3017,
-	SummonMercenariesHireHammerSecurities = 3018,
-	SummonMercenariesHireSiegeBreakers = 3019,
-	SummonMercenariesHireHelsAngels = 3020,
-	SummonMercenariesHireDuskWing = 3021,
-	SummonMercenariesHireDukesRevenge = 3022,
-	SummonMercenariesPHHireKelmorianMinersPH = 3045,
-	EnergyNovaEnergyNova = 3075,
-	TheMorosDeviceTheMorosDevice = 3077,
-	TossGrenadeTossGrenade = 3079,
-	VoidSeekerTransportMedivacLoad = 3081,
-	VoidSeekerTransportMedivacUnloadAll = 3083,
-	TerranBuildDropSupplyDepotDrop = 3086,
-	TerranBuildDropCancel = 3116,
-	OdinNuclearStrikeOdinNukeCalldown = 3117,
-	OdinNuclearStrikeCancel = 3118,
-	OdinWreckageOdin = 3119,
-	ResearchLabTransportHutLoad = 3121,
-	ResearchLabTransportHutUnloadAll = 3122,
-	ColonyShipTransportMedivacLoad = 3126,
-	ColonyShipTransportMedivacUnloadAll = 3128,
-	ColonyInfestationColonyInfestation = 3131,
-	DominationDomination = 3133,
-	DominationCancel = 3134,
-	KarassPlasmaSurgeKarassPlasmaSurge = 3135,
-	KarassPsiStormPsiStorm = 3137,
-	HybridBlinkZeratulBlink = 3139,
-	HybridCPlasmaBlastHybridCPlasmaBlast = 3141,
-	HeroArmNukeNukeArm = 3143,
-	HeroNuclearStrikeNukeCalldown = 3163,
-	HeroNuclearStrikeCancel = 3164,
-	OdinBarrageOdinBarrage = 3165,
-	OdinBarrageCancel = 3166,
-	PurifierTogglePowerPurifierPowerDown = 3167,
-	PurifierTogglePowerPurifierPowerUp = 3168,
-	PhaseMineBlastPhaseMineBlast = 3169,
-	VoidSeekerPhaseMineBlastPhaseMineBlast = 3171,
-	TransportTruckTransportTransportTruckLoad = 3173,
-	TransportTruckTransportTransportTruckUnloadAll = 3174,
-	Val03QueenOfBladesBurrowBurrowDown = 3178,
-	Val03QueenOfBladesDeepTunnelDeepTunnel = 3180,
-	Val03QueenOfBladesUnburrowBurrowUp = 3182,
-	LokiYamatoLokiYamatoGun = 3188,
-	DukesRevengeYamatoYamatoGun = 3190,
-	ZeratulBlinkZeratulBlink = 3192,
-	RogueGhostCloakCloakOnSpectre = 3194,
-	RogueGhostCloakCloakOff = 3195,
-	VultureSpiderMinesSpiderMine = 3196,
-	VultureQueue3Cancel = 3198,
-	VultureQueue3CancelSlot = 3199,
-	SuperWarpGateTrainZealot = 3200,
-	SuperWarpGateTrainStalker = 3201,
-	SuperWarpGateTrainImmortal = 3202,
-	SuperWarpGateTrainHighTemplar = 3203,
-	SuperWarpGateTrainDarkTemplar = 3204,
-	SuperWarpGateTrainSentry = 3205,
-	SuperWarpGateTrainCarrier = 3206,
-	SuperWarpGateTrainPhoenix = 3207,
-	SuperWarpGateTrainVoidRay = 3208,
-	SuperWarpGateTrainArchon = 3209,
-	SuperWarpGateTrainWarpInZeratul = 3210,
-	SuperWarpGateTrainWarpInUrun = 3211,
-	SuperWarpGateTrainWarpInMohandar = 3212,
-	SuperWarpGateTrainWarpInSelendis = 3213,
-	SuperWarpGateTrainWarpInScout = 3214,
-	SuperWarpGateTrainColossus = 3215,
-	SuperWarpGateTrainWarpPrism = 3216,
-	BurrowOmegaliskDownBurrowDown = 3220,
-	BurrowOmegaliskUpBurrowUp = 3222,
-	BurrowInfestedAbominationDownBurrowDown = 3224,
-	BurrowInfestedAbominationUpBurrowUp = 3226,
-	BurrowHunterKillerDownBurrowDown = 3228,
-	BurrowHunterKillerDownCancel = 3229,
-	BurrowHunterKillerUpBurrowUp = 3230,
-	NovaSnipeNovaSnipe = 3232,
-	VortexPurifierVortex = 3234,
-	TalDarimVortexVortex = 3236,
-	PurifierPlanetCrackerPlanetCracker = 3238,
-	BurrowInfestedTerranCampaignDownBurrowDown = 3240,
-	BurrowInfestedTerranCampaignUpBurrowUp = 3242,
-	InfestedMonsterTrainInfestedCivilian = 3244,
-	InfestedMonsterTrainInfestedTerranCampaign = 3245,
-	InfestedMonsterTrainInfestedAbomination = 3246,
-	BiodomeTransportBiodomeLoad = 3274,
-	BiodomeTransportBiodomeUnloadAll = 3275,
-	AttackAllowsInvulnerableAttackAllowsInvulnerable = 3295,
-	AttackAllowsInvulnerableAttackTowards = 3296,
-	AttackAllowsInvulnerableAttackBarrage = 3297,
-	ZeratulStunZeratulStun = 3298,
-	WraithCloakCloakOff = 3301,
-	BarracksTechReactorMorphTechLabBarracks = 3304,
-	FactoryTechReactorMorphTechLabFactory = 3306,
-	StarportTechReactorMorphTechLabStarport = 3308,
-	SSFighterShootingSSShooting = 3310,
-	RaynorC4PlantC4Charge = 3312,
-	DukesRevengeDefensiveMatrixDefensiveMatrix = 3314,
-	DukesRevengeMissilePodsMissilePods = 3316,
-	ThorWreckageThor = 3318,
-	_330mmBarrageCannons330mmBarrageCannons = 3320,
-	_330mmBarrageCannonsCancel = 3321,
-	ThorRebornThor = 3322,
-	ThorRebornCancel = 3323,
-	SpectreNukeSpectreNukeCalldown = 3324,
-	SpectreNukeCancel = 3325,
-	SpectreNukeSiloArmMagazineSpectreNukeArm = 3327,
-	ColonistShipLiftOffLift = 3346,
-	ColonistShipLandLand = 3348,
-	BioDomeCommandLiftOffLift = 3350,
-	BioDomeCommandLandLand = 3352,
-	HerculesLiftOffLift = 3354,
-	HerculesLandHerculesLand = 3356,
-	LightBridgeOffLightBridgeOff = 3358,
-	LightBridgeOnLightBridgeOn = 3360,
-	LibraryDownLibraryDown = 3362,
-	LibraryUpLibraryUp = 3364,
-	TempleDoorDownTempleDoorDown = 3366,
-	TempleDoorUpTempleDoorUp = 3368,
-	TempleDoorDownURDLTempleDoorDownURDL = 3370,
-	TempleDoorUpURDLTempleDoorUpURDL = 3372,
-	PsytrousOxidePsytrousOxideOn = 3374,
-	PsytrousOxidePsytrousOxideOff = 3375,
-	BioPlasmidDischargeBioPlasmidDischarge = 3378,
-	WreckingCrewAssaultModeAssaultMode = 3380,
-	WreckingCrewFighterModeFighterMode = 3382,
-	BioStasisBioStasis = 3384,
-	ColonistTransportTransportColonistTransportLoad = 3386,
-	ColonistTransportTransportColonistTransportUnloadAll = 3387,
-	DropToSupplyDepotRaise = 3391,
-	RefineryToAutomatedRefineryRaise = 3393,
-	HeliosCrashMorphCrashMorph = 3395,
-	NanoRepairHeal = 3397,
-	PickupPickup = 3399,
-	PickupArcadePickup = 3401,
-	PickupGas100PickupGas100 = 3403,
-	PickupMinerals100PickupMinerals100 = 3405,
-	TaurenStimpackStim = 3423,
-	TestReviveSCV = 3474,
-	TestInteractDesignate = 3514,
-	CliffDoorOpen0SpacePlatformDoorOpen = 3515,
-	CliffDoorClose0SpacePlatformDoorClose = 3517,
-	CliffDoorOpen1SpacePlatformDoorOpen = 3519,
-	CliffDoorClose1SpacePlatformDoorClose = 3521,
-	DestructibleGateDiagonalBLURLoweredGateOpen = 3523,
-	DestructibleGateDiagonalULBRLoweredGateOpen = 3525,
-	DestructibleGateStraightHorizontalBFLoweredGateOpen = 3527,
-	DestructibleGateStraightHorizontalLoweredGateOpen = 3529,
-	DestructibleGateStraightVerticalLFLoweredGateOpen = 3531,
-	DestructibleGateStraightVerticalLoweredGateOpen = 3533,
-	DestructibleGateDiagonalBLURGateClose = 3535,
-	DestructibleGateDiagonalULBRGateClose = 3537,
-	DestructibleGateStraightHorizontalBFGateClose = 3539,
-	DestructibleGateStraightHorizontalGateClose = 3541,
-	DestructibleGateStraightVerticalLFGateClose = 3543,
-	DestructibleGateStraightVerticalGateClose = 3545,
-	TestLearnTestLearn = 3547,
-	TestLeveledSpellYamatoGun = 3567,
-	MetalGateDiagonalBLURLoweredGateOpen = 3569,
-	MetalGateDiagonalULBRLoweredGateOpen = 3571,
-	MetalGateStraightHorizontalBFLoweredGateOpen = 3573,
-	MetalGateStraightHorizontalLoweredGateOpen = 3575,
-	MetalGateStraightVerticalLFLoweredGateOpen = 3577,
-	MetalGateStraightVerticalLoweredGateOpen = 3579,
-	MetalGateDiagonalBLURGateClose = 3581,
-	MetalGateDiagonalULBRGateClose = 3583,
-	MetalGateStraightHorizontalBFGateClose = 3585,
-	MetalGateStraightHorizontalGateClose = 3587,
-	MetalGateStraightVerticalLFGateClose = 3589,
-	MetalGateStraightVerticalGateClose = 3591,
-	SecurityGateDiagonalBLURLoweredGateOpen = 3593,
-	SecurityGateDiagonalULBRLoweredGateOpen = 3595,
-	SecurityGateStraightHorizontalBFLoweredGateOpen = 3597,
-	SecurityGateStraightHorizontalLoweredGateOpen = 3599,
-	SecurityGateStraightVerticalLFLoweredGateOpen = 3601,
-	SecurityGateStraightVerticalLoweredGateOpen = 3603,
-	SecurityGateDiagonalBLURGateClose = 3605,
-	SecurityGateDiagonalULBRGateClose = 3607,
-	SecurityGateStraightHorizontalBFGateClose = 3609,
-	SecurityGateStraightHorizontalGateClose = 3611,
-	SecurityGateStraightVerticalLFGateClose = 3613,
-	SecurityGateStraightVerticalGateClose = 3615,
-	ChangeShrineTerranChangeShrineTerran = 3617,
-	ChangeShrineProtossChangeShrineProtoss = 3619,
-	SpectreHoldFireSpectreHoldFire = 3621,
-	SpectreWeaponsFreeWeaponsFree = 3623,
-	GWALearnTestLearn = 3626,
-	LightBridgeOffTopRightLightBridgeOff = 3647,
-	LightBridgeOnTopRightLightBridgeOn = 3649,
-	TestHeroGrabGrabZergling = 3651,
-	TestHeroThrowThrowZergling = 3653,
-	TestHeroDebugMissileAbilityTestHeroDebugMissileAbility = 3655,
-	TestHeroDebugTrackingAbilityTestHeroDebugTrackingAbility = 3657,
-	TestHeroDebugTrackingAbilityCancel = 3658,
-	Cancel = 3659,
-	Halt = 3660,
-	BurrowDown = 3661,
-	BurrowUp = 3662,
-	LoadAll = 3663,
-	UnloadAll = 3664,
-	Stop = 3665,
-	HarvestGather = 3666,
-	HarvestReturn = 3667,
-	Load = 3668,
-	UnloadAllAt = 3669,
-	CancelLast = 3671,
-	CancelSlot = 3672,
-	RallyUnits = 3673,
-	Attack = 3674,
-	EffectStim = 3675,
-	BehaviorCloakOn = 3676,
-	BehaviorCloakOff = 3677,
-	Land = 3678,
-	Lift = 3679,
-	MorphRoot = 3680,
-	MorphUproot = 3681,
-	BuildTechLab = 3682,
-	BuildReactor = 3683,
-	EffectSpray = 3684,
-	EffectRepair = 3685,
-	EffectMassRecall = 3686,
-	EffectBlink = 3687,
-	BehaviorHoldFireOn = 3688,
-	BehaviorHoldFireOff = 3689,
-	RallyWorkers = 3690,
-	BuildCreepTumor = 3691,
-	ResearchProtossAirArmor = 3692,
-	ResearchProtossAirWeapons = 3693,
-	ResearchProtossGroundArmor = 3694,
-	ResearchProtossGroundWeapons = 3695,
-	ResearchProtossShields = 3696,
-	ResearchTerranInfantryArmor = 3697,
-	ResearchTerranInfantryWeapons = 3698,
-	ResearchTerranShipWeapons = 3699,
-	ResearchTerranVehicleAndShipPlating = 3700,
-	ResearchTerranVehicleWeapons = 3701,
-	ResearchZergFlyerArmor = 3702,
-	ResearchZergFlyerAttack = 3703,
-	ResearchZergGroundArmor = 3704,
-	ResearchZergMeleeWeapons = 3705,
-	ResearchZergMissileWeapons = 3706,
-	CancelVoidRayPrismaticAlignment = 3707,
-	ResearchAdaptiveTalons = 3709,
-	MorphObserverMode = 3739,
-	MorphSurveillanceMode = 3741,
-	MorphOversightMode = 3743,
-	MorphOverseerMode = 3745,
-	EffectInterferenceMatrix = 3747,
-	EffectRepairDrone = 3749,
-	EffectRepairRepairDrone = 3751,
-	EffectAntiArmorMissile = 3753,
-	EffectChronoBoostEnergyCost = 3755,
-	EffectMassRecallNexus = 3757,
-	NexusShieldRechargeNexusShieldRecharge = 3759,
-	NexusShieldRechargeOnPylonNexusShieldRechargeOnPylon = 3761,
-	InfestorEnsnareInfestorEnsnare = 3763,
-	EffectRestore = 3765,
-	NexusShieldOverchargeNexusShieldOvercharge = 3767,
-	NexusShieldOverchargeOffNexusShieldOverchargeOff = 3769,
-	AttackBattlecruiser = 3771,
-	BattlecruiserAttackAttackTowards = 3772,
-	BattlecruiserAttackAttackBarrage = 3773,
-	BattlecruiserAttackEvaluatorMothershipCoreAttack = 3774,
-	MoveBattlecruiser = 3776,
-	PatrolBattlecruiser = 3777,
-	HoldPositionBattlecruiser = 3778,
-	BattlecruiserMoveAcquireMove = 3779,
-	BattlecruiserMoveTurn = 3780,
-	BattlecruiserStopEvaluatorStop = 3781,
-	StopBattlecruiser = 3783,
-	BattlecruiserStopHoldFire = 3784,
-	BattlecruiserStopCheer = 3785,
-	BattlecruiserStopDance = 3786,
-	ViperParasiticBombRelayParasiticBomb = 3789,
-	ParasiticBombRelayDodgeParasiticBomb = 3791,
-	HoldPosition = 3793,
-	Move = 3794,
-	Patrol = 3795,
-	UnloadUnit = 3796,
-	MorphToCollapsibleRockTowerDebrisRampLeftGreenCancel = 3798,
-	MorphToCollapsibleRockTowerDebrisRampRightGreenCancel = 3800,
-}
@@ -15,3 +15,7 @@ pub use buff_id::BuffId;
 pub use effect_id::EffectId;
 
 mod impls;
+mod remaps;
+mod versions;
+
+pub use versions::DataVersion;
@@ -0,0 +1,281 @@
+//! Ids of game versions 4.10.x, which differ from the latest ones.
+#![allow(deprecated)]
+
+use super::*;
+use num_traits::{FromPrimitive, ToPrimitive};
+
+pub(crate) fn unit_typeid_from_4_10(raw: u32) -> Option<UnitTypeId> {
+	match raw {
+		1943 => Some(UnitTypeId::MechaZerglingACGluescreenDummy),
+		1944 => Some(UnitTypeId::MechaBanelingACGluescreenDummy),
+		1945 => Some(UnitTypeId::MechaHydraliskACGluescreenDummy),
+		1946 => Some(UnitTypeId::MechaInfestorACGluescreenDummy),
+		1947 => Some(UnitTypeId::MechaCorruptorACGluescreenDummy),
+		1948 => Some(UnitTypeId::MechaUltraliskACGluescreenDummy),
+		1949 => Some(UnitTypeId::MechaOverseerACGluescreenDummy),
+		1950 => Some(UnitTypeId::MechaLurkerACGluescreenDummy),
+		1951 => Some(UnitTypeId::MechaBattlecarrierLordACGluescreenDummy),
+		1952 => Some(UnitTypeId::MechaSpineCrawlerACGluescreenDummy),
+		1953 => Some(UnitTypeId::MechaSporeCrawlerACGluescreenDummy),
+		1954 => Some(UnitTypeId::PreviewBunkerUpgraded),
+		1955 => Some(UnitTypeId::AssimilatorRich),
+		1956 => Some(UnitTypeId::ExtractorRich),
+		1957 => Some(UnitTypeId::InhibitorZoneSmall),
+		1958 => Some(UnitTypeId::InhibitorZoneMedium),
+		1959 => Some(UnitTypeId::InhibitorZoneLarge),
+		1960 => Some(UnitTypeId::RefineryRich),
+		1961 => Some(UnitTypeId::MineralField450),
+		1962 => Some(UnitTypeId::MineralFieldOpaque),
+		1963 => Some(UnitTypeId::MineralFieldOpaque900),
+		1964 => Some(UnitTypeId::CollapsibleRockTowerDebrisRampLeftGreen),
+		1965 => Some(UnitTypeId::CollapsibleRockTowerDebrisRampRightGreen),
+		1966 => Some(UnitTypeId::CollapsibleRockTowerPushUnitRampLeftGreen),
+		1967 => Some(UnitTypeId::CollapsibleRockTowerPushUnitRampRightGreen),
+		1968 => Some(UnitTypeId::CollapsibleRockTowerRampLeftGreen),
+		1969 => Some(UnitTypeId::CollapsibleRockTowerRampRightGreen),
+		1970 => None,
+		1971 => None,
+		1972 => None,
+		1973 => None,
+		1974 => None,
+		1975 => None,
+		1976 => None,
+		1977 => None,
+		1978 => None,
+		1979 => None,
+		1980 => None,
+		1981 => None,
+		1982 => None,
+		1983 => None,
+		1984 => None,
+		1985 => None,
+		1986 => None,
+		1987 => None,
+		1988 => None,
+		1989 => None,
+		1990 => None,
+		1991 => None,
+		1992 => None,
+		1993 => None,
+		1994 => None,
+		1995 => None,
+		1996 => None,
+		1997 => None,
+		1998 => None,
+		1999 => None,
+		2000 => None,
+		2001 => None,
+		2002 => None,
+		2003 => None,
+		2004 => None,
+		_ => UnitTypeId::from_u32(raw),
+	}
+}
+
+pub(crate) fn unit_typeid_to_4_10(id: UnitTypeId) -> u32 {
+	match id {
+		UnitTypeId::RefineryRich => 1960,
+		UnitTypeId::MechaZerglingACGluescreenDummy => 1943,
+		UnitTypeId::MechaBanelingACGluescreenDummy => 1944,
+		UnitTypeId::MechaHydraliskACGluescreenDummy => 1945,
+		UnitTypeId::MechaInfestorACGluescreenDummy => 1946,
+		UnitTypeId::MechaCorruptorACGluescreenDummy => 1947,
+		UnitTypeId::MechaUltraliskACGluescreenDummy => 1948,
+		UnitTypeId::MechaOverseerACGluescreenDummy => 1949,
+		UnitTypeId::MechaLurkerACGluescreenDummy => 1950,
+		UnitTypeId::MechaBattlecarrierLordACGluescreenDummy => 1951,
+		UnitTypeId::MechaSpineCrawlerACGluescreenDummy => 1952,
+		UnitTypeId::MechaSporeCrawlerACGluescreenDummy => 1953,
+		UnitTypeId::TrooperMengskACGluescreenDummy => 0,
+		UnitTypeId::MedivacMengskACGluescreenDummy => 0,
+		UnitTypeId::BlimpMengskACGluescreenDummy => 0,
+		UnitTypeId::MarauderMengskACGluescreenDummy => 0,
+		UnitTypeId::GhostMengskACGluescreenDummy => 0,
+		UnitTypeId::SiegeTankMengskACGluescreenDummy => 0,
+		UnitTypeId::ThorMengskACGluescreenDummy => 0,
+		UnitTypeId::VikingMengskACGluescreenDummy => 0,
+		UnitTypeId::BattlecruiserMengskACGluescreenDummy => 0,
+		UnitTypeId::BunkerDepotMengskACGluescreenDummy => 0,
+		UnitTypeId::MissileTurretMengskACGluescreenDummy => 0,
+		UnitTypeId::ArtilleryMengskACGluescreenDummy => 0,
+		UnitTypeId::LoadOutSpray1 => 0,
+		UnitTypeId::LoadOutSpray2 => 0,
+		UnitTypeId::LoadOutSpray3 => 0,
+		UnitTypeId::LoadOutSpray4 => 0,
+		UnitTypeId::LoadOutSpray5 => 0,
+		UnitTypeId::LoadOutSpray6 => 0,
+		UnitTypeId::LoadOutSpray7 => 0,
+		UnitTypeId::LoadOutSpray8 => 0,
+		UnitTypeId::LoadOutSpray9 => 0,
+		UnitTypeId::LoadOutSpray10 => 0,
+		UnitTypeId::LoadOutSpray11 => 0,
+		UnitTypeId::LoadOutSpray12 => 0,
+		UnitTypeId::LoadOutSpray13 => 0,
+		UnitTypeId::LoadOutSpray14 => 0,
+		UnitTypeId::PreviewBunkerUpgraded => 1954,
+		UnitTypeId::InhibitorZoneSmall => 1957,
+		UnitTypeId::InhibitorZoneMedium => 1958,
+		UnitTypeId::InhibitorZoneLarge => 1959,
+		UnitTypeId::AccelerationZoneSmall => 0,
+		UnitTypeId::AccelerationZoneMedium => 0,
+		UnitTypeId::AccelerationZoneLarge => 0,
+		UnitTypeId::AccelerationZoneFlyingSmall => 0,
+		UnitTypeId::AccelerationZoneFlyingMedium => 0,
+		UnitTypeId::AccelerationZoneFlyingLarge => 0,
+		UnitTypeId::InhibitorZoneFlyingSmall => 0,
+		UnitTypeId::InhibitorZoneFlyingMedium => 0,
+		UnitTypeId::InhibitorZoneFlyingLarge => 0,
+		UnitTypeId::AssimilatorRich => 1955,
+		UnitTypeId::ExtractorRich => 1956,
+		UnitTypeId::MineralField450 => 1961,
+		UnitTypeId::MineralFieldOpaque => 1962,
+		UnitTypeId::MineralFieldOpaque900 => 1963,
+		UnitTypeId::CollapsibleRockTowerDebrisRampLeftGreen => 1964,
+		UnitTypeId::CollapsibleRockTowerDebrisRampRightGreen => 1965,
+		UnitTypeId::CollapsibleRockTowerPushUnitRampLeftGreen => 1966,
+		UnitTypeId::CollapsibleRockTowerPushUnitRampRightGreen => 1967,
+		UnitTypeId::CollapsibleRockTowerRampLeftGreen => 1968,
+		UnitTypeId::CollapsibleRockTowerRampRightGreen => 1969,
+		_ => id.to_u32().unwrap(),
+	}
+}
+
+pub(crate) fn ability_id_from_4_10(raw: u32) -> Option<AbilityId> {
+	match raw {
+		48 => None,
+		49 => None,
+		822 => None,
+		1195 => None,
+		1457 => None,
+		1533 => None,
+		1534 => None,
+		1595 => None,
+		1596 => None,
+		1597 => None,
+		3710 => None,
+		3797 => None,
+		3798 => Some(AbilityId::MorphToCollapsibleRockTowerDebrisRampLeftGreenCancel),
+		3799 => None,
+		3800 => Some(AbilityId::MorphToCollapsibleRockTowerDebrisRampRightGreenCancel),
+		3801 => None,
+		3802 => None,
+		3803 => None,
+		3804 => None,
+		3805 => None,
+		3806 => None,
+		3807 => None,
+		3808 => None,
+		3809 => None,
+		3810 => None,
+		3967 => None,
+		3970 => None,
+		4107 => None,
+		4109 => None,
+		4111 => None,
+		4112 => None,
+		_ => AbilityId::from_u32(raw),
+	}
+}
+
+pub(crate) fn ability_id_to_4_10(id: AbilityId) -> u32 {
+	match id {
+		AbilityId::FleetBeaconResearchVoidRaySpeedUpgrade => 0,
+		AbilityId::FleetBeaconResearchTempestResearchGroundAttackUpgrade => 0,
+		AbilityId::GhostAcademyResearchEnhancedShockwaves => 0,
+		AbilityId::EvolutionchamberresearchEvolvePropulsivePeristalsis => 0,
+		AbilityId::InfestationPitResearchEvolveAmorphousArmorcloud => 0,
+		AbilityId::FusionCoreResearchBallisticRange => 0,
+		AbilityId::FusionCoreResearchRapidReignitionSystem => 0,
+		AbilityId::TwilightCouncilResearchPsionicSurge => 0,
+		AbilityId::TwilightCouncilResearchAmplifiedShielding => 0,
+		AbilityId::TwilightCouncilResearchPsionicAmplifiers => 0,
+		AbilityId::LurkerDenResearchLurkerRange => 0,
+		AbilityId::LoadOutSprayLoadOutSpray1 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray2 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray3 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray4 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray5 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray6 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray7 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray8 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray9 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray10 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray11 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray12 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray13 => 0,
+		AbilityId::LoadOutSprayLoadOutSpray14 => 0,
+		AbilityId::MorphToCollapsibleRockTowerDebrisRampLeftGreenCancel => 3798,
+		AbilityId::MorphToCollapsibleRockTowerDebrisRampRightGreenCancel => 3800,
+		AbilityId::BatteryOverchargeBatteryOvercharge => 0,
+		AbilityId::AmorphousArmorcloudAmorphousArmorcloud => 0,
+		AbilityId::ShieldBatteryRechargeEx5ShieldBatteryRecharge => 0,
+		AbilityId::ShieldBatteryRechargeEx5Stop => 0,
+		_ => id.to_u32().unwrap(),
+	}
+}
+
+pub(crate) fn upgrade_id_from_4_10(raw: u32) -> Option<UpgradeId> {
+	match raw {
+		296 => None,
+		297 => None,
+		298 => None,
+		299 => None,
+		300 => None,
+		301 => None,
+		302 => None,
+		_ => UpgradeId::from_u32(raw),
+	}
+}
+
+pub(crate) fn upgrade_id_to_4_10(id: UpgradeId) -> u32 {
+	match id {
+		UpgradeId::TempestGroundAttackUpgrade => 0,
+		UpgradeId::EnhancedShockwaves => 0,
+		UpgradeId::MicrobialShroud => 0,
+		UpgradeId::SunderingImpact => 0,
+		UpgradeId::AmplifiedShielding => 0,
+		UpgradeId::PsionicAmplifiers => 0,
+		UpgradeId::SecretedCoating => 0,
+		_ => id.to_u32().unwrap(),
+	}
+}
+
+pub(crate) fn buff_id_from_4_10(raw: u32) -> Option<BuffId> {
+	match raw {
+		289 => Some(BuffId::InhibitorZoneTemporalField),
+		290 => None,
+		291 => None,
+		292 => None,
+		293 => None,
+		294 => None,
+		295 => None,
+		296 => None,
+		297 => None,
+		298 => None,
+		_ => BuffId::from_u32(raw),
+	}
+}
+
+pub(crate) fn buff_id_to_4_10(id: BuffId) -> u32 {
+	match id {
+		BuffId::AccelerationZoneTemporalField => 0,
+		BuffId::AccelerationZoneFlyingTemporalField => 0,
+		BuffId::InhibitorZoneFlyingTemporalField => 0,
+		BuffId::LoadOutSprayTracker => 0,
+		BuffId::InhibitorZoneTemporalField => 289,
+		BuffId::ResonatingGlaivesPhaseShift => 0,
+		BuffId::NeuralParasiteChildren => 0,
+		BuffId::AmorphousArmorcloud => 0,
+		BuffId::RavenShredderMissileArmorReductionUISubtruct => 0,
+		BuffId::BatteryOvercharge => 0,
+		_ => id.to_u32().unwrap(),
+	}
+}
+
+pub(crate) fn effect_id_from_4_10(raw: u32) -> Option<EffectId> {
+	EffectId::from_u32(raw)
+}
+
+pub(crate) fn effect_id_to_4_10(id: EffectId) -> u32 {
+	id.to_u32().unwrap()
+}
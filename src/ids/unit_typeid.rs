@@ -3,9 +3,8 @@
 #[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
-#[cfg(target_os = "windows")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, FromPrimitive, ToPrimitive, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, FromPrimitive, ToPrimitive, FromStr, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum UnitTypeId {
 	NotAUnit = 0,
 	SystemSnapshotDummy = 1,
@@ -2017,1983 +2016,3 @@ pub enum UnitTypeId {
 	CollapsibleRockTowerRampLeftGreen = 2003,
 	CollapsibleRockTowerRampRightGreen = 2004,
 }
-
-#[cfg(target_os = "linux")]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, FromPrimitive, ToPrimitive, Copy, Clone, PartialEq, Eq, Hash)]
-pub enum UnitTypeId {
-	NotAUnit = 0,
-	SystemSnapshotDummy = 1,
-	Ball = 2,
-	StereoscopicOptionsUnit = 3,
-	Colossus = 4,
-	TechLab = 5,
-	Reactor = 6,
-	InfestorTerran = 7,
-	BanelingCocoon = 8,
-	Baneling = 9,
-	Mothership = 10,
-	PointDefenseDrone = 11,
-	Changeling = 12,
-	ChangelingZealot = 13,
-	ChangelingMarineShield = 14,
-	ChangelingMarine = 15,
-	ChangelingZerglingWings = 16,
-	ChangelingZergling = 17,
-	CommandCenter = 18,
-	SupplyDepot = 19,
-	Refinery = 20,
-	Barracks = 21,
-	EngineeringBay = 22,
-	MissileTurret = 23,
-	Bunker = 24,
-	SensorTower = 25,
-	GhostAcademy = 26,
-	Factory = 27,
-	Starport = 28,
-	Armory = 29,
-	FusionCore = 30,
-	AutoTurret = 31,
-	SiegeTankSieged = 32,
-	SiegeTank = 33,
-	VikingAssault = 34,
-	VikingFighter = 35,
-	CommandCenterFlying = 36,
-	BarracksTechLab = 37,
-	BarracksReactor = 38,
-	FactoryTechLab = 39,
-	FactoryReactor = 40,
-	StarportTechLab = 41,
-	StarportReactor = 42,
-	FactoryFlying = 43,
-	StarportFlying = 44,
-	SCV = 45,
-	BarracksFlying = 46,
-	SupplyDepotLowered = 47,
-	Marine = 48,
-	Reaper = 49,
-	Ghost = 50,
-	Marauder = 51,
-	Thor = 52,
-	Hellion = 53,
-	Medivac = 54,
-	Banshee = 55,
-	Raven = 56,
-	Battlecruiser = 57,
-	Nuke = 58,
-	Nexus = 59,
-	Pylon = 60,
-	Assimilator = 61,
-	Gateway = 62,
-	Forge = 63,
-	FleetBeacon = 64,
-	TwilightCouncil = 65,
-	PhotonCannon = 66,
-	Stargate = 67,
-	TemplarArchive = 68,
-	DarkShrine = 69,
-	RoboticsBay = 70,
-	RoboticsFacility = 71,
-	CyberneticsCore = 72,
-	Zealot = 73,
-	Stalker = 74,
-	HighTemplar = 75,
-	DarkTemplar = 76,
-	Sentry = 77,
-	Phoenix = 78,
-	Carrier = 79,
-	VoidRay = 80,
-	WarpPrism = 81,
-	Observer = 82,
-	Immortal = 83,
-	Probe = 84,
-	Interceptor = 85,
-	Hatchery = 86,
-	CreepTumor = 87,
-	Extractor = 88,
-	SpawningPool = 89,
-	EvolutionChamber = 90,
-	HydraliskDen = 91,
-	Spire = 92,
-	UltraliskCavern = 93,
-	InfestationPit = 94,
-	NydusNetwork = 95,
-	BanelingNest = 96,
-	RoachWarren = 97,
-	SpineCrawler = 98,
-	SporeCrawler = 99,
-	Lair = 100,
-	Hive = 101,
-	GreaterSpire = 102,
-	Egg = 103,
-	Drone = 104,
-	Zergling = 105,
-	Overlord = 106,
-	Hydralisk = 107,
-	Mutalisk = 108,
-	Ultralisk = 109,
-	Roach = 110,
-	Infestor = 111,
-	Corruptor = 112,
-	BroodLordCocoon = 113,
-	BroodLord = 114,
-	BanelingBurrowed = 115,
-	DroneBurrowed = 116,
-	HydraliskBurrowed = 117,
-	RoachBurrowed = 118,
-	ZerglingBurrowed = 119,
-	InfestorTerranBurrowed = 120,
-	RedstoneLavaCritterBurrowed = 121,
-	RedstoneLavaCritterInjuredBurrowed = 122,
-	RedstoneLavaCritter = 123,
-	RedstoneLavaCritterInjured = 124,
-	QueenBurrowed = 125,
-	Queen = 126,
-	InfestorBurrowed = 127,
-	OverlordCocoon = 128,
-	Overseer = 129,
-	PlanetaryFortress = 130,
-	UltraliskBurrowed = 131,
-	OrbitalCommand = 132,
-	WarpGate = 133,
-	OrbitalCommandFlying = 134,
-	ForceField = 135,
-	WarpPrismPhasing = 136,
-	CreepTumorBurrowed = 137,
-	CreepTumorQueen = 138,
-	SpineCrawlerUprooted = 139,
-	SporeCrawlerUprooted = 140,
-	Archon = 141,
-	NydusCanal = 142,
-	BroodlingEscort = 143,
-	GhostAlternate = 144,
-	GhostNova = 145,
-	RichMineralField = 146,
-	RichMineralField750 = 147,
-	Ursadon = 148,
-	XelNagaTower = 149,
-	InfestedTerransEgg = 150,
-	Larva = 151,
-	ReaperPlaceholder = 152,
-	MarineACGluescreenDummy = 153,
-	FirebatACGluescreenDummy = 154,
-	MedicACGluescreenDummy = 155,
-	MarauderACGluescreenDummy = 156,
-	VultureACGluescreenDummy = 157,
-	SiegeTankACGluescreenDummy = 158,
-	VikingACGluescreenDummy = 159,
-	BansheeACGluescreenDummy = 160,
-	BattlecruiserACGluescreenDummy = 161,
-	OrbitalCommandACGluescreenDummy = 162,
-	BunkerACGluescreenDummy = 163,
-	BunkerUpgradedACGluescreenDummy = 164,
-	MissileTurretACGluescreenDummy = 165,
-	HellbatACGluescreenDummy = 166,
-	GoliathACGluescreenDummy = 167,
-	CycloneACGluescreenDummy = 168,
-	WraithACGluescreenDummy = 169,
-	ScienceVesselACGluescreenDummy = 170,
-	HerculesACGluescreenDummy = 171,
-	ThorACGluescreenDummy = 172,
-	PerditionTurretACGluescreenDummy = 173,
-	FlamingBettyACGluescreenDummy = 174,
-	DevastationTurretACGluescreenDummy = 175,
-	BlasterBillyACGluescreenDummy = 176,
-	SpinningDizzyACGluescreenDummy = 177,
-	ZerglingKerriganACGluescreenDummy = 178,
-	RaptorACGluescreenDummy = 179,
-	QueenCoopACGluescreenDummy = 180,
-	HydraliskACGluescreenDummy = 181,
-	HydraliskLurkerACGluescreenDummy = 182,
-	MutaliskBroodlordACGluescreenDummy = 183,
-	BroodLordACGluescreenDummy = 184,
-	UltraliskACGluescreenDummy = 185,
-	TorrasqueACGluescreenDummy = 186,
-	OverseerACGluescreenDummy = 187,
-	LurkerACGluescreenDummy = 188,
-	SpineCrawlerACGluescreenDummy = 189,
-	SporeCrawlerACGluescreenDummy = 190,
-	NydusNetworkACGluescreenDummy = 191,
-	OmegaNetworkACGluescreenDummy = 192,
-	ZerglingZagaraACGluescreenDummy = 193,
-	SwarmlingACGluescreenDummy = 194,
-	BanelingACGluescreenDummy = 195,
-	SplitterlingACGluescreenDummy = 196,
-	AberrationACGluescreenDummy = 197,
-	ScourgeACGluescreenDummy = 198,
-	CorruptorACGluescreenDummy = 199,
-	BileLauncherACGluescreenDummy = 200,
-	SwarmQueenACGluescreenDummy = 201,
-	RoachACGluescreenDummy = 202,
-	RoachVileACGluescreenDummy = 203,
-	RavagerACGluescreenDummy = 204,
-	SwarmHostACGluescreenDummy = 205,
-	MutaliskACGluescreenDummy = 206,
-	GuardianACGluescreenDummy = 207,
-	DevourerACGluescreenDummy = 208,
-	ViperACGluescreenDummy = 209,
-	BrutaliskACGluescreenDummy = 210,
-	LeviathanACGluescreenDummy = 211,
-	ZealotACGluescreenDummy = 212,
-	ZealotAiurACGluescreenDummy = 213,
-	DragoonACGluescreenDummy = 214,
-	HighTemplarACGluescreenDummy = 215,
-	ArchonACGluescreenDummy = 216,
-	ImmortalACGluescreenDummy = 217,
-	ObserverACGluescreenDummy = 218,
-	PhoenixAiurACGluescreenDummy = 219,
-	ReaverACGluescreenDummy = 220,
-	TempestACGluescreenDummy = 221,
-	PhotonCannonACGluescreenDummy = 222,
-	ZealotVorazunACGluescreenDummy = 223,
-	ZealotShakurasACGluescreenDummy = 224,
-	StalkerShakurasACGluescreenDummy = 225,
-	DarkTemplarShakurasACGluescreenDummy = 226,
-	CorsairACGluescreenDummy = 227,
-	VoidRayACGluescreenDummy = 228,
-	VoidRayShakurasACGluescreenDummy = 229,
-	OracleACGluescreenDummy = 230,
-	DarkArchonACGluescreenDummy = 231,
-	DarkPylonACGluescreenDummy = 232,
-	ZealotPurifierACGluescreenDummy = 233,
-	SentryPurifierACGluescreenDummy = 234,
-	ImmortalKaraxACGluescreenDummy = 235,
-	ColossusACGluescreenDummy = 236,
-	ColossusPurifierACGluescreenDummy = 237,
-	PhoenixPurifierACGluescreenDummy = 238,
-	CarrierACGluescreenDummy = 239,
-	CarrierAiurACGluescreenDummy = 240,
-	KhaydarinMonolithACGluescreenDummy = 241,
-	ShieldBatteryACGluescreenDummy = 242,
-	EliteMarineACGluescreenDummy = 243,
-	MarauderCommandoACGluescreenDummy = 244,
-	SpecOpsGhostACGluescreenDummy = 245,
-	HellbatRangerACGluescreenDummy = 246,
-	StrikeGoliathACGluescreenDummy = 247,
-	HeavySiegeTankACGluescreenDummy = 248,
-	RaidLiberatorACGluescreenDummy = 249,
-	RavenTypeIIACGluescreenDummy = 250,
-	CovertBansheeACGluescreenDummy = 251,
-	RailgunTurretACGluescreenDummy = 252,
-	BlackOpsMissileTurretACGluescreenDummy = 253,
-	SupplicantACGluescreenDummy = 254,
-	StalkerTaldarimACGluescreenDummy = 255,
-	SentryTaldarimACGluescreenDummy = 256,
-	HighTemplarTaldarimACGluescreenDummy = 257,
-	ImmortalTaldarimACGluescreenDummy = 258,
-	ColossusTaldarimACGluescreenDummy = 259,
-	WarpPrismTaldarimACGluescreenDummy = 260,
-	PhotonCannonTaldarimACGluescreenDummy = 261,
-	NeedleSpinesWeapon = 262,
-	CorruptionWeapon = 263,
-	InfestedTerransWeapon = 264,
-	NeuralParasiteWeapon = 265,
-	PointDefenseDroneReleaseWeapon = 266,
-	HunterSeekerWeapon = 267,
-	MULE = 268,
-	ThorAAWeapon = 269,
-	PunisherGrenadesLMWeapon = 270,
-	VikingFighterWeapon = 271,
-	ATALaserBatteryLMWeapon = 272,
-	ATSLaserBatteryLMWeapon = 273,
-	LongboltMissileWeapon = 274,
-	D8ChargeWeapon = 275,
-	YamatoWeapon = 276,
-	IonCannonsWeapon = 277,
-	AcidSalivaWeapon = 278,
-	SpineCrawlerWeapon = 279,
-	SporeCrawlerWeapon = 280,
-	GlaiveWurmWeapon = 281,
-	GlaiveWurmM2Weapon = 282,
-	GlaiveWurmM3Weapon = 283,
-	StalkerWeapon = 284,
-	EMP2Weapon = 285,
-	BacklashRocketsLMWeapon = 286,
-	PhotonCannonWeapon = 287,
-	ParasiteSporeWeapon = 288,
-	Broodling = 289,
-	BroodLordBWeapon = 290,
-	AutoTurretReleaseWeapon = 291,
-	LarvaReleaseMissile = 292,
-	AcidSpinesWeapon = 293,
-	FrenzyWeapon = 294,
-	ContaminateWeapon = 295,
-	BeaconRally = 296,
-	BeaconArmy = 297,
-	BeaconAttack = 298,
-	BeaconDefend = 299,
-	BeaconHarass = 300,
-	BeaconIdle = 301,
-	BeaconAuto = 302,
-	BeaconDetect = 303,
-	BeaconScout = 304,
-	BeaconClaim = 305,
-	BeaconExpand = 306,
-	BeaconCustom1 = 307,
-	BeaconCustom2 = 308,
-	BeaconCustom3 = 309,
-	BeaconCustom4 = 310,
-	Adept = 311,
-	Rocks2x2NonConjoined = 312,
-	FungalGrowthMissile = 313,
-	NeuralParasiteTentacleMissile = 314,
-	BeaconProtoss = 315,
-	BeaconProtossSmall = 316,
-	BeaconTerran = 317,
-	BeaconTerranSmall = 318,
-	BeaconZerg = 319,
-	BeaconZergSmall = 320,
-	Lyote = 321,
-	CarrionBird = 322,
-	KarakMale = 323,
-	KarakFemale = 324,
-	UrsadakFemaleExotic = 325,
-	UrsadakMale = 326,
-	UrsadakFemale = 327,
-	UrsadakCalf = 328,
-	UrsadakMaleExotic = 329,
-	UtilityBot = 330,
-	CommentatorBot1 = 331,
-	CommentatorBot2 = 332,
-	CommentatorBot3 = 333,
-	CommentatorBot4 = 334,
-	Scantipede = 335,
-	Dog = 336,
-	Sheep = 337,
-	Cow = 338,
-	InfestedTerransEggPlacement = 339,
-	InfestorTerransWeapon = 340,
-	MineralField = 341,
-	VespeneGeyser = 342,
-	SpacePlatformGeyser = 343,
-	RichVespeneGeyser = 344,
-	DestructibleSearchlight = 345,
-	DestructibleBullhornLights = 346,
-	DestructibleStreetlight = 347,
-	DestructibleSpacePlatformSign = 348,
-	DestructibleStoreFrontCityProps = 349,
-	DestructibleBillboardTall = 350,
-	DestructibleBillboardScrollingText = 351,
-	DestructibleSpacePlatformBarrier = 352,
-	DestructibleSignsDirectional = 353,
-	DestructibleSignsConstruction = 354,
-	DestructibleSignsFunny = 355,
-	DestructibleSignsIcons = 356,
-	DestructibleSignsWarning = 357,
-	DestructibleGarage = 358,
-	DestructibleGarageLarge = 359,
-	DestructibleTrafficSignal = 360,
-	TrafficSignal = 361,
-	BraxisAlphaDestructible1x1 = 362,
-	BraxisAlphaDestructible2x2 = 363,
-	DestructibleDebris4x4 = 364,
-	DestructibleDebris6x6 = 365,
-	DestructibleRock2x4Vertical = 366,
-	DestructibleRock2x4Horizontal = 367,
-	DestructibleRock2x6Vertical = 368,
-	DestructibleRock2x6Horizontal = 369,
-	DestructibleRock4x4 = 370,
-	DestructibleRock6x6 = 371,
-	DestructibleRampDiagonalHugeULBR = 372,
-	DestructibleRampDiagonalHugeBLUR = 373,
-	DestructibleRampVerticalHuge = 374,
-	DestructibleRampHorizontalHuge = 375,
-	DestructibleDebrisRampDiagonalHugeULBR = 376,
-	DestructibleDebrisRampDiagonalHugeBLUR = 377,
-	OverlordGenerateCreepKeybind = 378,
-	MengskStatueAlone = 379,
-	MengskStatue = 380,
-	WolfStatue = 381,
-	GlobeStatue = 382,
-	Weapon = 383,
-	GlaiveWurmBounceWeapon = 384,
-	BroodLordWeapon = 385,
-	BroodLordAWeapon = 386,
-	CreepBlocker1x1 = 387,
-	PermanentCreepBlocker1x1 = 388,
-	PathingBlocker1x1 = 389,
-	PathingBlocker2x2 = 390,
-	AutoTestAttackTargetGround = 391,
-	AutoTestAttackTargetAir = 392,
-	AutoTestAttacker = 393,
-	HelperEmitterSelectionArrow = 394,
-	MultiKillObject = 395,
-	ShapeGolfball = 396,
-	ShapeCone = 397,
-	ShapeCube = 398,
-	ShapeCylinder = 399,
-	ShapeDodecahedron = 400,
-	ShapeIcosahedron = 401,
-	ShapeOctahedron = 402,
-	ShapePyramid = 403,
-	ShapeRoundedCube = 404,
-	ShapeSphere = 405,
-	ShapeTetrahedron = 406,
-	ShapeThickTorus = 407,
-	ShapeThinTorus = 408,
-	ShapeTorus = 409,
-	Shape4PointStar = 410,
-	Shape5PointStar = 411,
-	Shape6PointStar = 412,
-	Shape8PointStar = 413,
-	ShapeArrowPointer = 414,
-	ShapeBowl = 415,
-	ShapeBox = 416,
-	ShapeCapsule = 417,
-	ShapeCrescentMoon = 418,
-	ShapeDecahedron = 419,
-	ShapeDiamond = 420,
-	ShapeFootball = 421,
-	ShapeGemstone = 422,
-	ShapeHeart = 423,
-	ShapeJack = 424,
-	ShapePlusSign = 425,
-	ShapeShamrock = 426,
-	ShapeSpade = 427,
-	ShapeTube = 428,
-	ShapeEgg = 429,
-	ShapeYenSign = 430,
-	ShapeX = 431,
-	ShapeWatermelon = 432,
-	ShapeWonSign = 433,
-	ShapeTennisball = 434,
-	ShapeStrawberry = 435,
-	ShapeSmileyFace = 436,
-	ShapeSoccerball = 437,
-	ShapeRainbow = 438,
-	ShapeSadFace = 439,
-	ShapePoundSign = 440,
-	ShapePear = 441,
-	ShapePineapple = 442,
-	ShapeOrange = 443,
-	ShapePeanut = 444,
-	ShapeO = 445,
-	ShapeLemon = 446,
-	ShapeMoneyBag = 447,
-	ShapeHorseshoe = 448,
-	ShapeHockeyStick = 449,
-	ShapeHockeyPuck = 450,
-	ShapeHand = 451,
-	ShapeGolfClub = 452,
-	ShapeGrape = 453,
-	ShapeEuroSign = 454,
-	ShapeDollarSign = 455,
-	ShapeBasketball = 456,
-	ShapeCarrot = 457,
-	ShapeCherry = 458,
-	ShapeBaseball = 459,
-	ShapeBaseballBat = 460,
-	ShapeBanana = 461,
-	ShapeApple = 462,
-	ShapeCashLarge = 463,
-	ShapeCashMedium = 464,
-	ShapeCashSmall = 465,
-	ShapeFootballColored = 466,
-	ShapeLemonSmall = 467,
-	ShapeOrangeSmall = 468,
-	ShapeTreasureChestOpen = 469,
-	ShapeTreasureChestClosed = 470,
-	ShapeWatermelonSmall = 471,
-	UnbuildableRocksDestructible = 472,
-	UnbuildableBricksDestructible = 473,
-	UnbuildablePlatesDestructible = 474,
-	Debris2x2NonConjoined = 475,
-	EnemyPathingBlocker1x1 = 476,
-	EnemyPathingBlocker2x2 = 477,
-	EnemyPathingBlocker4x4 = 478,
-	EnemyPathingBlocker8x8 = 479,
-	EnemyPathingBlocker16x16 = 480,
-	ScopeTest = 481,
-	SentryACGluescreenDummy = 482,
-	MineralField750 = 483,
-	HellionTank = 484,
-	CollapsibleTerranTowerDebris = 485,
-	DebrisRampLeft = 486,
-	DebrisRampRight = 487,
-	MothershipCore = 488,
-	LocustMP = 489,
-	CollapsibleRockTowerDebris = 490,
-	NydusCanalAttacker = 491,
-	NydusCanalCreeper = 492,
-	SwarmHostBurrowedMP = 493,
-	SwarmHostMP = 494,
-	Oracle = 495,
-	Tempest = 496,
-	WarHound = 497,
-	WidowMine = 498,
-	Viper = 499,
-	WidowMineBurrowed = 500,
-	LurkerMPEgg = 501,
-	LurkerMP = 502,
-	LurkerMPBurrowed = 503,
-	LurkerDenMP = 504,
-	ExtendingBridgeNEWide8Out = 505,
-	ExtendingBridgeNEWide8 = 506,
-	ExtendingBridgeNWWide8Out = 507,
-	ExtendingBridgeNWWide8 = 508,
-	ExtendingBridgeNEWide10Out = 509,
-	ExtendingBridgeNEWide10 = 510,
-	ExtendingBridgeNWWide10Out = 511,
-	ExtendingBridgeNWWide10 = 512,
-	ExtendingBridgeNEWide12Out = 513,
-	ExtendingBridgeNEWide12 = 514,
-	ExtendingBridgeNWWide12Out = 515,
-	ExtendingBridgeNWWide12 = 516,
-	CollapsibleRockTowerDebrisRampRight = 517,
-	CollapsibleRockTowerDebrisRampLeft = 518,
-	XelNagaCavernsDoorE = 519,
-	XelNagaCavernsDoorEOpened = 520,
-	XelNagaCavernsDoorN = 521,
-	XelNagaCavernsDoorNE = 522,
-	XelNagaCavernsDoorNEOpened = 523,
-	XelNagaCavernsDoorNOpened = 524,
-	XelNagaCavernsDoorNW = 525,
-	XelNagaCavernsDoorNWOpened = 526,
-	XelNagaCavernsDoorS = 527,
-	XelNagaCavernsDoorSE = 528,
-	XelNagaCavernsDoorSEOpened = 529,
-	XelNagaCavernsDoorSOpened = 530,
-	XelNagaCavernsDoorSW = 531,
-	XelNagaCavernsDoorSWOpened = 532,
-	XelNagaCavernsDoorW = 533,
-	XelNagaCavernsDoorWOpened = 534,
-	XelNagaCavernsFloatingBridgeNE8Out = 535,
-	XelNagaCavernsFloatingBridgeNE8 = 536,
-	XelNagaCavernsFloatingBridgeNW8Out = 537,
-	XelNagaCavernsFloatingBridgeNW8 = 538,
-	XelNagaCavernsFloatingBridgeNE10Out = 539,
-	XelNagaCavernsFloatingBridgeNE10 = 540,
-	XelNagaCavernsFloatingBridgeNW10Out = 541,
-	XelNagaCavernsFloatingBridgeNW10 = 542,
-	XelNagaCavernsFloatingBridgeNE12Out = 543,
-	XelNagaCavernsFloatingBridgeNE12 = 544,
-	XelNagaCavernsFloatingBridgeNW12Out = 545,
-	XelNagaCavernsFloatingBridgeNW12 = 546,
-	XelNagaCavernsFloatingBridgeH8Out = 547,
-	XelNagaCavernsFloatingBridgeH8 = 548,
-	XelNagaCavernsFloatingBridgeV8Out = 549,
-	XelNagaCavernsFloatingBridgeV8 = 550,
-	XelNagaCavernsFloatingBridgeH10Out = 551,
-	XelNagaCavernsFloatingBridgeH10 = 552,
-	XelNagaCavernsFloatingBridgeV10Out = 553,
-	XelNagaCavernsFloatingBridgeV10 = 554,
-	XelNagaCavernsFloatingBridgeH12Out = 555,
-	XelNagaCavernsFloatingBridgeH12 = 556,
-	XelNagaCavernsFloatingBridgeV12Out = 557,
-	XelNagaCavernsFloatingBridgeV12 = 558,
-	CollapsibleTerranTowerPushUnitRampLeft = 559,
-	CollapsibleTerranTowerPushUnitRampRight = 560,
-	CollapsibleRockTowerPushUnit = 561,
-	CollapsibleTerranTowerPushUnit = 562,
-	CollapsibleRockTowerPushUnitRampRight = 563,
-	CollapsibleRockTowerPushUnitRampLeft = 564,
-	DigesterCreepSprayTargetUnit = 565,
-	DigesterCreepSprayUnit = 566,
-	NydusCanalAttackerWeapon = 567,
-	ViperConsumeStructureWeapon = 568,
-	ResourceBlocker = 569,
-	TempestWeapon = 570,
-	YoinkMissile = 571,
-	YoinkVikingAirMissile = 572,
-	YoinkVikingGroundMissile = 573,
-	YoinkSiegeTankMissile = 574,
-	WarHoundWeapon = 575,
-	EyeStalkWeapon = 576,
-	WidowMineWeapon = 577,
-	WidowMineAirWeapon = 578,
-	MothershipCoreWeaponWeapon = 579,
-	TornadoMissileWeapon = 580,
-	TornadoMissileDummyWeapon = 581,
-	TalonsMissileWeapon = 582,
-	CreepTumorMissile = 583,
-	LocustMPEggAMissileWeapon = 584,
-	LocustMPEggBMissileWeapon = 585,
-	LocustMPWeapon = 586,
-	RepulsorCannonWeapon = 587,
-	CollapsibleRockTowerDiagonal = 588,
-	CollapsibleTerranTowerDiagonal = 589,
-	CollapsibleTerranTowerRampLeft = 590,
-	CollapsibleTerranTowerRampRight = 591,
-	Ice2x2NonConjoined = 592,
-	IceProtossCrates = 593,
-	ProtossCrates = 594,
-	TowerMine = 595,
-	PickupPalletGas = 596,
-	PickupPalletMinerals = 597,
-	PickupScrapSalvage1x1 = 598,
-	PickupScrapSalvage2x2 = 599,
-	PickupScrapSalvage3x3 = 600,
-	RoughTerrain = 601,
-	UnbuildableBricksSmallUnit = 602,
-	UnbuildablePlatesSmallUnit = 603,
-	UnbuildablePlatesUnit = 604,
-	UnbuildableRocksSmallUnit = 605,
-	XelNagaHealingShrine = 606,
-	InvisibleTargetDummy = 607,
-	ProtossVespeneGeyser = 608,
-	CollapsibleRockTower = 609,
-	CollapsibleTerranTower = 610,
-	ThornLizard = 611,
-	CleaningBot = 612,
-	DestructibleRock6x6Weak = 613,
-	ProtossSnakeSegmentDemo = 614,
-	PhysicsCapsule = 615,
-	PhysicsCube = 616,
-	PhysicsCylinder = 617,
-	PhysicsKnot = 618,
-	PhysicsL = 619,
-	PhysicsPrimitives = 620,
-	PhysicsSphere = 621,
-	PhysicsStar = 622,
-	CreepBlocker4x4 = 623,
-	DestructibleCityDebris2x4Vertical = 624,
-	DestructibleCityDebris2x4Horizontal = 625,
-	DestructibleCityDebris2x6Vertical = 626,
-	DestructibleCityDebris2x6Horizontal = 627,
-	DestructibleCityDebris4x4 = 628,
-	DestructibleCityDebris6x6 = 629,
-	DestructibleCityDebrisHugeDiagonalBLUR = 630,
-	DestructibleCityDebrisHugeDiagonalULBR = 631,
-	TestZerg = 632,
-	PathingBlockerRadius1 = 633,
-	DestructibleRockEx12x4Vertical = 634,
-	DestructibleRockEx12x4Horizontal = 635,
-	DestructibleRockEx12x6Vertical = 636,
-	DestructibleRockEx12x6Horizontal = 637,
-	DestructibleRockEx14x4 = 638,
-	DestructibleRockEx16x6 = 639,
-	DestructibleRockEx1DiagonalHugeULBR = 640,
-	DestructibleRockEx1DiagonalHugeBLUR = 641,
-	DestructibleRockEx1VerticalHuge = 642,
-	DestructibleRockEx1HorizontalHuge = 643,
-	DestructibleIce2x4Vertical = 644,
-	DestructibleIce2x4Horizontal = 645,
-	DestructibleIce2x6Vertical = 646,
-	DestructibleIce2x6Horizontal = 647,
-	DestructibleIce4x4 = 648,
-	DestructibleIce6x6 = 649,
-	DestructibleIceDiagonalHugeULBR = 650,
-	DestructibleIceDiagonalHugeBLUR = 651,
-	DestructibleIceVerticalHuge = 652,
-	DestructibleIceHorizontalHuge = 653,
-	DesertPlanetSearchlight = 654,
-	DesertPlanetStreetlight = 655,
-	UnbuildableBricksUnit = 656,
-	UnbuildableRocksUnit = 657,
-	ZerusDestructibleArch = 658,
-	Artosilope = 659,
-	Anteplott = 660,
-	LabBot = 661,
-	Crabeetle = 662,
-	CollapsibleRockTowerRampRight = 663,
-	CollapsibleRockTowerRampLeft = 664,
-	LabMineralField = 665,
-	LabMineralField750 = 666,
-	SnowRefineryTerranExtendingBridgeNEShort8Out = 667,
-	SnowRefineryTerranExtendingBridgeNEShort8 = 668,
-	SnowRefineryTerranExtendingBridgeNWShort8Out = 669,
-	SnowRefineryTerranExtendingBridgeNWShort8 = 670,
-	TarsonisDoorN = 671,
-	TarsonisDoorNLowered = 672,
-	TarsonisDoorNE = 673,
-	TarsonisDoorNELowered = 674,
-	TarsonisDoorE = 675,
-	TarsonisDoorELowered = 676,
-	TarsonisDoorNW = 677,
-	TarsonisDoorNWLowered = 678,
-	CompoundMansionDoorN = 679,
-	CompoundMansionDoorNLowered = 680,
-	CompoundMansionDoorNE = 681,
-	CompoundMansionDoorNELowered = 682,
-	CompoundMansionDoorE = 683,
-	CompoundMansionDoorELowered = 684,
-	CompoundMansionDoorNW = 685,
-	CompoundMansionDoorNWLowered = 686,
-	RavagerCocoon = 687,
-	Ravager = 688,
-	Liberator = 689,
-	RavagerBurrowed = 690,
-	ThorAP = 691,
-	Cyclone = 692,
-	LocustMPFlying = 693,
-	Disruptor = 694,
-	AiurLightBridgeNE8Out = 695,
-	AiurLightBridgeNE8 = 696,
-	AiurLightBridgeNE10Out = 697,
-	AiurLightBridgeNE10 = 698,
-	AiurLightBridgeNE12Out = 699,
-	AiurLightBridgeNE12 = 700,
-	AiurLightBridgeNW8Out = 701,
-	AiurLightBridgeNW8 = 702,
-	AiurLightBridgeNW10Out = 703,
-	AiurLightBridgeNW10 = 704,
-	AiurLightBridgeNW12Out = 705,
-	AiurLightBridgeNW12 = 706,
-	AiurTempleBridgeNE8Out = 707,
-	AiurTempleBridgeNE10Out = 708,
-	AiurTempleBridgeNE12Out = 709,
-	AiurTempleBridgeNW8Out = 710,
-	AiurTempleBridgeNW10Out = 711,
-	AiurTempleBridgeNW12Out = 712,
-	ShakurasLightBridgeNE8Out = 713,
-	ShakurasLightBridgeNE8 = 714,
-	ShakurasLightBridgeNE10Out = 715,
-	ShakurasLightBridgeNE10 = 716,
-	ShakurasLightBridgeNE12Out = 717,
-	ShakurasLightBridgeNE12 = 718,
-	ShakurasLightBridgeNW8Out = 719,
-	ShakurasLightBridgeNW8 = 720,
-	ShakurasLightBridgeNW10Out = 721,
-	ShakurasLightBridgeNW10 = 722,
-	ShakurasLightBridgeNW12Out = 723,
-	ShakurasLightBridgeNW12 = 724,
-	VoidMPImmortalReviveCorpse = 725,
-	GuardianCocoonMP = 726,
-	GuardianMP = 727,
-	DevourerCocoonMP = 728,
-	DevourerMP = 729,
-	DefilerMPBurrowed = 730,
-	DefilerMP = 731,
-	OracleStasisTrap = 732,
-	DisruptorPhased = 733,
-	LiberatorAG = 734,
-	AiurLightBridgeAbandonedNE8Out = 735,
-	AiurLightBridgeAbandonedNE8 = 736,
-	AiurLightBridgeAbandonedNE10Out = 737,
-	AiurLightBridgeAbandonedNE10 = 738,
-	AiurLightBridgeAbandonedNE12Out = 739,
-	AiurLightBridgeAbandonedNE12 = 740,
-	AiurLightBridgeAbandonedNW8Out = 741,
-	AiurLightBridgeAbandonedNW8 = 742,
-	AiurLightBridgeAbandonedNW10Out = 743,
-	AiurLightBridgeAbandonedNW10 = 744,
-	AiurLightBridgeAbandonedNW12Out = 745,
-	AiurLightBridgeAbandonedNW12 = 746,
-	CollapsiblePurifierTowerDebris = 747,
-	PortCityBridgeUnitNE8Out = 748,
-	PortCityBridgeUnitNE8 = 749,
-	PortCityBridgeUnitSE8Out = 750,
-	PortCityBridgeUnitSE8 = 751,
-	PortCityBridgeUnitNW8Out = 752,
-	PortCityBridgeUnitNW8 = 753,
-	PortCityBridgeUnitSW8Out = 754,
-	PortCityBridgeUnitSW8 = 755,
-	PortCityBridgeUnitNE10Out = 756,
-	PortCityBridgeUnitNE10 = 757,
-	PortCityBridgeUnitSE10Out = 758,
-	PortCityBridgeUnitSE10 = 759,
-	PortCityBridgeUnitNW10Out = 760,
-	PortCityBridgeUnitNW10 = 761,
-	PortCityBridgeUnitSW10Out = 762,
-	PortCityBridgeUnitSW10 = 763,
-	PortCityBridgeUnitNE12Out = 764,
-	PortCityBridgeUnitNE12 = 765,
-	PortCityBridgeUnitSE12Out = 766,
-	PortCityBridgeUnitSE12 = 767,
-	PortCityBridgeUnitNW12Out = 768,
-	PortCityBridgeUnitNW12 = 769,
-	PortCityBridgeUnitSW12Out = 770,
-	PortCityBridgeUnitSW12 = 771,
-	PortCityBridgeUnitN8Out = 772,
-	PortCityBridgeUnitN8 = 773,
-	PortCityBridgeUnitS8Out = 774,
-	PortCityBridgeUnitS8 = 775,
-	PortCityBridgeUnitE8Out = 776,
-	PortCityBridgeUnitE8 = 777,
-	PortCityBridgeUnitW8Out = 778,
-	PortCityBridgeUnitW8 = 779,
-	PortCityBridgeUnitN10Out = 780,
-	PortCityBridgeUnitN10 = 781,
-	PortCityBridgeUnitS10Out = 782,
-	PortCityBridgeUnitS10 = 783,
-	PortCityBridgeUnitE10Out = 784,
-	PortCityBridgeUnitE10 = 785,
-	PortCityBridgeUnitW10Out = 786,
-	PortCityBridgeUnitW10 = 787,
-	PortCityBridgeUnitN12Out = 788,
-	PortCityBridgeUnitN12 = 789,
-	PortCityBridgeUnitS12Out = 790,
-	PortCityBridgeUnitS12 = 791,
-	PortCityBridgeUnitE12Out = 792,
-	PortCityBridgeUnitE12 = 793,
-	PortCityBridgeUnitW12Out = 794,
-	PortCityBridgeUnitW12 = 795,
-	PurifierRichMineralField = 796,
-	PurifierRichMineralField750 = 797,
-	CollapsiblePurifierTowerPushUnit = 798,
-	LocustMPPrecursor = 799,
-	ReleaseInterceptorsBeacon = 800,
-	AdeptPhaseShift = 801,
-	RavagerCorrosiveBileMissile = 802,
-	HydraliskImpaleMissile = 803,
-	CycloneMissileLargeAir = 804,
-	CycloneMissile = 805,
-	CycloneMissileLarge = 806,
-	ThorAALance = 807,
-	OracleWeapon = 808,
-	TempestWeaponGround = 809,
-	RavagerWeaponMissile = 810,
-	ScoutMPAirWeaponLeft = 811,
-	ScoutMPAirWeaponRight = 812,
-	ArbiterMPWeaponMissile = 813,
-	GuardianMPWeapon = 814,
-	DevourerMPWeaponMissile = 815,
-	DefilerMPDarkSwarmWeapon = 816,
-	QueenMPEnsnareMissile = 817,
-	QueenMPSpawnBroodlingsMissile = 818,
-	LightningBombWeapon = 819,
-	HERCPlacement = 820,
-	GrappleWeapon = 821,
-	CausticSprayMissile = 822,
-	ParasiticBombMissile = 823,
-	ParasiticBombDummy = 824,
-	AdeptWeapon = 825,
-	AdeptUpgradeWeapon = 826,
-	LiberatorMissile = 827,
-	LiberatorDamageMissile = 828,
-	LiberatorAGMissile = 829,
-	KD8Charge = 830,
-	KD8ChargeWeapon = 831,
-	SlaynElementalGrabWeapon = 832,
-	SlaynElementalGrabAirUnit = 833,
-	SlaynElementalGrabGroundUnit = 834,
-	SlaynElementalWeapon = 835,
-	DestructibleExpeditionGate6x6 = 836,
-	DestructibleZergInfestation3x3 = 837,
-	HERC = 838,
-	Moopy = 839,
-	Replicant = 840,
-	SeekerMissile = 841,
-	AiurTempleBridgeDestructibleNE8Out = 842,
-	AiurTempleBridgeDestructibleNE10Out = 843,
-	AiurTempleBridgeDestructibleNE12Out = 844,
-	AiurTempleBridgeDestructibleNW8Out = 845,
-	AiurTempleBridgeDestructibleNW10Out = 846,
-	AiurTempleBridgeDestructibleNW12Out = 847,
-	AiurTempleBridgeDestructibleSW8Out = 848,
-	AiurTempleBridgeDestructibleSW10Out = 849,
-	AiurTempleBridgeDestructibleSW12Out = 850,
-	AiurTempleBridgeDestructibleSE8Out = 851,
-	AiurTempleBridgeDestructibleSE10Out = 852,
-	AiurTempleBridgeDestructibleSE12Out = 853,
-	FlyoverUnit = 854,
-	CorsairMP = 855,
-	ScoutMP = 856,
-	ArbiterMP = 857,
-	ScourgeMP = 858,
-	DefilerMPPlagueWeapon = 859,
-	QueenMP = 860,
-	XelNagaDestructibleRampBlocker6S = 861,
-	XelNagaDestructibleRampBlocker6SE = 862,
-	XelNagaDestructibleRampBlocker6E = 863,
-	XelNagaDestructibleRampBlocker6NE = 864,
-	XelNagaDestructibleRampBlocker6N = 865,
-	XelNagaDestructibleRampBlocker6NW = 866,
-	XelNagaDestructibleRampBlocker6W = 867,
-	XelNagaDestructibleRampBlocker6SW = 868,
-	XelNagaDestructibleRampBlocker8S = 869,
-	XelNagaDestructibleRampBlocker8SE = 870,
-	XelNagaDestructibleRampBlocker8E = 871,
-	XelNagaDestructibleRampBlocker8NE = 872,
-	XelNagaDestructibleRampBlocker8N = 873,
-	XelNagaDestructibleRampBlocker8NW = 874,
-	XelNagaDestructibleRampBlocker8W = 875,
-	XelNagaDestructibleRampBlocker8SW = 876,
-	ReptileCrate = 877,
-	SlaynSwarmHostSpawnFlyer = 878,
-	SlaynElemental = 879,
-	PurifierVespeneGeyser = 880,
-	ShakurasVespeneGeyser = 881,
-	CollapsiblePurifierTowerDiagonal = 882,
-	CreepOnlyBlocker4x4 = 883,
-	PurifierMineralField = 884,
-	PurifierMineralField750 = 885,
-	BattleStationMineralField = 886,
-	BattleStationMineralField750 = 887,
-	BeaconNova = 888,
-	BeaconNovaSmall = 889,
-	Ursula = 890,
-	ElsecaroColonistHut = 891,
-	TransportOverlordCocoon = 892,
-	OverlordTransport = 893,
-	PylonOvercharged = 894,
-	BypassArmorDrone = 895,
-	AdeptPiercingWeapon = 896,
-	CorrosiveParasiteWeapon = 897,
-	InfestedTerran = 898,
-	MercCompound = 899,
-	SupplyDepotDrop = 900,
-	#[deprecated(note = "Use `UnitTypeId::LurkerDenMP` instead.")]
-	LurkerDen = 901,
-	D8Charge = 902,
-	ThorWreckage = 903,
-	Goliath = 904,
-	TechReactor = 905,
-	SSPowerupBomb = 906,
-	SSPowerupHealth = 907,
-	SSPowerupSideMissiles = 908,
-	SSPowerupStrongerMissiles = 909,
-	#[deprecated(note = "Use `UnitTypeId::LurkerMPEgg` instead.")]
-	LurkerEgg = 910,
-	#[deprecated(note = "Use `UnitTypeId::LurkerMP` instead.")]
-	Lurker = 911,
-	#[deprecated(note = "Use `UnitTypeId::LurkerMPBurrowed` instead.")]
-	LurkerBurrowed = 912,
-	ArchiveSealed = 913,
-	InfestedCivilian = 914,
-	FlamingBetty = 915,
-	InfestedCivilianBurrowed = 916,
-	SelendisInterceptor = 917,
-	SiegeBreakerSieged = 918,
-	SiegeBreaker = 919,
-	PerditionTurretUnderground = 920,
-	PerditionTurret = 921,
-	SentryGunUnderground = 922,
-	SentryGun = 923,
-	WarPig = 924,
-	DevilDog = 925,
-	SpartanCompany = 926,
-	HammerSecurity = 927,
-	HelsAngelFighter = 928,
-	DuskWing = 929,
-	DukesRevenge = 930,
-	OdinWreckage = 931,
-	HeroNuke = 932,
-	KerriganCharBurrowed = 933,
-	KerriganChar = 934,
-	SpiderMineBurrowed = 935,
-	SpiderMine = 936,
-	Zeratul = 937,
-	Urun = 938,
-	Mohandar = 939,
-	Selendis = 940,
-	Scout = 941,
-	OmegaliskBurrowed = 942,
-	Omegalisk = 943,
-	InfestedAbominationBurrowed = 944,
-	InfestedAbomination = 945,
-	HunterKillerBurrowed = 946,
-	HunterKiller = 947,
-	InfestedTerranCampaignBurrowed = 948,
-	InfestedTerranCampaign = 949,
-	CheckStation = 950,
-	CheckStationDiagonalBLUR = 951,
-	CheckStationDiagonalULBR = 952,
-	CheckStationVertical = 953,
-	CheckStationOpened = 954,
-	CheckStationDiagonalBLUROpened = 955,
-	CheckStationDiagonalULBROpened = 956,
-	CheckStationVerticalOpened = 957,
-	BarracksTechReactor = 958,
-	FactoryTechReactor = 959,
-	StarportTechReactor = 960,
-	SpectreNuke = 961,
-	ColonistShipFlying = 962,
-	ColonistShip = 963,
-	BioDomeCommandFlying = 964,
-	BioDomeCommand = 965,
-	HerculesLanderFlying = 966,
-	HerculesLander = 967,
-	ZhakulDasLightBridgeOff = 968,
-	ZhakulDasLightBridge = 969,
-	ZhakulDasLibraryUnitBurrowed = 970,
-	ZhakulDasLibraryUnit = 971,
-	XelNagaTempleDoorBurrowed = 972,
-	XelNagaTempleDoor = 973,
-	XelNagaTempleDoorURDLBurrowed = 974,
-	XelNagaTempleDoorURDL = 975,
-	HelsAngelAssault = 976,
-	AutomatedRefinery = 977,
-	BattlecruiserHeliosMorph = 978,
-	HealingPotionTESTInstant = 979,
-	SpacePlatformCliffDoorOpen0 = 980,
-	SpacePlatformCliffDoor0 = 981,
-	SpacePlatformCliffDoorOpen1 = 982,
-	SpacePlatformCliffDoor1 = 983,
-	DestructibleGateDiagonalBLURLowered = 984,
-	DestructibleGateDiagonalULBRLowered = 985,
-	DestructibleGateStraightHorizontalBFLowered = 986,
-	DestructibleGateStraightHorizontalLowered = 987,
-	DestructibleGateStraightVerticalLFLowered = 988,
-	DestructibleGateStraightVerticalLowered = 989,
-	DestructibleGateDiagonalBLUR = 990,
-	DestructibleGateDiagonalULBR = 991,
-	DestructibleGateStraightHorizontalBF = 992,
-	DestructibleGateStraightHorizontal = 993,
-	DestructibleGateStraightVerticalLF = 994,
-	DestructibleGateStraightVertical = 995,
-	MetalGateDiagonalBLURLowered = 996,
-	MetalGateDiagonalULBRLowered = 997,
-	MetalGateStraightHorizontalBFLowered = 998,
-	MetalGateStraightHorizontalLowered = 999,
-	MetalGateStraightVerticalLFLowered = 1000,
-	MetalGateStraightVerticalLowered = 1001,
-	MetalGateDiagonalBLUR = 1002,
-	MetalGateDiagonalULBR = 1003,
-	MetalGateStraightHorizontalBF = 1004,
-	MetalGateStraightHorizontal = 1005,
-	MetalGateStraightVerticalLF = 1006,
-	MetalGateStraightVertical = 1007,
-	SecurityGateDiagonalBLURLowered = 1008,
-	SecurityGateDiagonalULBRLowered = 1009,
-	SecurityGateStraightHorizontalBFLowered = 1010,
-	SecurityGateStraightHorizontalLowered = 1011,
-	SecurityGateStraightVerticalLFLowered = 1012,
-	SecurityGateStraightVerticalLowered = 1013,
-	SecurityGateDiagonalBLUR = 1014,
-	SecurityGateDiagonalULBR = 1015,
-	SecurityGateStraightHorizontalBF = 1016,
-	SecurityGateStraightHorizontal = 1017,
-	SecurityGateStraightVerticalLF = 1018,
-	SecurityGateStraightVertical = 1019,
-	TerrazineNodeDeadTerran = 1020,
-	TerrazineNodeHappyProtoss = 1021,
-	ZhakulDasLightBridgeOffTopRight = 1022,
-	ZhakulDasLightBridgeTopRight = 1023,
-	BattlecruiserHelios = 1024,
-	NukeSiloNova = 1025,
-	Odin = 1026,
-	PygaliskCocoon = 1027,
-	DevourerTissueDoodad = 1028,
-	SSBattlecruiserMissileLauncher = 1029,
-	SSTerraTronMissileSpinnerMissile = 1030,
-	SSTerraTronSaw = 1031,
-	SSBattlecruiserHunterSeekerMissile = 1032,
-	SSLeviathanBomb = 1033,
-	DevourerTissueMissile = 1034,
-	SSInterceptor = 1035,
-	SSLeviathanBombMissile = 1036,
-	SSLeviathanSpawnBombMissile = 1037,
-	SSFighterMissileLeft = 1038,
-	SSFighterMissileRight = 1039,
-	SSInterceptorSpawnMissile = 1040,
-	SSCarrierBossMissile = 1041,
-	SSLeviathanTentacleTarget = 1042,
-	SSLeviathanTentacleL2Missile = 1043,
-	SSLeviathanTentacleR1Missile = 1044,
-	SSLeviathanTentacleR2Missile = 1045,
-	SSLeviathanTentacleL1Missile = 1046,
-	SSTerraTronMissile = 1047,
-	SSWraithMissile = 1048,
-	SSScourgeMissile = 1049,
-	SSCorruptorMissile = 1050,
-	SSSwarmGuardianMissile = 1051,
-	SSStrongMissile1 = 1052,
-	SSStrongMissile2 = 1053,
-	SSFighterDroneMissile = 1054,
-	SSPhoenixMissile = 1055,
-	SSScoutMissile = 1056,
-	SSInterceptorMissile = 1057,
-	SSScienceVesselMissile = 1058,
-	SSBattlecruiserMissile = 1059,
-	D8ClusterBombWeapon = 1060,
-	D8ClusterBomb = 1061,
-	BroodLordEgg = 1062,
-	BroodLordEggMissile = 1063,
-	CivilianWeapon = 1064,
-	BattlecruiserHeliosALMWeapon = 1065,
-	BattlecruiserLokiLMWeapon = 1066,
-	BattlecruiserHeliosGLMWeapon = 1067,
-	BioStasisMissile = 1068,
-	InfestedVentBroodLordEgg = 1069,
-	InfestedVentCorruptorEgg = 1070,
-	TentacleAMissile = 1071,
-	TentacleBMissile = 1072,
-	TentacleCMissile = 1073,
-	TentacleDMissile = 1074,
-	MutaliskEgg = 1075,
-	InfestedVentMutaliskEgg = 1076,
-	MutaliskEggMissile = 1077,
-	InfestedVentEggMissile = 1078,
-	SporeCannonFireMissile = 1079,
-	ExperimentalPlasmaGunWeapon = 1080,
-	BrutaliskWeapon = 1081,
-	LokiHurricaneMissileLeft = 1082,
-	LokiHurricaneMissileRight = 1083,
-	OdinAAWeapon = 1084,
-	DuskWingWeapon = 1085,
-	KerriganWeapon = 1086,
-	UltrasonicPulseWeapon = 1087,
-	KerriganCharWeapon = 1088,
-	DevastatorMissileWeapon = 1089,
-	SwannWeapon = 1090,
-	HammerSecurityLMWeapon = 1091,
-	ConsumeDNAFeedbackWeapon = 1092,
-	UrunWeaponLeft = 1093,
-	UrunWeaponRight = 1094,
-	HailstormMissilesWeapon = 1095,
-	ColonyInfestationWeapon = 1096,
-	VoidSeekerPhaseMineBlastWeapon = 1097,
-	VoidSeekerPhaseMineBlastSecondaryWeapon = 1098,
-	TossGrenadeWeapon = 1099,
-	TychusGrenadeWeapon = 1100,
-	VileStreamWeapon = 1101,
-	WraithAirWeaponRight = 1102,
-	WraithAirWeaponLeft = 1103,
-	WraithGroundWeapon = 1104,
-	WeaponHybridD = 1105,
-	KarassWeapon = 1106,
-	HybridCPlasmaWeapon = 1107,
-	WarbotBMissile = 1108,
-	LokiYamatoWeapon = 1109,
-	HyperionYamatoSpecialWeapon = 1110,
-	HyperionLMWeapon = 1111,
-	HyperionALMWeapon = 1112,
-	VultureWeapon = 1113,
-	ScoutAirWeaponLeft = 1114,
-	ScoutAirWeaponRight = 1115,
-	HunterKillerWeapon = 1116,
-	GoliathAWeapon = 1117,
-	SpartanCompanyAWeapon = 1118,
-	LeviathanScourgeMissile = 1119,
-	BioPlasmidDischargeWeapon = 1120,
-	VoidSeekerWeapon = 1121,
-	HelsAngelFighterWeapon = 1122,
-	DRBattlecruiserALMWeapon = 1123,
-	DRBattlecruiserGLMWeapon = 1124,
-	HurricaneMissileRight = 1125,
-	HurricaneMissileLeft = 1126,
-	HybridSingularityFeedbackWeapon = 1127,
-	DominionKillTeamLMWeapon = 1128,
-	ItemGrenadesWeapon = 1129,
-	ItemGravityBombsWeapon = 1130,
-	TestHeroThrowMissile = 1131,
-	TestHeroDebugMissileAbility1Weapon = 1132,
-	TestHeroDebugMissileAbility2Weapon = 1133,
-	Spectre = 1134,
-	Vulture = 1135,
-	Loki = 1136,
-	Wraith = 1137,
-	DominionKillTeam = 1138,
-	Firebat = 1139,
-	Diamondback = 1140,
-	G4ChargeWeapon = 1141,
-	SSBlackEdgeBorder = 1142,
-	DevourerTissueSampleTube = 1143,
-	Monolith = 1144,
-	Obelisk = 1145,
-	Archive = 1146,
-	ArtifactVault = 1147,
-	AvernusGateControl = 1148,
-	GateControlUnit = 1149,
-	BlimpAds = 1150,
-	Blocker6x6 = 1151,
-	Blocker8x8 = 1152,
-	Blocker16x16 = 1153,
-	CargoTruckUnitFlatbed = 1154,
-	CargoTruckUnitTrailer = 1155,
-	Blimp = 1156,
-	CastanarWindowLargeDiagonalULBRUnit = 1157,
-	Blocker4x4 = 1158,
-	HomeLarge = 1159,
-	HomeSmall = 1160,
-	ElevatorBlocker = 1161,
-	QuestionMark = 1162,
-	NydusWormLavaDeath = 1163,
-	SSBackgroundSpaceLarge = 1164,
-	SSBackgroundSpaceTerran00 = 1165,
-	SSBackgroundSpaceTerran02 = 1166,
-	SSBackgroundSpaceZerg00 = 1167,
-	SSBackgroundSpaceZerg02 = 1168,
-	SSCarrierBoss = 1169,
-	SSBattlecruiser = 1170,
-	SSTerraTronMissileSpinnerLauncher = 1171,
-	SSTerraTronMissileSpinner = 1172,
-	SSTerraTronBeamTarget = 1173,
-	SSLightningProjectorFaceRight = 1174,
-	SSScourge = 1175,
-	SSCorruptor = 1176,
-	SSTerraTronMissileLauncher = 1177,
-	SSLightningProjectorFaceLeft = 1178,
-	SSWraith = 1179,
-	SSSwarmGuardian = 1180,
-	SSScout = 1181,
-	SSLeviathan = 1182,
-	SSScienceVessel = 1183,
-	SSTerraTron = 1184,
-	SecretDocuments = 1185,
-	Predator = 1186,
-	DefilerBoneSample = 1187,
-	DevourerTissueSample = 1188,
-	ProtossPsiElements = 1189,
-	Tassadar = 1190,
-	ScienceFacility = 1191,
-	InfestedCocoon = 1192,
-	FusionReactor = 1193,
-	BubbaCommercial = 1194,
-	XelNagaPrisonHeight2 = 1195,
-	XelNagaPrison = 1196,
-	XelNagaPrisonNorth = 1197,
-	XelNagaPrisonNorthHeight2 = 1198,
-	ZergDropPodCreep = 1199,
-	IPistolAd = 1200,
-	L800ETCAd = 1201,
-	NukeNoodlesCommercial = 1202,
-	PsiOpsCommercial = 1203,
-	ShipAlarm = 1204,
-	SpacePlatformDestructibleJumboBlocker = 1205,
-	SpacePlatformDestructibleLargeBlocker = 1206,
-	SpacePlatformDestructibleMediumBlocker = 1207,
-	SpacePlatformDestructibleSmallBlocker = 1208,
-	TalDarimMothership = 1209,
-	PlasmaTorpedoesWeapon = 1210,
-	PsiDisruptor = 1211,
-	HiveMindEmulator = 1212,
-	Raynor01 = 1213,
-	ScienceVessel = 1214,
-	Scourge = 1215,
-	SpacePlatformReactorPathingBlocker = 1216,
-	TaurenOuthouse = 1217,
-	TychusEjectMissile = 1218,
-	Feederling = 1219,
-	UlaanSmokeBridge = 1220,
-	TalDarimPrisonCrystal = 1221,
-	SpaceDiablo = 1222,
-	MurlocMarine = 1223,
-	XelNagaPrisonConsole = 1224,
-	TalDarimPrison = 1225,
-	AdjutantCapsule = 1226,
-	XelNagaVault = 1227,
-	HoldingPen = 1228,
-	ScrapHuge = 1229,
-	PrisonerCivilian = 1230,
-	BiodomeHalfBuilt = 1231,
-	Biodome = 1232,
-	DestructibleKorhalFlag = 1233,
-	DestructibleKorhalPodium = 1234,
-	DestructibleKorhalTree = 1235,
-	DestructibleKorhalFoliage = 1236,
-	DestructibleSandbags = 1237,
-	CastanarWindowLargeDiagonalBLURUnit = 1238,
-	CargoTruckUnitBarrels = 1239,
-	SporeCannon = 1240,
-	Stetmann = 1241,
-	BridgeBlocker4x12 = 1242,
-	CivilianShipWrecked = 1243,
-	Swann = 1244,
-	DrakkenLaserDrill = 1245,
-	MindSiphonReturnWeapon = 1246,
-	KerriganEgg = 1247,
-	ChrysalisEgg = 1248,
-	PrisonerSpectre = 1249,
-	PrisonZealot = 1250,
-	ScrapSalvage1x1 = 1251,
-	ScrapSalvage2x2 = 1252,
-	ScrapSalvage3x3 = 1253,
-	RaynorCommando = 1254,
-	Overmind = 1255,
-	OvermindRemains = 1256,
-	InfestedMercHaven = 1257,
-	MonlythArtifactForceField = 1258,
-	MonlythForceFieldStatue = 1259,
-	Virophage = 1260,
-	PsiShockWeapon = 1261,
-	TychusCommando = 1262,
-	Brutalisk = 1263,
-	Pygalisk = 1264,
-	ValhallaBaseDestructibleDoorDead = 1265,
-	ValhallaBaseDestructibleDoor = 1266,
-	VoidSeeker = 1267,
-	MindSiphonWeapon = 1268,
-	Warbot = 1269,
-	PlatformConnector = 1270,
-	Artanis = 1271,
-	TerrazineCanister = 1272,
-	Hercules = 1273,
-	MercenaryFortress = 1274,
-	Raynor = 1275,
-	ArtifactPiece1 = 1276,
-	ArtifactPiece2 = 1277,
-	ArtifactPiece4 = 1278,
-	ArtifactPiece3 = 1279,
-	ArtifactPiece5 = 1280,
-	RipFieldGenerator = 1281,
-	RipFieldGeneratorSmall = 1282,
-	XelNagaWorldshipVault = 1283,
-	TychusChaingun = 1284,
-	Artifact = 1285,
-	CellBlockB = 1286,
-	GhostLaserLines = 1287,
-	MainCellBlock = 1288,
-	Kerrigan = 1289,
-	DataCore = 1290,
-	SpecialOpsDropship = 1291,
-	Tosh = 1292,
-	CastanarUltraliskShackledUnit = 1293,
-	Karass = 1294,
-	InvisiblePylon = 1295,
-	Maar = 1296,
-	HybridDestroyer = 1297,
-	HybridReaver = 1298,
-	Hybrid = 1299,
-	TerrazineNode = 1300,
-	TransportTruck = 1301,
-	WallOfFire = 1302,
-	WeaponHybridC = 1303,
-	XelNagaTemple = 1304,
-	ExplodingBarrelLarge = 1305,
-	SuperWarpGate = 1306,
-	TerrazineTank = 1307,
-	XelNagaShrine = 1308,
-	SMCameraBridge = 1309,
-	SMMarSaraBarTychusCameras = 1310,
-	SMHyperionBridgeStage1HansonCameras = 1311,
-	SMHyperionBridgeStage1HornerCameras = 1312,
-	SMHyperionBridgeStage1TychusCameras = 1313,
-	SMHyperionBridgeStage1ToshCameras = 1314,
-	SMHyperionArmoryStage1SwannCameras = 1315,
-	SMHyperionCantinaToshCameras = 1316,
-	SMHyperionCantinaTychusCameras = 1317,
-	SMHyperionCantinaYbarraCameras = 1318,
-	SMHyperionLabAdjutantCameras = 1319,
-	SMHyperionLabCowinCameras = 1320,
-	SMHyperionLabHansonCameras = 1321,
-	SMHyperionBridgeTRaynor03BriefingCamera = 1322,
-	SMTestCamera = 1323,
-	SMCameraTerran01 = 1324,
-	SMCameraTerran02A = 1325,
-	SMCameraTerran02B = 1326,
-	SMCameraTerran03 = 1327,
-	SMCameraTerran04 = 1328,
-	SMCameraTerran04A = 1329,
-	SMCameraTerran04B = 1330,
-	SMCameraTerran05 = 1331,
-	SMCameraTerran06A = 1332,
-	SMCameraTerran06B = 1333,
-	SMCameraTerran06C = 1334,
-	SMCameraTerran07 = 1335,
-	SMCameraTerran08 = 1336,
-	SMCameraTerran09 = 1337,
-	SMCameraTerran10 = 1338,
-	SMCameraTerran11 = 1339,
-	SMCameraTerran12 = 1340,
-	SMCameraTerran13 = 1341,
-	SMCameraTerran14 = 1342,
-	SMCameraTerran15 = 1343,
-	SMCameraTerran16 = 1344,
-	SMCameraTerran17 = 1345,
-	SMCameraTerran20 = 1346,
-	SMFirstOfficer = 1347,
-	SMHyperionBridgeBriefingLeft = 1348,
-	SMHyperionBridgeBriefingRight = 1349,
-	SMHyperionMedLabBriefing = 1350,
-	SMHyperionMedLabBriefingCenter = 1351,
-	SMHyperionMedLabBriefingLeft = 1352,
-	SMHyperionMedLabBriefingRight = 1353,
-	SMToshShuttleSet = 1354,
-	SMKerriganPhoto = 1355,
-	SMToshShuttleSet2 = 1356,
-	SMMarSaraBarJukeboxHS = 1357,
-	SMMarSaraBarKerriganPhotoHS = 1358,
-	SMValerianFlagshipCorridorsSet = 1359,
-	SMValerianFlagshipCorridorsSet2 = 1360,
-	SMValerianFlagshipCorridorsSet3 = 1361,
-	SMValerianFlagshipCorridorsSet4 = 1362,
-	SMValerianObservatorySet = 1363,
-	SMValerianObservatorySet2 = 1364,
-	SMValerianObservatorySet3 = 1365,
-	SMValerianObservatoryPaintingHS = 1366,
-	SMCharBattlezoneFlag = 1367,
-	SMUNNSet = 1368,
-	SMTerranReadyRoomSet = 1369,
-	SMCharBattlezoneSet = 1370,
-	SMCharBattlezoneSet2 = 1371,
-	SMCharBattlezoneSet3 = 1372,
-	SMCharBattlezoneSet4 = 1373,
-	SMCharBattlezoneSet5 = 1374,
-	SMCharBattlezoneArtifactHS = 1375,
-	SMCharBattlezoneRadioHS = 1376,
-	SMCharBattlezoneDropshipHS = 1377,
-	SMCharBattlezoneBriefcaseHS = 1378,
-	SMCharBattlezoneBriefingSet = 1379,
-	SMCharBattlezoneBriefingSet2 = 1380,
-	SMCharBattlezoneBriefingSetLeft = 1381,
-	SMCharBattlezoneBriefingSetRight = 1382,
-	SMMarSaraBarBadgeHS = 1383,
-	SMHyperionCantinaBadgeHS = 1384,
-	SMHyperionCantinaPoster1HS = 1385,
-	SMHyperionCantinaPoster2HS = 1386,
-	SMHyperionCantinaPoster3HS = 1387,
-	SMHyperionCantinaPoster4HS = 1388,
-	SMHyperionCantinaPoster5HS = 1389,
-	SMFly = 1390,
-	SMBridgeWindowSpace = 1391,
-	SMBridgePlanetSpace = 1392,
-	SMBridgePlanetSpaceAsteroids = 1393,
-	SMBridgePlanetAgria = 1394,
-	SMBridgePlanetAiur = 1395,
-	SMBridgePlanetAvernus = 1396,
-	SMBridgePlanetBelShir = 1397,
-	SMBridgePlanetCastanar = 1398,
-	SMBridgePlanetChar = 1399,
-	SMBridgePlanetHaven = 1400,
-	SMBridgePlanetKorhal = 1401,
-	SMBridgePlanetMeinhoff = 1402,
-	SMBridgePlanetMonlyth = 1403,
-	SMBridgePlanetNewFolsom = 1404,
-	SMBridgePlanetPortZion = 1405,
-	SMBridgePlanetRedstone = 1406,
-	SMBridgePlanetShakuras = 1407,
-	SMBridgePlanetTarsonis = 1408,
-	SMBridgePlanetTyphon = 1409,
-	SMBridgePlanetTyrador = 1410,
-	SMBridgePlanetUlaan = 1411,
-	SMBridgePlanetUlnar = 1412,
-	SMBridgePlanetValhalla = 1413,
-	SMBridgePlanetXil = 1414,
-	SMBridgePlanetZhakulDas = 1415,
-	SMMarSaraPlanet = 1416,
-	SMNova = 1417,
-	SMHavenPlanet = 1418,
-	SMHyperionBridgeBriefing = 1419,
-	SMHyperionBridgeBriefingCenter = 1420,
-	SMCharBattlefieldEndProps = 1421,
-	SMCharBattlezoneTurret = 1422,
-	SMTerran01FX = 1423,
-	SMTerran03FX = 1424,
-	SMTerran05FX = 1425,
-	SMTerran05FXMutalisks = 1426,
-	SMTerran05Props = 1427,
-	SMTerran06aFX = 1428,
-	SMTerran06bFX = 1429,
-	SMTerran06cFX = 1430,
-	SMTerran12FX = 1431,
-	SMTerran14FX = 1432,
-	SMTerran15FX = 1433,
-	SMTerran06AProps = 1434,
-	SMTerran06bProps = 1435,
-	SMTerran07Props = 1436,
-	SMTerran07FX = 1437,
-	SMTerran08Props = 1438,
-	SMTerran09FX = 1439,
-	SMTerran09Props = 1440,
-	SMTerran11FX = 1441,
-	SMTerran11FXMissiles = 1442,
-	SMTerran11FXExplosions = 1443,
-	SMTerran11FXBlood = 1444,
-	SMTerran11FXDebris = 1445,
-	SMTerran11FXDebris1 = 1446,
-	SMTerran11FXDebris2 = 1447,
-	SMTerran11Props = 1448,
-	SMTerran11PropsBurrowRocks = 1449,
-	SMTerran11PropsRifleShells = 1450,
-	SMTerran12Props = 1451,
-	SMTerran13Props = 1452,
-	SMTerran14Props = 1453,
-	SMTerran15Props = 1454,
-	SMTerran16FX = 1455,
-	SMTerran16FXFlak = 1456,
-	SMTerran17Props = 1457,
-	SMTerran17FX = 1458,
-	SMMarSaraBarProps = 1459,
-	SMHyperionCorridorProps = 1460,
-	ZeratulCrystalCharge = 1461,
-	SMRaynorHands = 1462,
-	SMPressRoomProps = 1463,
-	SMRaynorGun = 1464,
-	SMMarineRifle = 1465,
-	SMToshKnife = 1466,
-	SMToshShuttleProps = 1467,
-	SMHyperionExterior = 1468,
-	SMHyperionExteriorLow = 1469,
-	SMHyperionExteriorHologram = 1470,
-	SMCharCutScenes00 = 1471,
-	SMCharCutScenes01 = 1472,
-	SMCharCutScenes02 = 1473,
-	SMCharCutScenes03 = 1474,
-	SMMarSaraBarBriefingSet = 1475,
-	SMMarSaraBarBriefingSet2 = 1476,
-	SMMarSaraBarBriefingSetLeft = 1477,
-	SMMarSaraBarBriefingSetRight = 1478,
-	SMMarSaraBarBriefingTVMain = 1479,
-	SMMarSaraBarBriefingTVMain2 = 1480,
-	SMMarSaraBarBriefingTVMain3 = 1481,
-	SMMarSaraBarBriefingTVPortrait1 = 1482,
-	SMMarSaraBarBriefingTVPortrait2 = 1483,
-	SMMarSaraBarBriefingTVPortrait3 = 1484,
-	SMMarSaraBarBriefingTVPortrait4 = 1485,
-	SMMarSaraBarBriefingTVPortrait5 = 1486,
-	SMMarSaraBarSet = 1487,
-	SMMarSaraBarSet2 = 1488,
-	SMMarSaraBarStarmapHS = 1489,
-	SMMarSaraBarTVHS = 1490,
-	SMMarSaraBarHydraliskSkullHS = 1491,
-	SMMarSaraBarCorkboardHS = 1492,
-	SMMarSaraBarCorkboardBackground = 1493,
-	SMMarSaraBarCorkboardItem1HS = 1494,
-	SMMarSaraBarCorkboardItem2HS = 1495,
-	SMMarSaraBarCorkboardItem3HS = 1496,
-	SMMarSaraBarCorkboardItem4HS = 1497,
-	SMMarSaraBarCorkboardItem5HS = 1498,
-	SMMarSaraBarCorkboardItem6HS = 1499,
-	SMMarSaraBarCorkboardItem7HS = 1500,
-	SMMarSaraBarCorkboardItem8HS = 1501,
-	SMMarSaraBarCorkboardItem9HS = 1502,
-	SMMarSaraBarBottlesHS = 1503,
-	SMValerianObservatoryProps = 1504,
-	SMValerianObservatoryStarmap = 1505,
-	SMBanshee = 1506,
-	SMViking = 1507,
-	SMArmoryBanshee = 1508,
-	SMArmoryDropship = 1509,
-	SMArmoryTank = 1510,
-	SMArmoryViking = 1511,
-	SMArmorySpiderMine = 1512,
-	SMArmoryGhostCrate = 1513,
-	SMArmorySpectreCrate = 1514,
-	SMArmoryBansheePHCrate = 1515,
-	SMArmoryDropshipPHCrate = 1516,
-	SMArmoryTankPHCrate = 1517,
-	SMArmoryVikingPHCrate = 1518,
-	SMArmorySpiderMinePHCrate = 1519,
-	SMArmoryGhostCratePHCrate = 1520,
-	SMArmorySpectreCratePHCrate = 1521,
-	SMArmoryRifle = 1522,
-	SMDropship = 1523,
-	SMDropshipBlue = 1524,
-	SMHyperionArmoryViking = 1525,
-	SMCharGatlingGun = 1526,
-	SMBountyHunter = 1527,
-	SMCivilian = 1528,
-	SMZergedHanson = 1529,
-	SMLabAssistant = 1530,
-	SMHyperionArmorer = 1531,
-	SMUNNScreen = 1532,
-	NewsArcturusInterviewSet = 1533,
-	NewsArcturusPressRoom = 1534,
-	SMDonnyVermillionSet = 1535,
-	NewsMeinhoffRefugeeCenter = 1536,
-	NewsRaynorLogo = 1537,
-	NewsTVEffect = 1538,
-	SMUNNCamera = 1539,
-	SMLeeKenoSet = 1540,
-	SMTVStatic = 1541,
-	SMDonnyVermillion = 1542,
-	SMDonnyVermillionDeath = 1543,
-	SMLeeKeno = 1544,
-	SMKateLockwell = 1545,
-	SMMikeLiberty = 1546,
-	SMTerranReadyRoomLeftTV = 1547,
-	SMTerranReadyRoomMainTV = 1548,
-	SMTerranReadyRoomRightTV = 1549,
-	SMHyperionArmoryStage1Set = 1550,
-	SMHyperionArmoryStage1Set01 = 1551,
-	SMHyperionArmoryStage1Set02 = 1552,
-	SMHyperionArmoryStage1Set03 = 1553,
-	SMHyperionArmorySpaceLighting = 1554,
-	SMHyperionArmoryStage1TechnologyConsoleHS = 1555,
-	SMHyperionBridgeStage1Bow = 1556,
-	SMHyperionBridgeStage1Set = 1557,
-	SMHyperionBridgeStage1Set2 = 1558,
-	SMHyperionBridgeStage1Set3 = 1559,
-	SMHyperionBridgeHolomap = 1560,
-	SMHyperionCantinaStage1Set = 1561,
-	SMHyperionCantinaStage1Set2 = 1562,
-	SMHyperionCantinaStage1WallPiece = 1563,
-	SMHyperionBridgeProps = 1564,
-	SMHyperionCantinaProps = 1565,
-	SMHyperionMedLabProps = 1566,
-	SMHyperionMedLabProtossCryotube0HS = 1567,
-	SMHyperionMedLabProtossCryotube1HS = 1568,
-	SMHyperionMedLabProtossCryotube2HS = 1569,
-	SMHyperionMedLabProtossCryotube3HS = 1570,
-	SMHyperionMedLabProtossCryotube4HS = 1571,
-	SMHyperionMedLabProtossCryotube5HS = 1572,
-	SMHyperionMedLabZergCryotube0HS = 1573,
-	SMHyperionMedLabZergCryotube1HS = 1574,
-	SMHyperionMedLabZergCryotube2HS = 1575,
-	SMHyperionMedLabZergCryotube3HS = 1576,
-	SMHyperionMedLabZergCryotube4HS = 1577,
-	SMHyperionMedLabZergCryotube5HS = 1578,
-	SMHyperionMedLabCryotubeA = 1579,
-	SMHyperionMedLabCryotubeB = 1580,
-	SMHyperionCantinaStage1ExitHS = 1581,
-	SMHyperionCantinaStage1StaircaseHS = 1582,
-	SMHyperionCantinaStage1TVHS = 1583,
-	SMHyperionCantinaStage1ArcadeGameHS = 1584,
-	SMHyperionCantinaStage1JukeboxHS = 1585,
-	SMHyperionCantinaStage1CorkboardHS = 1586,
-	SMHyperionCantinaProgressFrame = 1587,
-	SMHyperionCantinaHydraClawsHS = 1588,
-	SMHyperionCantinaMercComputerHS = 1589,
-	SMHyperionCantinaStage1Progress1HS = 1590,
-	SMHyperionCantinaStage1Progress2HS = 1591,
-	SMHyperionCantinaStage1Progress3HS = 1592,
-	SMHyperionCantinaStage1Progress4HS = 1593,
-	SMHyperionCantinaStage1Progress5HS = 1594,
-	SMHyperionCantinaStage1Progress6HS = 1595,
-	SMHyperionCorridorSet = 1596,
-	SMHyperionBridgeStage1BattleReportsHS = 1597,
-	SMHyperionBridgeStage1CenterConsoleHS = 1598,
-	SMHyperionBridgeStage1BattleCommandHS = 1599,
-	SMHyperionBridgeStage1CantinaHS = 1600,
-	SMHyperionBridgeStage1WindowHS = 1601,
-	SMHyperionMedLabStage1Set = 1602,
-	SMHyperionMedLabStage1Set2 = 1603,
-	SMHyperionMedLabStage1SetLights = 1604,
-	SMHyperionMedLabStage1ConsoleHS = 1605,
-	SMHyperionMedLabStage1DoorHS = 1606,
-	SMHyperionMedLabStage1CrystalHS = 1607,
-	SMHyperionMedLabStage1ArtifactHS = 1608,
-	SMHyperionLabArtifactPart1HS = 1609,
-	SMHyperionLabArtifactPart2HS = 1610,
-	SMHyperionLabArtifactPart3HS = 1611,
-	SMHyperionLabArtifactPart4HS = 1612,
-	SMHyperionLabArtifactBaseHS = 1613,
-	SMShadowbox = 1614,
-	SMCharBattlezoneShadowbox = 1615,
-	SMCharInteractiveSkyparallax = 1616,
-	SMCharInteractive02Skyparallax = 1617,
-	SMRaynorCommander = 1618,
-	SMAdjutant = 1619,
-	SMAdjutantHologram = 1620,
-	SMMarauder = 1621,
-	SMFirebat = 1622,
-	SMMarauderPHCrate = 1623,
-	SMFirebatPHCrate = 1624,
-	SMRaynorMarine = 1625,
-	SMMarine01 = 1626,
-	SMMarine02 = 1627,
-	SMMarine02AOD = 1628,
-	SMMarine03 = 1629,
-	SMMarine04 = 1630,
-	SMCade = 1631,
-	SMHall = 1632,
-	SMBralik = 1633,
-	SMAnnabelle = 1634,
-	SMEarl = 1635,
-	SMKachinsky = 1636,
-	SMGenericMaleGreaseMonkey01 = 1637,
-	SMGenericMaleGreaseMonkey02 = 1638,
-	SMGenericMaleOfficer01 = 1639,
-	SMGenericMaleOfficer02 = 1640,
-	SMStetmann = 1641,
-	SMCooper = 1642,
-	SMHill = 1643,
-	SMYbarra = 1644,
-	SMValerianMengsk = 1645,
-	SMArcturusMengsk = 1646,
-	SMArcturusHologram = 1647,
-	SMZeratul = 1648,
-	SMHydralisk = 1649,
-	SMHydraliskDead = 1650,
-	SMMutalisk = 1651,
-	SMZergling = 1652,
-	Scientist = 1653,
-	MinerMale = 1654,
-	Civilian = 1655,
-	Colonist = 1656,
-	CivilianFemale = 1657,
-	ColonistFemale = 1658,
-	Hut = 1659,
-	ColonistHut = 1660,
-	InfestableHut = 1661,
-	InfestableColonistHut = 1662,
-	XelNagaShrineXil = 1663,
-	ProtossRelic = 1664,
-	PickupGrenades = 1665,
-	PickupPlasmaGun = 1666,
-	PickupPlasmaRounds = 1667,
-	PickupMedicRecharge = 1668,
-	PickupManaRecharge = 1669,
-	PickupRestorationCharge = 1670,
-	PickupChronoRiftDevice = 1671,
-	PickupChronoRiftCharge = 1672,
-	GasCanister = 1673,
-	GasCanisterProtoss = 1674,
-	GasCanisterZerg = 1675,
-	MineralCrystal = 1676,
-	PalletGas = 1677,
-	PalletMinerals = 1678,
-	NaturalGas = 1679,
-	NaturalMinerals = 1680,
-	NaturalMineralsRed = 1681,
-	PickupHealth25 = 1682,
-	PickupHealth50 = 1683,
-	PickupHealth100 = 1684,
-	PickupHealthFull = 1685,
-	PickupEnergy25 = 1686,
-	PickupEnergy50 = 1687,
-	PickupEnergy100 = 1688,
-	PickupEnergyFull = 1689,
-	PickupMines = 1690,
-	PickupPsiStorm = 1691,
-	CivilianCarsUnit = 1692,
-	CruiserBike = 1693,
-	TerranBuggy = 1694,
-	ColonistVehicleUnit = 1695,
-	ColonistVehicleUnit01 = 1696,
-	DumpTruck = 1697,
-	TankerTruck = 1698,
-	FlatbedTruck = 1699,
-	ColonistShipTHanson02A = 1700,
-	Purifier = 1701,
-	InfestedArmory = 1702,
-	InfestedBarracks = 1703,
-	InfestedBunker = 1704,
-	InfestedCC = 1705,
-	InfestedEngBay = 1706,
-	InfestedFactory = 1707,
-	InfestedRefinery = 1708,
-	InfestedStarport = 1709,
-	InfestedMissileTurret = 1710,
-	LogisticsHeadquarters = 1711,
-	InfestedSupply = 1712,
-	TarsonisEngine = 1713,
-	TarsonisEngineFast = 1714,
-	FreightCar = 1715,
-	Caboose = 1716,
-	Hyperion = 1717,
-	MengskHologramBillboard = 1718,
-	TRaynor01SignsDestructible1 = 1719,
-	AbandonedBuilding = 1720,
-	Nova = 1721,
-	Food1000 = 1722,
-	PsiIndoctrinator = 1723,
-	JoriumStockpile = 1724,
-	ZergDropPod = 1725,
-	TerranDropPod = 1726,
-	ColonistBiodome = 1727,
-	ColonistBiodomeHalfBuilt = 1728,
-	InfestableBiodome = 1729,
-	InfestableColonistBiodome = 1730,
-	Medic = 1731,
-	VikingSkyUnit = 1732,
-	SSFighter = 1733,
-	SSPhoenix = 1734,
-	SSCarrier = 1735,
-	SSBackgroundZerg01 = 1736,
-	SSBackgroundSpace00 = 1737,
-	SSBackgroundSpace01 = 1738,
-	SSBackgroundSpace02 = 1739,
-	SSBackgroundSpaceProt00 = 1740,
-	SSBackgroundSpaceProt01 = 1741,
-	SSBackgroundSpaceProt02 = 1742,
-	SSBackgroundSpaceProt03 = 1743,
-	SSBackgroundSpaceProt04 = 1744,
-	SSBackgroundSpaceProtossLarge = 1745,
-	SSBackgroundSpaceZergLarge = 1746,
-	SSBackgroundSpaceTerranLarge = 1747,
-	SSBackgroundSpaceZerg01 = 1748,
-	SSBackgroundSpaceTerran01 = 1749,
-	BreachingCharge = 1750,
-	InfestationSpire = 1751,
-	SpacePlatformVentsUnit = 1752,
-	StoneZealot = 1753,
-	PreserverPrison = 1754,
-	PortJunker = 1755,
-	Leviathan = 1756,
-	Swarmling = 1757,
-	ValhallaDestructibleWall = 1758,
-	NewFolsomPrisonEntrance = 1759,
-	OdinBuild = 1760,
-	NukePack = 1761,
-	CharDestructibleRockCover = 1762,
-	CharDestructibleRockCoverV = 1763,
-	CharDestructibleRockCoverULDR = 1764,
-	CharDestructibleRockCoverURDL = 1765,
-	MaarWarpInUnit = 1766,
-	EggPurple = 1767,
-	TruckFlatbedUnit = 1768,
-	TruckSemiUnit = 1769,
-	TruckUtilityUnit = 1770,
-	InfestedColonistShip = 1771,
-	CastanarDestructibleDebris = 1772,
-	ColonistTransport = 1773,
-	PreserverBase = 1774,
-	PreserverA = 1775,
-	PreserverB = 1776,
-	PreserverC = 1777,
-	TaurenSpaceMarine = 1778,
-	MarSaraBridgeBLUR = 1779,
-	MarSaraBridgeBRUL = 1780,
-	ShortBridgeVertical = 1781,
-	ShortBridgeHorizontal = 1782,
-	TestHero = 1783,
-	TestShop = 1784,
-	HealingPotionTESTTarget = 1785,
-	_4SlotBag = 1786,
-	_6SlotBag = 1787,
-	_8SlotBag = 1788,
-	_10SlotBag = 1789,
-	_12SlotBag = 1790,
-	_14SlotBag = 1791,
-	_16SlotBag = 1792,
-	_18SlotBag = 1793,
-	_20SlotBag = 1794,
-	_22SlotBag = 1795,
-	_24SlotBag = 1796,
-	RepulserField6 = 1797,
-	RepulserField8 = 1798,
-	RepulserField10 = 1799,
-	RepulserField12 = 1800,
-	DestructibleWallCorner45ULBL = 1801,
-	DestructibleWallCorner45ULUR = 1802,
-	DestructibleWallCorner45URBR = 1803,
-	DestructibleWallCorner45 = 1804,
-	DestructibleWallCorner45UR90L = 1805,
-	DestructibleWallCorner45UL90B = 1806,
-	DestructibleWallCorner45BL90R = 1807,
-	DestructibleWallCorner45BR90T = 1808,
-	DestructibleWallCorner90L45BR = 1809,
-	DestructibleWallCorner90T45BL = 1810,
-	DestructibleWallCorner90R45UL = 1811,
-	DestructibleWallCorner90B45UR = 1812,
-	DestructibleWallCorner90TR = 1813,
-	DestructibleWallCorner90BR = 1814,
-	DestructibleWallCorner90LB = 1815,
-	DestructibleWallCorner90LT = 1816,
-	DestructibleWallDiagonalBLUR = 1817,
-	DestructibleWallDiagonalBLURLF = 1818,
-	DestructibleWallDiagonalULBRLF = 1819,
-	DestructibleWallDiagonalULBR = 1820,
-	DestructibleWallStraightVertical = 1821,
-	DestructibleWallVerticalLF = 1822,
-	DestructibleWallStraightHorizontal = 1823,
-	DestructibleWallStraightHorizontalBF = 1824,
-	DefenseWallE = 1825,
-	DefenseWallS = 1826,
-	DefenseWallW = 1827,
-	DefenseWallN = 1828,
-	DefenseWallNE = 1829,
-	DefenseWallSW = 1830,
-	DefenseWallNW = 1831,
-	DefenseWallSE = 1832,
-	WreckedBattlecruiserHeliosFinal = 1833,
-	FireworksBlue = 1834,
-	FireworksRed = 1835,
-	FireworksYellow = 1836,
-	PurifierBlastMarkUnit = 1837,
-	ItemGravityBombs = 1838,
-	ItemGrenades = 1839,
-	ItemMedkit = 1840,
-	ItemMines = 1841,
-	ReaperPlacement = 1842,
-	QueenZagaraACGluescreenDummy = 1843,
-	OverseerZagaraACGluescreenDummy = 1844,
-	StukovInfestedCivilianACGluescreenDummy = 1845,
-	StukovInfestedMarineACGluescreenDummy = 1846,
-	StukovInfestedSiegeTankACGluescreenDummy = 1847,
-	StukovInfestedDiamondbackACGluescreenDummy = 1848,
-	StukovInfestedBansheeACGluescreenDummy = 1849,
-	SILiberatorACGluescreenDummy = 1850,
-	StukovInfestedBunkerACGluescreenDummy = 1851,
-	StukovInfestedMissileTurretACGluescreenDummy = 1852,
-	StukovBroodQueenACGluescreenDummy = 1853,
-	ZealotFenixACGluescreenDummy = 1854,
-	SentryFenixACGluescreenDummy = 1855,
-	AdeptFenixACGluescreenDummy = 1856,
-	ImmortalFenixACGluescreenDummy = 1857,
-	ColossusFenixACGluescreenDummy = 1858,
-	DisruptorACGluescreenDummy = 1859,
-	ObserverFenixACGluescreenDummy = 1860,
-	ScoutACGluescreenDummy = 1861,
-	CarrierFenixACGluescreenDummy = 1862,
-	PhotonCannonFenixACGluescreenDummy = 1863,
-	PrimalZerglingACGluescreenDummy = 1864,
-	RavasaurACGluescreenDummy = 1865,
-	PrimalRoachACGluescreenDummy = 1866,
-	FireRoachACGluescreenDummy = 1867,
-	PrimalGuardianACGluescreenDummy = 1868,
-	PrimalHydraliskACGluescreenDummy = 1869,
-	PrimalMutaliskACGluescreenDummy = 1870,
-	PrimalImpalerACGluescreenDummy = 1871,
-	PrimalSwarmHostACGluescreenDummy = 1872,
-	CreeperHostACGluescreenDummy = 1873,
-	PrimalUltraliskACGluescreenDummy = 1874,
-	TyrannozorACGluescreenDummy = 1875,
-	PrimalWurmACGluescreenDummy = 1876,
-	HHReaperACGluescreenDummy = 1877,
-	HHWidowMineACGluescreenDummy = 1878,
-	HHHellionTankACGluescreenDummy = 1879,
-	HHWraithACGluescreenDummy = 1880,
-	HHVikingACGluescreenDummy = 1881,
-	HHBattlecruiserACGluescreenDummy = 1882,
-	HHRavenACGluescreenDummy = 1883,
-	HHBomberPlatformACGluescreenDummy = 1884,
-	HHMercStarportACGluescreenDummy = 1885,
-	HHMissileTurretACGluescreenDummy = 1886,
-	HighTemplarSkinPreview = 1887,
-	WarpPrismSkinPreview = 1888,
-	SiegeTankSkinPreview = 1889,
-	LiberatorSkinPreview = 1890,
-	VikingSkinPreview = 1891,
-	StukovInfestedTrooperACGluescreenDummy = 1892,
-	XelNagaDestructibleBlocker6S = 1893,
-	XelNagaDestructibleBlocker6SE = 1894,
-	XelNagaDestructibleBlocker6E = 1895,
-	XelNagaDestructibleBlocker6NE = 1896,
-	XelNagaDestructibleBlocker6N = 1897,
-	XelNagaDestructibleBlocker6NW = 1898,
-	XelNagaDestructibleBlocker6W = 1899,
-	XelNagaDestructibleBlocker6SW = 1900,
-	XelNagaDestructibleBlocker8S = 1901,
-	XelNagaDestructibleBlocker8SE = 1902,
-	XelNagaDestructibleBlocker8E = 1903,
-	XelNagaDestructibleBlocker8NE = 1904,
-	XelNagaDestructibleBlocker8N = 1905,
-	XelNagaDestructibleBlocker8NW = 1906,
-	XelNagaDestructibleBlocker8W = 1907,
-	XelNagaDestructibleBlocker8SW = 1908,
-	SnowGlazeStarterMP = 1909,
-	ShieldBattery = 1910,
-	ObserverSiegeMode = 1911,
-	OverseerSiegeMode = 1912,
-	RavenRepairDrone = 1913,
-	HighTemplarWeaponMissile = 1914,
-	CycloneMissileLargeAirAlternative = 1915,
-	RavenScramblerMissile = 1916,
-	RavenRepairDroneReleaseWeapon = 1917,
-	RavenShredderMissileWeapon = 1918,
-	InfestedAcidSpinesWeapon = 1919,
-	InfestorEnsnareAttackMissile = 1920,
-	SNAREPLACEHOLDER = 1921,
-	TychusReaperACGluescreenDummy = 1922,
-	TychusFirebatACGluescreenDummy = 1923,
-	TychusSpectreACGluescreenDummy = 1924,
-	TychusMedicACGluescreenDummy = 1925,
-	TychusMarauderACGluescreenDummy = 1926,
-	TychusWarhoundACGluescreenDummy = 1927,
-	TychusHERCACGluescreenDummy = 1928,
-	TychusGhostACGluescreenDummy = 1929,
-	TychusSCVAutoTurretACGluescreenDummy = 1930,
-	ZeratulStalkerACGluescreenDummy = 1931,
-	ZeratulSentryACGluescreenDummy = 1932,
-	ZeratulDarkTemplarACGluescreenDummy = 1933,
-	ZeratulImmortalACGluescreenDummy = 1934,
-	ZeratulObserverACGluescreenDummy = 1935,
-	ZeratulDisruptorACGluescreenDummy = 1936,
-	ZeratulWarpPrismACGluescreenDummy = 1937,
-	ZeratulPhotonCannonACGluescreenDummy = 1938,
-	RenegadeLongboltMissileWeapon = 1939,
-	Viking = 1940,
-	RenegadeMissileTurret = 1941,
-	ParasiticBombRelayDummy = 1942,
-	MechaZerglingACGluescreenDummy = 1943,
-	MechaBanelingACGluescreenDummy = 1944,
-	MechaHydraliskACGluescreenDummy = 1945,
-	MechaInfestorACGluescreenDummy = 1946,
-	MechaCorruptorACGluescreenDummy = 1947,
-	MechaUltraliskACGluescreenDummy = 1948,
-	MechaOverseerACGluescreenDummy = 1949,
-	MechaLurkerACGluescreenDummy = 1950,
-	MechaBattlecarrierLordACGluescreenDummy = 1951,
-	MechaSpineCrawlerACGluescreenDummy = 1952,
-	MechaSporeCrawlerACGluescreenDummy = 1953,
-	PreviewBunkerUpgraded = 1954,
-	AssimilatorRich = 1955,
-	ExtractorRich = 1956,
-	InhibitorZoneSmall = 1957,
-	InhibitorZoneMedium = 1958,
-	InhibitorZoneLarge = 1959,
-	RefineryRich = 1960,
-	MineralField450 = 1961,
-	MineralFieldOpaque = 1962,
-	MineralFieldOpaque900 = 1963,
-	CollapsibleRockTowerDebrisRampLeftGreen = 1964,
-	CollapsibleRockTowerDebrisRampRightGreen = 1965,
-	CollapsibleRockTowerPushUnitRampLeftGreen = 1966,
-	CollapsibleRockTowerPushUnitRampRightGreen = 1967,
-	CollapsibleRockTowerRampLeftGreen = 1968,
-	CollapsibleRockTowerRampRightGreen = 1969,
-}
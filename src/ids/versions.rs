@@ -0,0 +1,122 @@
+use super::{remaps::*, AbilityId, BuffId, EffectId, UnitTypeId, UpgradeId};
+use num_traits::{FromPrimitive, ToPrimitive};
+
+/// Version of game data, which determines raw ids of units, abilities and buffs.
+///
+/// Id enums always have values of the latest version,
+/// ids of older versions are converted when talking to SC2.
+/// Version is selected automatically on game start from the `base_build` of connected client
+/// and stored in [`data_version`](crate::bot::Bot::data_version) of the bot.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DataVersion {
+	/// Versions `4.10.x` (the latest available on Linux).
+	V4_10,
+	/// Versions `4.11` and newer.
+	Latest,
+}
+impl DataVersion {
+	/// Returns version of data, used by SC2 client with given base build.
+	pub fn from_base_build(base_build: u32) -> Self {
+		if base_build < 77379 {
+			Self::V4_10
+		} else {
+			Self::Latest
+		}
+	}
+}
+impl Default for DataVersion {
+	fn default() -> Self {
+		Self::Latest
+	}
+}
+
+macro_rules! impl_raw_ids {
+	($($id:ident: $from_4_10:ident, $to_4_10:ident),+ $(,)?) => {$(
+		impl $id {
+			/// Converts raw id of given [`DataVersion`],
+			/// returns `None` if id is unknown or doesn't exist in the latest version.
+			pub fn from_raw(raw: u32, version: DataVersion) -> Option<Self> {
+				match version {
+					DataVersion::V4_10 => $from_4_10(raw),
+					DataVersion::Latest => Self::from_u32(raw),
+				}
+			}
+			/// Converts id to raw id of given [`DataVersion`].
+			///
+			/// Ids which don't exist in given version are converted to `0` (null id), which SC2 rejects.
+			pub fn to_raw(self, version: DataVersion) -> u32 {
+				match version {
+					DataVersion::V4_10 => $to_4_10(self),
+					DataVersion::Latest => self.to_u32().unwrap(),
+				}
+			}
+		}
+	)+};
+}
+
+impl_raw_ids!(
+	UnitTypeId: unit_typeid_from_4_10, unit_typeid_to_4_10,
+	AbilityId: ability_id_from_4_10, ability_id_to_4_10,
+	UpgradeId: upgrade_id_from_4_10, upgrade_id_to_4_10,
+	BuffId: buff_id_from_4_10, buff_id_to_4_10,
+	EffectId: effect_id_from_4_10, effect_id_to_4_10,
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	macro_rules! check_round_trip {
+		($($id:ident),+ $(,)?) => {$(
+			for raw in 0..5000 {
+				for &version in &[DataVersion::V4_10, DataVersion::Latest] {
+					if let Some(id) = $id::from_raw(raw, version) {
+						assert_eq!(id.to_raw(version), raw, "{:?} in {:?}", id, version);
+					}
+				}
+			}
+		)+};
+	}
+
+	#[test]
+	fn converts_ids_back_and_forth() {
+		check_round_trip!(UnitTypeId, AbilityId, UpgradeId, BuffId, EffectId);
+	}
+
+	#[test]
+	fn remaps_ids_of_version() {
+		let id = UnitTypeId::MechaZerglingACGluescreenDummy;
+		assert_eq!(UnitTypeId::from_raw(1943, DataVersion::V4_10), Some(id));
+		assert_eq!(id.to_raw(DataVersion::V4_10), 1943);
+		assert_ne!(id.to_raw(DataVersion::Latest), 1943);
+		let id = BuffId::InhibitorZoneTemporalField;
+		assert_eq!(BuffId::from_raw(289, DataVersion::V4_10), Some(id));
+		assert_eq!(id.to_raw(DataVersion::V4_10), 289);
+	}
+
+	#[test]
+	fn rejects_ids_missing_in_version() {
+		// Raw id of unit added after 4.10
+		assert!(UnitTypeId::from_raw(1970, DataVersion::Latest).is_some());
+		assert_eq!(UnitTypeId::from_raw(1970, DataVersion::V4_10), None);
+		// Upgrade added after 4.10
+		assert_ne!(UpgradeId::EnhancedShockwaves.to_raw(DataVersion::Latest), 0);
+		assert_eq!(UpgradeId::EnhancedShockwaves.to_raw(DataVersion::V4_10), 0);
+	}
+
+	#[test]
+	fn keeps_ids_missing_in_remaps() {
+		for &version in &[DataVersion::V4_10, DataVersion::Latest] {
+			let raw = UnitTypeId::Marine.to_u32().unwrap();
+			assert_eq!(UnitTypeId::from_raw(raw, version), Some(UnitTypeId::Marine));
+			assert_eq!(UnitTypeId::Marine.to_raw(version), raw);
+		}
+	}
+
+	#[test]
+	fn selects_version_by_base_build() {
+		assert_eq!(DataVersion::from_base_build(75689), DataVersion::V4_10);
+		assert_eq!(DataVersion::from_base_build(77379), DataVersion::Latest);
+		assert_eq!(DataVersion::default(), DataVersion::Latest);
+	}
+}
@@ -0,0 +1,194 @@
+//! Influence maps, which show how dangerous every cell of the map is.
+//!
+//! # Usage
+//! ```
+//! let threats = ThreatMap::from_bot(self);
+//! if threats.ground_threat(unit.position) > 0.0 {
+//!     let grid = PathingGrid::from_bot(self);
+//!     if let Some(pos) = threats.safest_point(unit.position, 5.0, false, |cell| grid.is_pathable(cell)) {
+//!         unit.move_to(Target::Pos(pos), false);
+//!     }
+//! }
+//! ```
+
+use crate::{
+	bot::Bot,
+	distance::Distance,
+	geometry::Point2,
+	pathfinding::{cell_center, Path, PathingGrid},
+	unit::Unit,
+};
+use ndarray::Array2;
+
+/// Summed dps of enemy units, which can reach every cell of the map.
+/// Layers are indexed like [`pixel maps`](crate::pixel_map), by `(x, y)` or [`Point2`].
+#[derive(Default, Clone)]
+pub struct ThreatMap {
+	/// Dps against ground units.
+	pub ground: Array2<f32>,
+	/// Dps against air units.
+	pub air: Array2<f32>,
+	/// Number of enemy detectors, which can detect units in every cell.
+	pub detection: Array2<f32>,
+}
+impl ThreatMap {
+	/// Constructs map of given size without threats.
+	pub fn new(size: (usize, usize)) -> Self {
+		Self {
+			ground: Array2::zeros(size),
+			air: Array2::zeros(size),
+			detection: Array2::zeros(size),
+		}
+	}
+	/// Constructs map of the same size as pathing grid with threats of all visible enemies.
+	/// With `enemies_cache` feature enabled, cached enemies are used too.
+	pub fn from_bot(bot: &Bot) -> Self {
+		let mut map = Self::new(bot.game_info.pathing_grid.dim());
+		#[cfg(feature = "enemies_cache")]
+		let enemies = &bot.units.cached.all;
+		#[cfg(not(feature = "enemies_cache"))]
+		let enemies = &bot.units.enemy.all;
+		for u in enemies {
+			if u.is_ready() {
+				map.add_unit(u);
+			}
+		}
+		map
+	}
+
+	/// Adds threat of given unit using it's real ranges and dps, and it's detection range if it's detector.
+	///
+	/// Ranges are measured from the edge of unit, but radius of threatened unit isn't added,
+	/// so it's better to keep some distance from threats.
+	pub fn add_unit(&mut self, unit: &Unit) {
+		let ground_dps = unit.ground_dps();
+		if ground_dps > 0.0 {
+			self.add_ground(unit.position, unit.real_ground_range() + unit.radius, ground_dps);
+		}
+		let air_dps = unit.air_dps();
+		if air_dps > 0.0 {
+			self.add_air(unit.position, unit.real_air_range() + unit.radius, air_dps);
+		}
+		if unit.is_detector() {
+			self.add_detection(unit.position, unit.detect_range);
+		}
+	}
+	/// Adds threat of `enemy` against given `unit` to the layer of unit (air or ground),
+	/// using [`real_range_vs`] and [`dps_vs`] it, and detection range of `enemy` if it's detector.
+	///
+	/// Radius of `unit` and `margin` are added to all ranges.
+	///
+	/// [`real_range_vs`]: Unit::real_range_vs
+	/// [`dps_vs`]: Unit::dps_vs
+	pub fn add_unit_vs(&mut self, enemy: &Unit, unit: &Unit, margin: f32) {
+		let dps = enemy.dps_vs(unit);
+		if dps > 0.0 {
+			let range = enemy.real_range_vs(unit) + enemy.radius + unit.radius + margin;
+			let layer = if unit.is_flying {
+				&mut self.air
+			} else {
+				&mut self.ground
+			};
+			add_circle(layer, enemy.position, range, dps);
+		}
+		if enemy.is_detector() {
+			self.add_detection(enemy.position, enemy.detect_range + unit.radius + margin);
+		}
+	}
+	/// Adds `dps` to ground layer in cells with centers in `range` from `center`.
+	pub fn add_ground(&mut self, center: Point2, range: f32, dps: f32) {
+		add_circle(&mut self.ground, center, range, dps);
+	}
+	/// Adds `dps` to air layer in cells with centers in `range` from `center`.
+	pub fn add_air(&mut self, center: Point2, range: f32, dps: f32) {
+		add_circle(&mut self.air, center, range, dps);
+	}
+	/// Adds detector to detection layer in cells with centers in `range` from `center`.
+	pub fn add_detection(&mut self, center: Point2, range: f32) {
+		add_circle(&mut self.detection, center, range, 1.0);
+	}
+
+	/// Returns ground or air layer.
+	pub fn layer(&self, air: bool) -> &Array2<f32> {
+		if air {
+			&self.air
+		} else {
+			&self.ground
+		}
+	}
+	/// Returns dps against ground units in given point.
+	pub fn ground_threat(&self, pos: Point2) -> f32 {
+		threat(&self.ground, pos)
+	}
+	/// Returns dps against air units in given point.
+	pub fn air_threat(&self, pos: Point2) -> f32 {
+		threat(&self.air, pos)
+	}
+
+	/// Returns center of the least threatened cell in `distance` from `pos`,
+	/// if there're several such cells the closest is chosen.
+	///
+	/// `filter` is used to skip unreachable cells,
+	/// e.g. pass `|cell| grid.is_pathable(cell)` for ground units or `|_| true` for air.
+	pub fn safest_point<F>(&self, pos: Point2, distance: f32, air: bool, filter: F) -> Option<Point2>
+	where
+		F: Fn((usize, usize)) -> bool,
+	{
+		let layer = self.layer(air);
+		cells_in_range(layer.dim(), pos, distance)
+			.filter(|cell| filter(*cell))
+			.map(|cell| (cell, layer[cell], cell_center(cell).distance_squared(pos)))
+			.min_by(|(_, t1, d1), (_, t2, d2)| {
+				t1.partial_cmp(t2)
+					.unwrap()
+					.then_with(|| d1.partial_cmp(d2).unwrap())
+			})
+			.map(|(cell, _, _)| cell_center(cell))
+	}
+
+	/// Finds path for ground unit like [`PathingGrid::find_path`], but avoids threatened cells.
+	///
+	/// Entering a cell costs `weight * dps` in addition to it's length,
+	/// so greater `weight` makes longer but safer paths.
+	/// Cells outside of the map are considered not threatened.
+	pub fn find_path(
+		&self,
+		grid: &PathingGrid,
+		start: Point2,
+		goal: Point2,
+		radius: f32,
+		weight: f32,
+	) -> Option<Path> {
+		grid.find_path_with_cost(start, goal, radius, |cell| {
+			self.ground.get(cell).copied().unwrap_or(0.0) * weight
+		})
+	}
+}
+
+fn threat(layer: &Array2<f32>, pos: Point2) -> f32 {
+	if pos.x < 0.0 || pos.y < 0.0 {
+		return 0.0;
+	}
+	layer
+		.get((pos.x as usize, pos.y as usize))
+		.copied()
+		.unwrap_or(0.0)
+}
+
+pub(crate) fn cells_in_range(
+	(width, height): (usize, usize),
+	center: Point2,
+	range: f32,
+) -> impl Iterator<Item = (usize, usize)> {
+	let x0 = (center.x - range).floor().max(0.0) as usize;
+	let y0 = (center.y - range).floor().max(0.0) as usize;
+	let x1 = ((center.x + range).ceil().max(0.0) as usize).min(width);
+	let y1 = ((center.y + range).ceil().max(0.0) as usize).min(height);
+	iproduct!(x0..x1, y0..y1).filter(move |cell| cell_center(*cell).distance_squared(center) <= range * range)
+}
+
+pub(crate) fn add_circle(layer: &mut Array2<f32>, center: Point2, range: f32, value: f32) {
+	for cell in cells_in_range(layer.dim(), center, range) {
+		layer[cell] += value;
+	}
+}
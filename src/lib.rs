@@ -51,13 +51,16 @@ fn main() -> SC2Result<()> {
         &mut MyBot::default(),
         // Opponent configuration.
         Computer::new(Race::Random, Difficulty::VeryEasy, None),
-        // Map name. Panics if map doesn't exists in "StarCraft II/Maps" folder.
+        // Map name. Fails if map doesn't exists in "StarCraft II/Maps" folder or it's sub-folders.
         "EternalEmpireLE",
         // Additional settings:
         // LaunchOptions {
         //     sc2_version: Option<&str>, // Default: None - Latest available patch.
         //     save_replay_as: Option<&str>, // Default: None - Doesn't save replay.
         //     realtime: bool, // Default: false
+        //     record_as: Option<&str>, // Default: None - Doesn't record API protocol stream.
+        //     launcher: Launcher, // Default: Launches SC2 executable directly (see `Launcher::wine`).
+        //     limits: GameLimits, // Default: No limits of game length.
         // }
         LaunchOptions::default(),
     )
@@ -114,6 +117,12 @@ You always can do the same thing by hands if needed.
 | `self.ramp.enemy` | [`Ramp`]        | Opponent's main base ramp.    |
 | `self.ramp.all`   | `Vec`<[`Ramp`]> | All the ramps around the map. |
 
+### Regions
+| Field                   | Type              | Description                                        |
+|-------------------------|-------------------|----------------------------------------------------|
+| `self.regions().all`    | `Vec`<[`Region`]> | Areas of the map, connected by chokes.             |
+| `self.regions().chokes` | `Vec`<[`Choke`]>  | Narrow places between regions, including ramps.    |
+
 ### Units
 #### Common
 | Field                        | Type            | Description                                                                |
@@ -194,7 +203,7 @@ if self.can_afford(UnitTypeId::Barracks, false)
             step: 4,
             ..Default::default()
         },
-    ) {
+    )? {
         if let Some(builder) = self.units
             // Finding workers which are not already building.
             .my.workers.iter().filter(|w| !w.is_constructing())
@@ -219,7 +228,7 @@ if self.can_afford(UnitTypeId::CommandCenter, false)
     && self.counter().ordered().count(UnitTypeId::CommandCenter) == 0
 {
     // Getting next closest expansion
-    if let Some((location, _resource_center)) = self.get_expansion() {
+    if let Some((location, _resource_center)) = self.get_expansion()? {
         if let Some(builder) = self.units
             // Finding workers which are not already building.
             .my.workers.iter().filter(|w| !w.is_constructing())
@@ -278,10 +287,20 @@ Both use the same kind of system. In order to get your bot ready for ladder, mak
 - `--GamePort` - Port.
 - `--StartPort` - Yet another port.
 
-If you're too lazy to add argparser yourself, see [`examples`] folder,
-some examples already have fully functional parser.
+These args are parsed by [`LadderArgs`](client::LadderArgs), so the simplest way is to call
+[`run_ladder_or_local`](client::run_ladder_or_local), which joins ladder game if they're given
+and plays local game otherwise:
+```
+run_ladder_or_local(
+    &mut bot,
+    LocalOptions::new(Computer::new(Race::Random, Difficulty::VeryEasy, None), "EverDreamLE"),
+)
+```
+
+Bots with their own arguments can check [`LadderArgs::from_env`](client::LadderArgs::from_env) first,
+like ones in [`examples`] folder do.
 
-Then call [`run_ladder_game`](client::run_ladder_game) this way:
+Or parse them yourself and call [`run_ladder_game`](client::run_ladder_game) this way:
 ```
 run_ladder_game(
     &mut bot,
@@ -297,7 +316,8 @@ The API will do the rest.
 Since [SC2AI] and [AI Arena] run the games on different platforms
 you'll need to provide suitable binaries for each ladder.
 
-Because of version differences ids are conditionally compiled for windows and linux.
+Ids differ between versions, they're converted at runtime by [`DataVersion`](ids::DataVersion)
+of connected client, so the same code works on both.
 
 [SC2AI]: https://sc2ai.net
 [AI Arena]: https://ai-arena.net
@@ -307,6 +327,8 @@ Because of version differences ids are conditionally compiled for windows and li
 [`Point2`]: geometry::Point2
 [`UnitTypeId`]: ids::UnitTypeId
 [`Ramp`]: ramp::Ramp
+[`Region`]: regions::Region
+[`Choke`]: regions::Choke
 [`Units`]: units::Units
 [`PlayerUnits`]: units::PlayerUnits
 [`GameInfo`]: game_info::GameInfo
@@ -339,30 +361,47 @@ pub mod prelude {
 	pub use crate::distance::rayon::{ParCenter, ParDistanceIterator, ParDistanceSlice};
 	#[cfg(feature = "rayon")]
 	pub use crate::units::rayon::ParUnitsIterator;
+	#[cfg(feature = "async")]
+	pub use crate::async_client::{run_ladder_game_async, run_vs_computer_async};
+	#[cfg(all(feature = "async", feature = "test-utils"))]
+	pub use crate::async_client::run_mock_game_async;
+	#[cfg(feature = "test-utils")]
+	pub use crate::mock::{MockFixtures, MockServer};
+	#[cfg(feature = "replay")]
+	pub use crate::replay::{Replay, TrackerEventKind};
 	pub use crate::{
 		action::Target,
+		batch::BatchRunner,
 		bot::PlacementOptions,
 		client::{
-			run_ladder_game, run_vs_computer, run_vs_human, LaunchOptions, RunnerMulti, RunnerSingle,
-			SC2Result,
+			run_bot_vs_bot, run_ladder_game, run_ladder_game_recorded, run_ladder_game_with_args,
+			run_ladder_or_local, run_recorded_game, run_vs_computer, run_vs_human, ConnectOptions, GameLimits,
+			LadderArgs, LaunchOptions, Launcher, LimitAction, LocalOptions, RunnerBotVsBot, RunnerMulti,
+			RunnerSingle, SC2Error, SC2Result,
 		},
 		consts::{ALL_PRODUCERS, PRODUCERS, RESEARCHERS, TECH_REQUIREMENTS},
 		distance::{Center, Distance, DistanceIterator, DistanceSlice},
 		geometry::Point2,
 		ids::*,
+		influence::ThreatMap,
+		observer::{Observer, ReplayRunner, RunnerObserver},
+		pathfinding::{FlowField, PathWeights, PathingGrid},
 		player::{AIBuild, Computer, Difficulty, GameResult, Race},
 		unit::Unit,
 		units::{iter::UnitsIterator, Units},
-		Event, Player, PlayerSettings,
+		Event, Player, PlayerSettings, SpatialOptions,
 	};
 	#[doc(no_inline)]
 	pub use sc2_macro::{bot, bot_new};
 }
 
-mod paths;
+mod process;
 
 pub mod action;
 pub mod api;
+#[cfg(feature = "async")]
+pub mod async_client;
+pub mod batch;
 pub mod bot;
 pub mod client;
 pub mod consts;
@@ -373,9 +412,19 @@ pub mod game_info;
 pub mod game_state;
 pub mod geometry;
 pub mod ids;
+pub mod influence;
+#[cfg(feature = "test-utils")]
+pub mod mock;
+pub mod observer;
+pub mod pathfinding;
+pub mod paths;
 pub mod pixel_map;
 pub mod player;
 pub mod ramp;
+pub mod regions;
+pub mod recording;
+#[cfg(feature = "replay")]
+pub mod replay;
 pub mod score;
 pub mod unit;
 pub mod units;
@@ -606,7 +655,7 @@ impl MyBot {
 pub use sc2_macro::bot_new;
 
 #[doc(inline)]
-pub use client::SC2Result;
+pub use client::{SC2Error, SC2Result};
 /**
 Request to the SC2 API.
 
@@ -627,6 +676,8 @@ pub struct PlayerSettings {
 	name: Option<String>,
 	raw_affects_selection: bool,
 	raw_crop_to_playable_area: bool,
+	feature_layer: Option<SpatialOptions>,
+	render: Option<SpatialOptions>,
 }
 impl PlayerSettings {
 	/// Constructs new settings with given `Race` and name.
@@ -637,6 +688,8 @@ impl PlayerSettings {
 			name: name.map(|n| n.to_string()),
 			raw_affects_selection: false,
 			raw_crop_to_playable_area: false,
+			feature_layer: None,
+			render: None,
 		}
 	}
 	/// Constructs new settings with more options given.
@@ -657,6 +710,50 @@ impl PlayerSettings {
 			name: name.map(|n| n.to_string()),
 			raw_affects_selection,
 			raw_crop_to_playable_area,
+			feature_layer: None,
+			render: None,
+		}
+	}
+	/// Enables feature layers, which will be available in
+	/// [`state.observation.feature_layer`](game_state::Observation::feature_layer).
+	pub fn with_feature_layer(mut self, options: SpatialOptions) -> Self {
+		self.feature_layer = Some(options);
+		self
+	}
+	/// Enables rendered images, which will be available in
+	/// [`state.observation.render`](game_state::Observation::render).
+	///
+	/// Rendering works only on Windows and MacOS, and requires graphics to be enabled.
+	pub fn with_render(mut self, options: SpatialOptions) -> Self {
+		self.render = Some(options);
+		self
+	}
+}
+
+/// Resolutions and other options of spatial observations (feature layers and rendered images).
+#[derive(Debug, Clone, Copy)]
+pub struct SpatialOptions {
+	/// Resolution of screen (camera view) as `(x, y)`.
+	pub screen: (i32, i32),
+	/// Resolution of minimap as `(x, y)`.
+	pub minimap: (i32, i32),
+	/// Width of camera view in game units, used only for feature layers. Default: `24.0`.
+	pub camera_width: f32,
+	/// Crops minimap to playable area. Default: `false`.
+	pub crop_to_playable_area: bool,
+	/// Enables layers with information not available to humans
+	/// (e.g. alerts on minimap), used only for feature layers. Default: `false`.
+	pub allow_cheating_layers: bool,
+}
+impl SpatialOptions {
+	/// Constructs new options with given resolutions of screen and minimap.
+	pub fn new(screen: (i32, i32), minimap: (i32, i32)) -> Self {
+		Self {
+			screen,
+			minimap,
+			camera_width: 24.0,
+			crop_to_playable_area: false,
+			allow_cheating_layers: false,
 		}
 	}
 }
@@ -674,6 +771,12 @@ pub enum Event {
 	ConstructionComplete(u64),
 	/// Detected actual race of random opponent.
 	RandomRaceDetected(Race),
+	/// Action sent on current step failed (your only).
+	///
+	/// Reported right after SC2 responded, so failed command can be retried with different target
+	/// (e.g. [`build`](unit::Unit::build) with a new placement) and sent on the same step.
+	/// In [`pipelined`](client::RunnerSingle::pipelined) mode retried commands are sent on the next step.
+	ActionFailed(action::ActionOutcome),
 }
 
 /// Trait that bots must implement.
@@ -0,0 +1,346 @@
+//! Offline mock of SC2 API server, used to test bots without running the game client.
+//!
+//! Enabled with `test-utils` feature.
+//!
+//! Mock server speaks the same protobuf-over-websocket protocol as SC2 client
+//! and answers requests with scripted [`MockFixtures`].
+//!
+//! # Usage
+//! ```
+//! let server = MockServer::start(MockFixtures::new(Race::Terran, 10))?;
+//!
+//! let mut runner = RunnerSingle::mock(
+//!     &mut bot,
+//!     Computer::new(Race::Random, Difficulty::VeryEasy, None),
+//!     &server,
+//! )?;
+//! runner.run_game()?;
+//! ```
+
+use crate::{client::SC2Result, paths::get_version_info, player::Race, IntoProto};
+use protobuf::Message;
+use sc2_proto::{
+	common::{ImageData, Race as ProtoRace},
+	error::ActionResult as ProtoActionResult,
+	query::{ResponseQueryAvailableAbilities, ResponseQueryBuildingPlacement, ResponseQueryPathing},
+	sc2api::{
+		PlayerInfo, PlayerResult, PlayerType, Request, Response, ResponseData, ResponseGameInfo,
+		ResponseObservation, Result as ProtoGameResult, Status,
+	},
+};
+use std::{
+	collections::VecDeque,
+	net::{TcpListener, TcpStream},
+	sync::{Arc, Mutex},
+	thread::{self, JoinHandle},
+};
+use tungstenite::{accept, Message::Binary, WebSocket};
+
+const HOST: &str = "127.0.0.1";
+const MOCK_MAP_PATH: &str = "Mock.SC2Map";
+const MOCK_VERSION: &str = "5.0.2";
+
+/// Scripted responses of [`MockServer`].
+#[derive(Clone)]
+pub struct MockFixtures {
+	/// Id of the bot returned in `ResponseJoinGame`.
+	pub player_id: u32,
+	/// Base build of the game returned in `ResponsePing`, determines [`DataVersion`] of ids.
+	///
+	/// [`DataVersion`]: crate::ids::DataVersion
+	pub base_build: u32,
+	/// Hash of the game data returned in `ResponsePing`.
+	pub data_version: String,
+	/// Response to `RequestGameInfo`.
+	pub game_info: ResponseGameInfo,
+	/// Response to `RequestData`.
+	pub data: ResponseData,
+	/// Responses to `RequestObservation`, returned one by one in given order.
+	///
+	/// When all observations are used, server responds with `Status::ended`.
+	/// `RequestRestartGame` starts them over, `RequestQuickLoad` restores ones left at `RequestQuickSave`.
+	pub observations: Vec<ResponseObservation>,
+	/// Result of the game for every player, sent in the last observation.
+	pub results: Vec<(u32, ProtoGameResult)>,
+	/// Results of actions, returned one by one for every action received.
+	///
+	/// When all results are used, actions succeed.
+	pub action_results: Vec<ProtoActionResult>,
+}
+impl MockFixtures {
+	/// Constructs fixtures of empty 64x64 map, where bot plays given race against computer
+	/// and game lasts given number of observations. Bot wins in the end.
+	pub fn new(race: Race, steps: u32) -> Self {
+		Self::with_map_size(race, steps, 64, 64)
+	}
+	/// The same as [`new`](Self::new), but with custom map size.
+	pub fn with_map_size(race: Race, steps: u32, width: i32, height: i32) -> Self {
+		let mut game_info = ResponseGameInfo::new();
+		game_info.set_map_name("Mock".to_string());
+		game_info.set_local_map_path(MOCK_MAP_PATH.to_string());
+
+		let race = if race.is_random() { Race::Terran } else { race };
+		let players = game_info.mut_player_info();
+		let mut player = PlayerInfo::new();
+		player.set_player_id(1);
+		player.set_field_type(PlayerType::Participant);
+		player.set_race_requested(race.into_proto());
+		player.set_race_actual(race.into_proto());
+		players.push(player);
+		let mut computer = PlayerInfo::new();
+		computer.set_player_id(2);
+		computer.set_field_type(PlayerType::Computer);
+		computer.set_race_requested(ProtoRace::Random);
+		players.push(computer);
+
+		let start_raw = game_info.mut_start_raw();
+		let map_size = start_raw.mut_map_size();
+		map_size.set_x(width);
+		map_size.set_y(height);
+		start_raw.set_pathing_grid(bit_grid(width, height, u8::MAX));
+		start_raw.set_placement_grid(bit_grid(width, height, u8::MAX));
+		start_raw.set_terrain_height(byte_grid(width, height, 128));
+		let area = start_raw.mut_playable_area();
+		area.mut_p0().set_x(2);
+		area.mut_p0().set_y(2);
+		area.mut_p1().set_x(width - 2);
+		area.mut_p1().set_y(height - 2);
+		let mut enemy_start = sc2_proto::common::Point2D::new();
+		enemy_start.set_x((width - 8) as f32);
+		enemy_start.set_y((height - 8) as f32);
+		start_raw.mut_start_locations().push(enemy_start);
+
+		let observations = (0..steps)
+			.map(|step| {
+				let mut res = ResponseObservation::new();
+				let obs = res.mut_observation();
+				obs.set_game_loop(step);
+				let common = obs.mut_player_common();
+				common.set_player_id(1);
+				common.set_minerals(50);
+				common.set_food_cap(15);
+				let map_state = obs.mut_raw_data().mut_map_state();
+				// 2 - Visible
+				map_state.set_visibility(byte_grid(width, height, 2));
+				map_state.set_creep(bit_grid(width, height, u8::MAX));
+				res
+			})
+			.collect();
+
+		let (base_build, data_version) = get_version_info(MOCK_VERSION).unwrap();
+		Self {
+			player_id: 1,
+			base_build,
+			data_version: data_version.to_string(),
+			game_info,
+			data: ResponseData::new(),
+			observations,
+			results: vec![(1, ProtoGameResult::Victory), (2, ProtoGameResult::Defeat)],
+			action_results: Vec::new(),
+		}
+	}
+}
+
+fn bit_grid(width: i32, height: i32, fill: u8) -> ImageData {
+	let mut grid = ImageData::new();
+	grid.set_bits_per_pixel(1);
+	grid.mut_size().set_x(width);
+	grid.mut_size().set_y(height);
+	grid.set_data(vec![fill; (width * height) as usize / 8]);
+	grid
+}
+fn byte_grid(width: i32, height: i32, fill: u8) -> ImageData {
+	let mut grid = ImageData::new();
+	grid.set_bits_per_pixel(8);
+	grid.mut_size().set_x(width);
+	grid.mut_size().set_y(height);
+	grid.set_data(vec![fill; (width * height) as usize]);
+	grid
+}
+
+/// Mock SC2 API server running in background thread.
+///
+/// Serves a single websocket connection until `RequestQuit` is received or connection is closed.
+pub struct MockServer {
+	port: i32,
+	requests: Arc<Mutex<Vec<Request>>>,
+	handle: Option<JoinHandle<()>>,
+}
+impl MockServer {
+	/// Starts server on unused local port with given fixtures.
+	pub fn start(fixtures: MockFixtures) -> SC2Result<Self> {
+		let listener = TcpListener::bind((HOST, 0))?;
+		let port = listener.local_addr()?.port() as i32;
+		let requests = Arc::new(Mutex::new(Vec::new()));
+
+		let log = Arc::clone(&requests);
+		let handle = thread::spawn(move || match listener.accept() {
+			Ok((stream, _)) => match accept(stream) {
+				Ok(ws) => {
+					if let Err(e) = serve(ws, fixtures, log) {
+						error!("Mock server stopped: {}", e);
+					}
+				}
+				Err(e) => error!("Mock server handshake failed: {}", e),
+			},
+			Err(e) => error!("Mock server can't accept connection: {}", e),
+		});
+
+		Ok(Self {
+			port,
+			requests,
+			handle: Some(handle),
+		})
+	}
+	/// Port server is listening on.
+	pub fn port(&self) -> i32 {
+		self.port
+	}
+	/// Host server is listening on.
+	pub fn host(&self) -> &str {
+		HOST
+	}
+	/// Path to the map reported by server in `ResponseGameInfo`.
+	pub fn map_path(&self) -> String {
+		MOCK_MAP_PATH.to_string()
+	}
+	/// Returns all requests received by server so far.
+	pub fn requests(&self) -> Vec<Request> {
+		self.requests.lock().unwrap().clone()
+	}
+	/// Waits until server stops serving connection.
+	pub fn join(&mut self) {
+		if let Some(handle) = self.handle.take() {
+			if handle.join().is_err() {
+				error!("Mock server thread panicked");
+			}
+		}
+	}
+}
+
+fn serve(
+	mut ws: WebSocket<TcpStream>,
+	fixtures: MockFixtures,
+	log: Arc<Mutex<Vec<Request>>>,
+) -> SC2Result<()> {
+	let mut observations = fixtures.observations.iter().cloned().collect::<VecDeque<_>>();
+	let mut action_results = fixtures.action_results.iter().copied().collect::<VecDeque<_>>();
+	// Saved state of the game is the rest of observations
+	let mut saved = None;
+
+	loop {
+		let msg = match ws.read_message() {
+			Ok(msg) => msg,
+			Err(_) => return Ok(()),
+		};
+		if msg.is_close() {
+			return Ok(());
+		}
+		if !msg.is_binary() {
+			continue;
+		}
+
+		let mut req = Request::new();
+		req.merge_from_bytes(msg.into_data().as_slice())?;
+
+		let mut res = Response::new();
+		res.set_id(req.get_id());
+		res.set_status(Status::in_game);
+
+		let quit = req.has_quit();
+		if req.has_create_game() {
+			res.mut_create_game();
+			res.set_status(Status::init_game);
+		} else if req.has_start_replay() {
+			res.mut_start_replay();
+			res.set_status(Status::in_replay);
+		} else if req.has_join_game() {
+			res.mut_join_game().set_player_id(fixtures.player_id);
+		} else if req.has_game_info() {
+			res.set_game_info(fixtures.game_info.clone());
+		} else if req.has_data() {
+			res.set_data(fixtures.data.clone());
+		} else if req.has_observation() {
+			match observations.pop_front() {
+				Some(obs) => res.set_observation(obs),
+				None => {
+					res.set_status(Status::ended);
+					let results = res.mut_observation().mut_player_result();
+					fixtures.results.iter().for_each(|(id, result)| {
+						let mut player_result = PlayerResult::new();
+						player_result.set_player_id(*id);
+						player_result.set_result(*result);
+						results.push(player_result);
+					});
+				}
+			}
+		} else if req.has_step() {
+			res.mut_step();
+		} else if req.has_action() {
+			let results = req
+				.get_action()
+				.get_actions()
+				.iter()
+				.map(|_| action_results.pop_front().unwrap_or(ProtoActionResult::Success))
+				.collect();
+			res.mut_action().set_result(results);
+		} else if req.has_debug() {
+			res.mut_debug();
+		} else if req.has_query() {
+			let query = req.get_query();
+			let res_query = res.mut_query();
+			query.get_pathing().iter().for_each(|_| {
+				res_query.mut_pathing().push(ResponseQueryPathing::new());
+			});
+			query.get_abilities().iter().for_each(|q| {
+				let mut abilities = ResponseQueryAvailableAbilities::new();
+				abilities.set_unit_tag(q.get_unit_tag());
+				res_query.mut_abilities().push(abilities);
+			});
+			query.get_placements().iter().for_each(|_| {
+				let mut placement = ResponseQueryBuildingPlacement::new();
+				placement.set_result(ProtoActionResult::Success);
+				res_query.mut_placements().push(placement);
+			});
+		} else if req.has_restart_game() {
+			observations = fixtures.observations.iter().cloned().collect();
+			res.mut_restart_game();
+		} else if req.has_quick_save() {
+			saved = Some(observations.clone());
+			res.mut_quick_save();
+		} else if req.has_quick_load() {
+			match &saved {
+				Some(saved) => {
+					observations = saved.clone();
+					res.mut_quick_load();
+				}
+				None => res.mut_error().push("Game wasn't saved".to_string()),
+			}
+		} else if req.has_save_replay() {
+			res.mut_save_replay();
+		} else if req.has_leave_game() {
+			res.mut_leave_game();
+			res.set_status(Status::launched);
+		} else if quit {
+			res.mut_quit();
+			res.set_status(Status::quit);
+		} else if req.has_ping() {
+			let ping = res.mut_ping();
+			ping.set_game_version(MOCK_VERSION.to_string());
+			ping.set_data_version(fixtures.data_version.clone());
+			ping.set_data_build(fixtures.base_build);
+			ping.set_base_build(fixtures.base_build);
+		} else {
+			res.mut_error()
+				.push("Request is not supported by mock server".to_string());
+		}
+
+		log.lock().unwrap().push(req);
+		ws.write_message(Binary(res.write_to_bytes()?))?;
+
+		if quit {
+			let _ = ws.close(None);
+			return Ok(());
+		}
+	}
+}
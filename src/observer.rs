@@ -0,0 +1,439 @@
+//! Running games in observer (spectator) mode and watching replays.
+//!
+//! Observer doesn't play, but receives observations of the game every step,
+//! which are processed into the same [`Bot`] state as for players.
+//! Useful to build analysis tools and casting overlays.
+//! The same observer can be used to watch replays with [`ReplayRunner`].
+//!
+//! # Usage
+//! ```
+//! #[derive(Default)]
+//! struct Caster {
+//!     bot: Rc<RefCell<Bot>>,
+//! }
+//! impl Observer for Caster {
+//!     fn on_step(&mut self, _iteration: usize) -> SC2Result<()> {
+//!         let bot = self.bot.borrow();
+//!         println!("Army supply: {}", bot.supply_army);
+//!         Ok(())
+//!     }
+//!     fn bot(&self) -> Rc<RefCell<Bot>> {
+//!         Rc::clone(&self.bot)
+//!     }
+//! }
+//!
+//! let mut caster = Caster::default();
+//! let mut runner = RunnerObserver::new(
+//!     &mut caster,
+//!     (
+//!         Computer::new(Race::Zerg, Difficulty::Hard, None),
+//!         Computer::new(Race::Protoss, Difficulty::Hard, None),
+//!     ),
+//!     "EverDreamLE",
+//!     None,
+//! )?;
+//! runner.launch()?;
+//! runner.run_game()?;
+//!
+//! let mut runner = ReplayRunner::new(&mut caster, Some("5.0.2"))?;
+//! runner.launch()?;
+//! runner.run_replay("replays/Game.SC2Replay")?;
+//! ```
+
+#[cfg(feature = "test-utils")]
+use crate::mock::MockServer;
+use crate::{
+	api::API,
+	bot::Bot,
+	client::{
+		connect_to_websocket, create_computer_setup, create_game_result, get_unused_port, interface_options,
+		join_request, join_result, launch_client, observation_request, save_replay, set_static_data,
+		step_request, ConnectOptions, Launcher, ProtoError, SC2Result, HOST,
+	},
+	game_state::update_state,
+	paths::*,
+	player::{Computer, GameResult, Race},
+	Event, IntoSC2, Player, PlayerSettings,
+};
+use sc2_proto::sc2api::{PlayerSetup, PlayerType, Request, Status};
+use std::{cell::RefCell, env, rc::Rc};
+
+/// Trait for observers. The same as [`Player`], but observer can't play.
+///
+/// State of the game is available through [`bot`](Self::bot),
+/// from the perspective of observed player.
+pub trait Observer {
+	/// Called once on first step (i.e on game start).
+	fn on_start(&mut self) -> SC2Result<()> {
+		Ok(())
+	}
+	/// Called on every game step.
+	fn on_step(&mut self, _iteration: usize) -> SC2Result<()> {
+		Ok(())
+	}
+	/// Called once on last step with results of all players (player id, result).
+	fn on_end(&self, _results: &[(u32, GameResult)]) -> SC2Result<()> {
+		Ok(())
+	}
+	/// Called when different events happen.
+	fn on_event(&mut self, _event: Event) -> SC2Result<()> {
+		Ok(())
+	}
+
+	fn bot(&self) -> Rc<RefCell<Bot>>;
+}
+
+// Allows to process observations the same way as for players
+struct AsPlayer<'a, O: Observer>(&'a mut O);
+impl<O: Observer> Player for AsPlayer<'_, O> {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Random, None)
+	}
+	fn on_event(&mut self, event: Event) -> SC2Result<()> {
+		self.0.on_event(event)
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		self.0.bot()
+	}
+}
+
+/// Runner for observing games between built-in AIs.
+pub struct RunnerObserver<'a, O>
+where
+	O: Observer,
+{
+	observer: &'a mut O,
+	sc2_path: String,
+	sc2_version: Option<&'a str>,
+	map_path: String,
+	/// Computer players.
+	pub computers: (Computer, Computer),
+	/// Id of player, from whose perspective game is observed. Default: `1`.
+	pub observed_player_id: u32,
+	/// Observe games in real time mode or not.
+	pub realtime: bool,
+	/// Save replay after the game in given path.
+	pub save_replay_as: Option<&'a str>,
+	/// Timeouts and retry policy of connection to SC2 client.
+	pub connect_options: ConnectOptions,
+	/// Command used to launch SC2 client.
+	pub launcher: Launcher,
+}
+
+impl<'a, O> RunnerObserver<'a, O>
+where
+	O: Observer,
+{
+	/// Constructs new observer runner.
+	///
+	/// Returns error if SC2 installation or given map not found.
+	pub fn new(
+		observer: &'a mut O,
+		computers: (Computer, Computer),
+		map: &str,
+		sc2_version: Option<&'a str>,
+	) -> SC2Result<Self> {
+		debug!("Starting observed game");
+		let sc2_path = get_path_to_sc2()?;
+		let map_path = get_map_path(&sc2_path, map)?;
+
+		Ok(Self {
+			observer,
+			sc2_path,
+			sc2_version,
+			map_path,
+			computers,
+			observed_player_id: 1,
+			realtime: false,
+			save_replay_as: None,
+			connect_options: Default::default(),
+			launcher: Default::default(),
+		})
+	}
+
+	/// Constructs new observer runner, connected to the [`MockServer`] instead of SC2 client.
+	///
+	/// [`MockServer`]: crate::mock::MockServer
+	#[cfg(feature = "test-utils")]
+	pub fn mock(
+		observer: &'a mut O,
+		computers: (Computer, Computer),
+		server: &MockServer,
+	) -> SC2Result<Self> {
+		debug!("Starting observed game on mock server");
+		let connect_options = ConnectOptions::default();
+		connect_to_mock(observer, server, &connect_options)?;
+
+		Ok(Self {
+			observer,
+			sc2_path: String::new(),
+			sc2_version: None,
+			map_path: server.map_path(),
+			computers,
+			observed_player_id: 1,
+			realtime: false,
+			save_replay_as: None,
+			connect_options,
+			launcher: Default::default(),
+		})
+	}
+
+	/// Launches SC2 client and connects observer to the API.
+	pub fn launch(&mut self) -> SC2Result<()> {
+		let port = get_unused_port();
+		let bot = self.observer.bot();
+		let mut bot = bot.borrow_mut();
+		debug!("Launching SC2 process");
+		bot.process = Some(launch_client(
+			&self.sc2_path,
+			port,
+			self.sc2_version,
+			&self.launcher,
+		)?);
+		debug!("Connecting to websocket");
+		let ws = connect_to_websocket(HOST, port, &self.connect_options, bot.process.as_mut())?;
+		bot.api = Some(API::new(ws));
+		Ok(())
+	}
+
+	/// Runs requested game.
+	///
+	/// Returns results of all players (player id, result).
+	pub fn run_game(&mut self) -> SC2Result<Vec<(u32, GameResult)>> {
+		let bot = self.observer.bot();
+
+		debug!("Sending CreateGame request");
+		let mut req = Request::new();
+		let req_create_game = req.mut_create_game();
+
+		req_create_game
+			.mut_local_map()
+			.set_map_path(self.map_path.clone());
+		let mut setup = PlayerSetup::new();
+		setup.set_field_type(PlayerType::Observer);
+		req_create_game.mut_player_setup().push(setup);
+		create_computer_setup(&self.computers.0, req_create_game);
+		create_computer_setup(&self.computers.1, req_create_game);
+		req_create_game.set_realtime(self.realtime);
+
+		let res = bot.borrow_mut().api().send(req)?;
+		create_game_result(&res)?;
+
+		debug!("Sending JoinGame request");
+		let mut req = join_request(&PlayerSettings::new(Race::Random, None), None);
+		req.mut_join_game()
+			.set_observed_player_id(self.observed_player_id);
+		let res = bot.borrow_mut().api().send(req)?;
+		join_result(&res)?;
+		// Observer sees the game as observed player
+		bot.borrow_mut().player_id = self.observed_player_id;
+
+		set_static_data(&mut bot.borrow_mut())?;
+
+		debug!("Entered main loop");
+		let results = observe_game(self.observer, self.realtime)?;
+		info!("Game finished!");
+
+		if let Some(path) = &self.save_replay_as {
+			save_replay(bot.borrow_mut().api(), &path)?;
+		}
+		Ok(results)
+	}
+
+	/// Changes map to play on.
+	///
+	/// Map can be a path relative to maps directory (e.g. `Ladder2019Season3/AcropolisLE`)
+	/// or just a name, which is searched in all sub-directories (see [`get_map_path`]).
+	///
+	/// Returns error if the map doesn't exist in maps directory.
+	pub fn set_map(&mut self, map: &str) -> SC2Result<()> {
+		self.map_path = get_map_path(&self.sc2_path, map)?;
+		Ok(())
+	}
+
+	/// Manually closes SC2 client.
+	pub fn close(&mut self) {
+		self.observer.bot().borrow_mut().close_client();
+	}
+}
+
+/// Runner for watching replays.
+///
+/// Replays must be played on the same SC2 version they were recorded on.
+pub struct ReplayRunner<'a, O>
+where
+	O: Observer,
+{
+	observer: &'a mut O,
+	sc2_path: String,
+	sc2_version: Option<&'a str>,
+	/// Id of player, from whose perspective replay is watched. Default: `1`.
+	pub observed_player_id: u32,
+	/// Watch replays without fog of war. Default: `false`.
+	pub disable_fog: bool,
+	/// Watch replays in real time mode or not.
+	pub realtime: bool,
+	/// Timeouts and retry policy of connection to SC2 client.
+	pub connect_options: ConnectOptions,
+	/// Command used to launch SC2 client.
+	pub launcher: Launcher,
+}
+
+impl<'a, O> ReplayRunner<'a, O>
+where
+	O: Observer,
+{
+	/// Constructs new replay runner.
+	///
+	/// Returns error if SC2 installation not found.
+	pub fn new(observer: &'a mut O, sc2_version: Option<&'a str>) -> SC2Result<Self> {
+		debug!("Starting replay runner");
+		Ok(Self {
+			observer,
+			sc2_path: get_path_to_sc2()?,
+			sc2_version,
+			observed_player_id: 1,
+			disable_fog: false,
+			realtime: false,
+			connect_options: Default::default(),
+			launcher: Default::default(),
+		})
+	}
+
+	/// Constructs new replay runner, connected to the [`MockServer`] instead of SC2 client.
+	///
+	/// [`MockServer`]: crate::mock::MockServer
+	#[cfg(feature = "test-utils")]
+	pub fn mock(observer: &'a mut O, server: &MockServer) -> SC2Result<Self> {
+		debug!("Starting replay runner on mock server");
+		let connect_options = ConnectOptions::default();
+		connect_to_mock(observer, server, &connect_options)?;
+
+		Ok(Self {
+			observer,
+			sc2_path: String::new(),
+			sc2_version: None,
+			observed_player_id: 1,
+			disable_fog: false,
+			realtime: false,
+			connect_options,
+			launcher: Default::default(),
+		})
+	}
+
+	/// Launches SC2 client and connects observer to the API.
+	pub fn launch(&mut self) -> SC2Result<()> {
+		let port = get_unused_port();
+		let bot = self.observer.bot();
+		let mut bot = bot.borrow_mut();
+		debug!("Launching SC2 process");
+		bot.process = Some(launch_client(
+			&self.sc2_path,
+			port,
+			self.sc2_version,
+			&self.launcher,
+		)?);
+		debug!("Connecting to websocket");
+		let ws = connect_to_websocket(HOST, port, &self.connect_options, bot.process.as_mut())?;
+		bot.api = Some(API::new(ws));
+		Ok(())
+	}
+
+	/// Watches replay in given path.
+	///
+	/// Returns results of all players (player id, result).
+	pub fn run_replay(&mut self, path: &str) -> SC2Result<Vec<(u32, GameResult)>> {
+		let bot = self.observer.bot();
+		// SC2 requires absolute path to replay
+		let path = env::current_dir()?.join(path);
+
+		debug!("Sending StartReplay request");
+		let mut req = Request::new();
+		let req_start_replay = req.mut_start_replay();
+		req_start_replay.set_replay_path(path.to_string_lossy().into_owned());
+		req_start_replay.set_observed_player_id(self.observed_player_id);
+		req_start_replay.set_options(interface_options(&PlayerSettings::new(Race::Random, None)));
+		req_start_replay.set_disable_fog(self.disable_fog);
+		req_start_replay.set_realtime(self.realtime);
+
+		let res = bot.borrow_mut().api().send(req)?;
+		let res_start_replay = res.get_start_replay();
+		if res_start_replay.has_error() {
+			let err = ProtoError::new(res_start_replay.get_error(), res_start_replay.get_error_details());
+			error!("{}", err);
+			return Err(err.into());
+		}
+		{
+			let mut bot = bot.borrow_mut();
+			bot.player_id = self.observed_player_id;
+			bot.disable_fog = self.disable_fog;
+		}
+
+		set_static_data(&mut bot.borrow_mut())?;
+
+		debug!("Entered main loop");
+		let results = observe_game(self.observer, self.realtime)?;
+		info!("Replay finished!");
+
+		Ok(results)
+	}
+
+	/// Manually closes SC2 client.
+	pub fn close(&mut self) {
+		self.observer.bot().borrow_mut().close_client();
+	}
+}
+
+#[cfg(feature = "test-utils")]
+fn connect_to_mock<O: Observer>(
+	observer: &mut O,
+	server: &MockServer,
+	connect_options: &ConnectOptions,
+) -> SC2Result<()> {
+	debug!("Connecting to websocket");
+	let ws = connect_to_websocket(server.host(), server.port(), connect_options, None)?;
+	observer.bot().borrow_mut().api = Some(API::new(ws));
+	Ok(())
+}
+
+fn observe_game<O: Observer>(observer: &mut O, realtime: bool) -> SC2Result<Vec<(u32, GameResult)>> {
+	let bot = observer.bot();
+	let mut observer = AsPlayer(observer);
+	let mut iteration = None;
+	loop {
+		let req = observation_request(&bot.borrow());
+		let res = bot.borrow_mut().api().send(req)?;
+
+		if matches!(res.get_status(), Status::ended) {
+			let results = res
+				.get_observation()
+				.get_player_result()
+				.iter()
+				.map(|r| (r.get_player_id(), r.get_result().into_sc2()))
+				.collect::<Vec<_>>();
+			debug!("Results: {:?}", results);
+			observer.0.on_end(&results)?;
+			return Ok(results);
+		}
+
+		match iteration {
+			Some(iteration) => {
+				update_state(&mut observer, res.get_observation())?;
+				bot.borrow_mut().prepare_step();
+				observer.0.on_step(iteration)?;
+			}
+			None => {
+				bot.borrow_mut().init_data_for_unit();
+				update_state(&mut observer, res.get_observation())?;
+				bot.borrow_mut().prepare_start();
+				observer.0.on_start()?;
+			}
+		}
+		iteration = Some(iteration.map_or(0, |i| i + 1));
+
+		if !realtime {
+			let req = step_request(&bot.borrow());
+			bot.borrow_mut().api().send_request(req)?;
+		}
+	}
+}
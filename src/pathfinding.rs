@@ -0,0 +1,563 @@
+//! Local pathfinding for ground and air units, which doesn't need requests to SC2.
+//!
+//! Unlike [`query_pathing`](crate::bot::Bot::query_pathing) returns full paths
+//! and can be used to find many paths on every step.
+//!
+//! # Usage
+//! ```
+//! // Pathing grid of the map with structures and resources on it
+//! let grid = PathingGrid::from_bot(self);
+//! if let Some(path) = grid.find_path(unit.position, target, unit.radius) {
+//!     println!("Distance: {}, waypoints: {:?}", path.distance, path.waypoints);
+//! }
+//!
+//! // Path for harassing unit, which avoids enemies and creep
+//! let grid = if unit.is_flying { PathingGrid::air(self) } else { PathingGrid::from_bot(self) };
+//! let path = grid.find_safe_path(self, &unit, target, &PathWeights::default());
+//!
+//! // Field computed once and used by all units of army to move to the rally point
+//! if let Some(field) = PathingGrid::from_bot(self).flow_field(rally, 1.0) {
+//!     for u in &army {
+//!         if let Some(next) = field.next_step(u.position) {
+//!             u.move_to(Target::Pos(next), false);
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::{
+	bot::{Bot, Locked},
+	geometry::Point2,
+	ids::UnitTypeId,
+	influence::ThreatMap,
+	pixel_map::PixelMap,
+	unit::Unit,
+};
+use ndarray::Array2;
+use std::{cmp::Ordering, collections::BinaryHeap, f32::consts::SQRT_2};
+
+// Structures which don't block pathing of ground units
+const PATHABLE_STRUCTURES: &[UnitTypeId] = &[
+	UnitTypeId::SupplyDepotLowered,
+	UnitTypeId::CreepTumor,
+	UnitTypeId::CreepTumorBurrowed,
+	UnitTypeId::CreepTumorQueen,
+];
+
+// How far from unpathable goal pathable cell is searched
+const GOAL_SEARCH_RADIUS: isize = 8;
+
+/// Path found by [`PathingGrid`].
+#[derive(Debug, Clone)]
+pub struct Path {
+	/// Points where path changes direction, including start and goal.
+	pub waypoints: Vec<Point2>,
+	/// Length of the path, including additional costs of cells if there were any.
+	pub distance: f32,
+}
+
+/// Grid of pathable cells used to find paths for ground units.
+///
+/// Cell `(x, y)` covers area from `(x, y)` to `(x + 1, y + 1)`.
+#[derive(Clone)]
+pub struct PathingGrid {
+	pathable: Array2<bool>,
+}
+impl PathingGrid {
+	/// Constructs grid from pathing grid of the map (e.g. [`game_info.pathing_grid`]).
+	///
+	/// [`game_info.pathing_grid`]: crate::game_info::GameInfo::pathing_grid
+	pub fn new(grid: &PixelMap) -> Self {
+		Self {
+			pathable: grid.map(|pixel| pixel.is_empty()),
+		}
+	}
+	/// Constructs grid from pathing grid of the map
+	/// and blocks cells under structures and resources currently visible to the bot.
+	pub fn from_bot(bot: &Bot) -> Self {
+		let mut grid = Self::new(&bot.game_info.pathing_grid);
+		let structures = bot
+			.units
+			.all
+			.iter()
+			.filter(|u| u.is_structure() && !u.is_flying && !PATHABLE_STRUCTURES.contains(&u.type_id));
+		for u in structures.chain(&bot.units.resources) {
+			grid.block_unit(u);
+		}
+		grid
+	}
+	/// Constructs grid for flying units, where all cells of playable area are pathable.
+	pub fn air(bot: &Bot) -> Self {
+		let area = bot.game_info.playable_area;
+		Self {
+			pathable: Array2::from_shape_fn(bot.game_info.pathing_grid.dim(), |(x, y)| {
+				x >= area.x0 && x < area.x1 && y >= area.y0 && y < area.y1
+			}),
+		}
+	}
+
+	/// Returns size of the grid as `(width, height)`.
+	pub fn size(&self) -> (usize, usize) {
+		self.pathable.dim()
+	}
+	/// Checks if given cell is pathable, cells out of grid are not.
+	pub fn is_pathable(&self, pos: (usize, usize)) -> bool {
+		self.pathable.get(pos).copied().unwrap_or(false)
+	}
+	/// Changes pathability of given cell.
+	pub fn set_pathable(&mut self, pos: (usize, usize), pathable: bool) {
+		if let Some(cell) = self.pathable.get_mut(pos) {
+			*cell = pathable;
+		}
+	}
+	/// Changes pathability of all cells in rectangle with given center and size.
+	pub fn set_rect(&mut self, center: Point2, size: (usize, usize), pathable: bool) {
+		let x0 = (center.x - size.0 as f32 / 2.0).round().max(0.0) as usize;
+		let y0 = (center.y - size.1 as f32 / 2.0).round().max(0.0) as usize;
+		for x in x0..x0 + size.0 {
+			for y in y0..y0 + size.1 {
+				self.set_pathable((x, y), pathable);
+			}
+		}
+	}
+	/// Blocks cells under footprint of given structure or resource, other units are ignored.
+	pub fn block_unit(&mut self, unit: &Unit) {
+		let size = if unit.is_mineral() {
+			(2, 1)
+		} else if unit.is_geyser() {
+			(3, 3)
+		} else {
+			match unit.building_size() {
+				Some(size) => (size, size),
+				None => return,
+			}
+		};
+		self.set_rect(unit.position, size, false);
+	}
+
+	/// Finds the shortest path for ground unit with given radius from `start` to `goal`.
+	///
+	/// Unit can't move through cells with centers closer than `radius` to obstacles.
+	/// If `goal` is unpathable (e.g. it's structure), path leads to the closest pathable cell near it.
+	///
+	/// Returns `None` if there's no path.
+	pub fn find_path(&self, start: Point2, goal: Point2, radius: f32) -> Option<Path> {
+		self.find_path_with_cost(start, goal, radius, |_| 0.0)
+	}
+	/// Finds the cheapest path like [`find_path`](Self::find_path),
+	/// but entering a cell costs `cost(cell)` in addition to it's length.
+	///
+	/// `cost` shouldn't be negative, [`distance`](Path::distance) of returned path includes it.
+	pub fn find_path_with_cost<F>(&self, start: Point2, goal: Point2, radius: f32, cost: F) -> Option<Path>
+	where
+		F: Fn((usize, usize)) -> f32,
+	{
+		let passable = Passability::new(self, radius);
+		let goal_cell = self.cell(goal)?;
+		let end_cell = passable.closest_to(goal_cell)?;
+		let (cells, distance) = astar(
+			self.size(),
+			self.cell(start)?,
+			end_cell,
+			|cell| passable.check(cell),
+			cost,
+		)?;
+		let end = if end_cell == goal_cell {
+			goal
+		} else {
+			cell_center(end_cell)
+		};
+		Some(Path::from_cells(&cells, start, end, distance))
+	}
+	/// Finds the cheapest path for given unit to `goal`, avoiding enemies and creep.
+	/// Costs of cells are calculated with [`PathWeights::costs`].
+	///
+	/// Grid should match the unit, i.e. use grid from [`air`](Self::air) for flying units.
+	pub fn find_safe_path(
+		&self,
+		bot: &Bot,
+		unit: &Unit,
+		goal: Point2,
+		weights: &PathWeights,
+	) -> Option<Path> {
+		let costs = weights.costs(bot, unit);
+		self.find_path_with_cost(unit.position, goal, unit.radius, |cell| {
+			costs.get(cell).copied().unwrap_or(0.0)
+		})
+	}
+	/// Computes distances from all cells to `target` for units with given radius,
+	/// so every unit can get the next step toward it in `O(1)` with [`FlowField::next_step`].
+	///
+	/// Use grid from [`air`](Self::air) for flying units.
+	/// If `target` is unpathable, field leads to the closest pathable cell near it.
+	pub fn flow_field(&self, target: Point2, radius: f32) -> Option<FlowField> {
+		let passable = Passability::new(self, radius);
+		let goal_cell = self.cell(target)?;
+		let target_cell = passable.closest_to(goal_cell)?;
+		Some(FlowField {
+			distances: dijkstra(self.size(), target_cell, |cell| passable.check(cell)),
+			target: if target_cell == goal_cell {
+				target
+			} else {
+				cell_center(target_cell)
+			},
+			target_cell,
+		})
+	}
+	/// Returns length of the shortest path for ground unit with given radius from `start` to `goal`,
+	/// or `None` if there's no path. See [`find_path`](Self::find_path) for details.
+	pub fn distance(&self, start: Point2, goal: Point2, radius: f32) -> Option<f32> {
+		self.find_path(start, goal, radius).map(|path| path.distance)
+	}
+
+	fn cell(&self, pos: Point2) -> Option<(usize, usize)> {
+		let (width, height) = self.size();
+		if pos.x < 0.0 || pos.y < 0.0 || pos.x >= width as f32 || pos.y >= height as f32 {
+			return None;
+		}
+		Some((pos.x as usize, pos.y as usize))
+	}
+}
+
+/// Distances from every cell of the map to target, created by [`PathingGrid::flow_field`].
+#[derive(Debug, Clone)]
+pub struct FlowField {
+	/// Length of the shortest path from every cell to target, infinity for unreachable cells.
+	pub distances: Array2<f32>,
+	/// Point where units are led to.
+	pub target: Point2,
+	target_cell: (usize, usize),
+}
+impl FlowField {
+	/// Returns length of the shortest path from given point to target, or `None` if it's unreachable.
+	pub fn distance(&self, pos: Point2) -> Option<f32> {
+		if pos.x < 0.0 || pos.y < 0.0 {
+			return None;
+		}
+		self.distances
+			.get((pos.x as usize, pos.y as usize))
+			.copied()
+			.filter(|d| d.is_finite())
+	}
+	/// Returns center of the next cell on the shortest path from given point to target,
+	/// or target itself if it's in the next cell.
+	///
+	/// Units in unreachable cells (e.g. too close to obstacles for radius of field)
+	/// are led to reachable cell around. Returns `None` if there're no such cells.
+	pub fn next_step(&self, pos: Point2) -> Option<Point2> {
+		if pos.x < 0.0 || pos.y < 0.0 {
+			return None;
+		}
+		let cell = (pos.x as usize, pos.y as usize);
+		if cell == self.target_cell {
+			return Some(self.target);
+		}
+		let reachable = |cell: (usize, usize)| self.distances.get(cell).map_or(false, |d| d.is_finite());
+		moves(self.distances.dim(), cell, &reachable)
+			.map(|(next, step)| (next, step + self.distances[next]))
+			.min_by(|(_, d1), (_, d2)| d1.partial_cmp(d2).unwrap())
+			.map(|(next, _)| {
+				if next == self.target_cell {
+					self.target
+				} else {
+					cell_center(next)
+				}
+			})
+	}
+}
+
+/// Weights of additional costs of cells, used to find safe paths with [`PathingGrid::find_safe_path`].
+#[derive(Debug, Clone)]
+pub struct PathWeights {
+	/// Cost of cell per 1 dps of enemies, which can attack unit in it. Default: `1.0`.
+	pub threat: f32,
+	/// Cost of cell in range of enemy detectors, used only for cloaked units. Default: `20.0`.
+	pub detection: f32,
+	/// Cost of cell with creep, used only for ground units. Default: `2.0`.
+	///
+	/// Should be set to `0` when playing zerg, since creep can be your own.
+	pub creep: f32,
+	/// Distance added to attack and detection ranges of enemies. Default: `1.0`.
+	pub margin: f32,
+}
+impl Default for PathWeights {
+	fn default() -> Self {
+		Self {
+			threat: 1.0,
+			detection: 20.0,
+			creep: 2.0,
+			margin: 1.0,
+		}
+	}
+}
+impl PathWeights {
+	/// Returns additional costs of entering every cell of the map for given unit.
+	///
+	/// Threats are added to [`ThreatMap`] with [`add_unit_vs`] given unit,
+	/// then costs are calculated with [`costs_from`](Self::costs_from).
+	/// With `enemies_cache` feature enabled, cached enemies are used too.
+	///
+	/// [`add_unit_vs`]: ThreatMap::add_unit_vs
+	pub fn costs(&self, bot: &Bot, unit: &Unit) -> Array2<f32> {
+		let mut threats = ThreatMap::new(bot.game_info.pathing_grid.dim());
+
+		#[cfg(feature = "enemies_cache")]
+		let enemies = &bot.units.cached.all;
+		#[cfg(not(feature = "enemies_cache"))]
+		let enemies = &bot.units.enemy.all;
+		for e in enemies {
+			if e.is_ready() {
+				threats.add_unit_vs(e, unit, self.margin);
+			}
+		}
+
+		let creep = bot.state.observation.raw.creep.read_lock();
+		self.costs_from(&threats, &creep, unit.is_flying, unit.is_cloaked)
+	}
+	/// Returns additional costs of entering every cell of `threats` map
+	/// for flying (`air`) or ground unit, which is `cloaked` or not.
+	///
+	/// [`margin`](Self::margin) isn't used here, it should be added to ranges of threats instead.
+	pub fn costs_from(&self, threats: &ThreatMap, creep: &PixelMap, air: bool, cloaked: bool) -> Array2<f32> {
+		let mut costs = threats.layer(air) * self.threat;
+		if self.detection > 0.0 && cloaked {
+			costs.zip_mut_with(&threats.detection, |cost, detectors| {
+				*cost += detectors * self.detection
+			});
+		}
+		if self.creep > 0.0 && !air {
+			for (cell, cost) in costs.indexed_iter_mut() {
+				// Creep is stored as empty pixels
+				if creep.get(cell).map_or(false, |pixel| pixel.is_empty()) {
+					*cost += self.creep;
+				}
+			}
+		}
+		costs
+	}
+}
+
+// Checks if unit with given radius fits in cells
+struct Passability<'a> {
+	grid: &'a PathingGrid,
+	offsets: Vec<(isize, isize)>,
+}
+impl<'a> Passability<'a> {
+	fn new(grid: &'a PathingGrid, radius: f32) -> Self {
+		let r = radius.ceil() as isize;
+		let offsets = iproduct!(-r..=r, -r..=r)
+			.filter(|(dx, dy)| ((dx * dx + dy * dy) as f32) < radius * radius)
+			.collect();
+		Self { grid, offsets }
+	}
+	fn check(&self, (x, y): (usize, usize)) -> bool {
+		self.grid.is_pathable((x, y))
+			&& self.offsets.iter().all(|(dx, dy)| {
+				let (x, y) = (x as isize + dx, y as isize + dy);
+				x >= 0 && y >= 0 && self.grid.is_pathable((x as usize, y as usize))
+			})
+	}
+	// Returns given cell if it's passable or the closest passable cell around it
+	fn closest_to(&self, (x, y): (usize, usize)) -> Option<(usize, usize)> {
+		if self.check((x, y)) {
+			return Some((x, y));
+		}
+		iproduct!(
+			-GOAL_SEARCH_RADIUS..=GOAL_SEARCH_RADIUS,
+			-GOAL_SEARCH_RADIUS..=GOAL_SEARCH_RADIUS
+		)
+		.map(|(dx, dy)| (x as isize + dx, y as isize + dy, dx * dx + dy * dy))
+		.filter(|(x, y, _)| *x >= 0 && *y >= 0 && self.check((*x as usize, *y as usize)))
+		.min_by_key(|(_, _, d)| *d)
+		.map(|(x, y, _)| (x as usize, y as usize))
+	}
+}
+
+pub(crate) fn cell_center((x, y): (usize, usize)) -> Point2 {
+	Point2::new(x as f32 + 0.5, y as f32 + 0.5)
+}
+
+impl Path {
+	// Converts cells of path to waypoints at points where direction changes
+	pub(crate) fn from_cells(cells: &[(usize, usize)], start: Point2, end: Point2, distance: f32) -> Self {
+		let direction =
+			|a: (usize, usize), b: (usize, usize)| (b.0 as isize - a.0 as isize, b.1 as isize - a.1 as isize);
+
+		let mut waypoints = vec![start];
+		for i in 1..cells.len().saturating_sub(1) {
+			if direction(cells[i - 1], cells[i]) != direction(cells[i], cells[i + 1]) {
+				waypoints.push(cell_center(cells[i]));
+			}
+		}
+		waypoints.push(end);
+		Self { waypoints, distance }
+	}
+}
+
+#[derive(PartialEq)]
+struct Node {
+	// Cost of path to the node plus heuristic
+	estimate: f32,
+	cost: f32,
+	index: usize,
+}
+impl Eq for Node {}
+impl Ord for Node {
+	// Reversed, so BinaryHeap pops the cheapest node
+	fn cmp(&self, other: &Self) -> Ordering {
+		other
+			.estimate
+			.partial_cmp(&self.estimate)
+			.unwrap_or(Ordering::Equal)
+	}
+}
+impl PartialOrd for Node {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+const NEIGHBORS: [(isize, isize, f32); 8] = [
+	(1, 0, 1.0),
+	(-1, 0, 1.0),
+	(0, 1, 1.0),
+	(0, -1, 1.0),
+	(1, 1, SQRT_2),
+	(1, -1, SQRT_2),
+	(-1, 1, SQRT_2),
+	(-1, -1, SQRT_2),
+];
+
+fn octile_distance(a: (usize, usize), b: (usize, usize)) -> f32 {
+	let dx = (a.0 as f32 - b.0 as f32).abs();
+	let dy = (a.1 as f32 - b.1 as f32).abs();
+	dx.max(dy) + (SQRT_2 - 1.0) * dx.min(dy)
+}
+
+// Cells which can be reached from given cell in one move with lengths of these moves,
+// diagonal moves are allowed only if both adjacent cells are passable
+fn moves<'a, P>(
+	(width, height): (usize, usize),
+	(x, y): (usize, usize),
+	passable: &'a P,
+) -> impl Iterator<Item = ((usize, usize), f32)> + 'a
+where
+	P: Fn((usize, usize)) -> bool,
+{
+	let (x, y) = (x as isize, y as isize);
+	let is_passable = move |x: isize, y: isize| {
+		x >= 0
+			&& y >= 0 && (x as usize) < width
+			&& (y as usize) < height
+			&& passable((x as usize, y as usize))
+	};
+	NEIGHBORS.iter().filter_map(move |&(dx, dy, step)| {
+		let diagonal = dx != 0 && dy != 0;
+		if !is_passable(x + dx, y + dy) || (diagonal && !(is_passable(x + dx, y) && is_passable(x, y + dy))) {
+			return None;
+		}
+		Some((((x + dx) as usize, (y + dy) as usize), step))
+	})
+}
+
+// A* on 8-connected grid.
+// `extra_cost` is added to cost of entering a cell.
+// Start cell is always considered passable, since unit is already there.
+// Returns cells of the path including start and goal, and it's cost.
+pub(crate) fn astar<P, C>(
+	(width, height): (usize, usize),
+	start: (usize, usize),
+	goal: (usize, usize),
+	passable: P,
+	extra_cost: C,
+) -> Option<(Vec<(usize, usize)>, f32)>
+where
+	P: Fn((usize, usize)) -> bool,
+	C: Fn((usize, usize)) -> f32,
+{
+	let index = |(x, y): (usize, usize)| y * width + x;
+	let cell = |i: usize| (i % width, i / width);
+
+	let mut costs = vec![f32::INFINITY; width * height];
+	let mut came_from = vec![usize::MAX; width * height];
+	let mut heap = BinaryHeap::new();
+
+	costs[index(start)] = 0.0;
+	heap.push(Node {
+		estimate: octile_distance(start, goal),
+		cost: 0.0,
+		index: index(start),
+	});
+
+	while let Some(Node {
+		cost, index: current, ..
+	}) = heap.pop()
+	{
+		// Node is outdated, cheaper path to it was found
+		if cost > costs[current] {
+			continue;
+		}
+		let pos = cell(current);
+		if pos == goal {
+			let mut path = vec![goal];
+			let mut i = current;
+			while came_from[i] != usize::MAX {
+				i = came_from[i];
+				path.push(cell(i));
+			}
+			path.reverse();
+			return Some((path, cost));
+		}
+
+		for (next, step) in moves((width, height), pos, &passable) {
+			let next_cost = cost + step + extra_cost(next);
+			let i = index(next);
+			if next_cost < costs[i] {
+				costs[i] = next_cost;
+				came_from[i] = current;
+				heap.push(Node {
+					estimate: next_cost + octile_distance(next, goal),
+					cost: next_cost,
+					index: i,
+				});
+			}
+		}
+	}
+	None
+}
+
+// Dijkstra on 8-connected grid, returns lengths of paths from start to all cells (infinity if unreachable)
+fn dijkstra<P>((width, height): (usize, usize), start: (usize, usize), passable: P) -> Array2<f32>
+where
+	P: Fn((usize, usize)) -> bool,
+{
+	let mut costs = Array2::from_elem((width, height), f32::INFINITY);
+	let mut heap = BinaryHeap::new();
+
+	costs[start] = 0.0;
+	heap.push(Node {
+		estimate: 0.0,
+		cost: 0.0,
+		index: start.1 * width + start.0,
+	});
+
+	while let Some(Node { cost, index, .. }) = heap.pop() {
+		let pos = (index % width, index / width);
+		// Node is outdated, cheaper path to it was found
+		if cost > costs[pos] {
+			continue;
+		}
+		for (next, step) in moves((width, height), pos, &passable) {
+			let next_cost = cost + step;
+			if next_cost < costs[next] {
+				costs[next] = next_cost;
+				heap.push(Node {
+					estimate: next_cost,
+					cost: next_cost,
+					index: next.1 * width + next.0,
+				});
+			}
+		}
+	}
+	costs
+}
@@ -1,3 +1,6 @@
+//! Locating SC2 installation, it's versions and maps.
+
+use crate::client::{SC2Error, SC2Result};
 use dirs::home_dir;
 use regex::Regex;
 use std::{env, fs, path::Path};
@@ -17,93 +20,193 @@ const DEFAULT_SC2_PATH: &str = {
 	}
 };
 
-pub fn get_path_to_sc2() -> String {
+/// Returns path to SC2 installation, taken from `SC2PATH` environment variable or default location.
+pub fn get_path_to_sc2() -> SC2Result<String> {
 	match env::var_os("SC2PATH") {
-		Some(path) => path.to_str().unwrap().to_string(),
+		Some(path) => path
+			.into_string()
+			.map_err(|path| SC2Error::NotInstalled(format!("SC2PATH is not valid unicode: {:?}", path))),
 		None => {
 			if cfg!(target_os = "windows") {
-				let file = fs::read_to_string(format!(
-					"{}/Documents/StarCraft II/ExecuteInfo.txt",
-					home_dir().unwrap().to_str().unwrap(),
-				))
-				.expect("Can't read ExecuteInfo.txt");
-				let re = Regex::new(r"= (.*)\\Versions").unwrap().captures(&file).unwrap();
-
-				let path = Path::new(&re[1]);
-				if path.exists() {
-					return path.to_str().unwrap().replace("\\", "/");
+				let home = home_dir()
+					.ok_or_else(|| SC2Error::NotInstalled("Can't find home directory".to_string()))?;
+				let file = fs::read_to_string(home.join("Documents/StarCraft II/ExecuteInfo.txt"))
+					.map_err(|e| SC2Error::NotInstalled(format!("Can't read ExecuteInfo.txt: {}", e)))?;
+
+				if let Some(re) = Regex::new(r"= (.*)\\Versions").unwrap().captures(&file) {
+					let path = Path::new(&re[1]);
+					if path.exists() {
+						return Ok(path.to_string_lossy().replace("\\", "/"));
+					}
 				}
 			}
-			DEFAULT_SC2_PATH.to_string()
+			Ok(DEFAULT_SC2_PATH.to_string())
 		}
 	}
 }
 
-pub fn get_map_path(sc2_path: &str, map_name: &str) -> String {
-	let maps = {
-		let path = format!("{}/Maps", sc2_path);
-		if fs::metadata(&path).is_ok() {
-			path
-		} else {
-			let path = format!("{}/maps", sc2_path);
-			if fs::metadata(&path).is_ok() {
-				path
-			} else {
-				panic!("Can't find maps folder in: {}", sc2_path);
+const MAP_EXTENSION: &str = ".SC2Map";
+
+fn get_maps_folder(sc2_path: &str) -> SC2Result<String> {
+	["Maps", "maps"]
+		.iter()
+		.map(|folder| format!("{}/{}", sc2_path, folder))
+		.find(|path| fs::metadata(path).is_ok())
+		.ok_or_else(|| SC2Error::NotInstalled(format!("Can't find maps folder in: {}", sc2_path)))
+}
+
+/// Returns full path to the map.
+///
+/// Map can be given as path relative to maps folder (e.g. `Ladder2019Season3/AcropolisLE`)
+/// or just as name of the map (e.g. `AcropolisLE`), which is searched in all sub-folders.
+/// `.SC2Map` extension is optional.
+pub fn get_map_path(sc2_path: &str, map: &str) -> SC2Result<String> {
+	let maps = get_maps_folder(sc2_path)?;
+	let map = map.trim_end_matches(MAP_EXTENSION);
+
+	let map_path = format!("{}/{}{}", maps, map, MAP_EXTENSION);
+	if fs::metadata(&map_path).is_ok() {
+		return Ok(map_path);
+	}
+	// Only bare names are searched, sub-paths should be exact
+	if !map.contains(&['/', '\\'][..]) {
+		if let Some(found) = get_installed_maps(sc2_path)?.into_iter().find(|m| m.name == map) {
+			return Ok(format!("{}/{}", maps, found.path));
+		}
+	}
+	Err(SC2Error::MapNotFound(map_path))
+}
+
+/// Map found in maps folder of SC2 installation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledMap {
+	/// Name of the map without extension, e.g. `AcropolisLE`.
+	pub name: String,
+	/// Path relative to maps folder, e.g. `Ladder2019Season3/AcropolisLE.SC2Map`.
+	pub path: String,
+}
+
+/// Returns all maps in maps folder of SC2 installation including sub-folders, sorted by path.
+pub fn get_installed_maps(sc2_path: &str) -> SC2Result<Vec<InstalledMap>> {
+	fn collect(dir: &Path, prefix: &str, maps: &mut Vec<InstalledMap>) -> SC2Result<()> {
+		for entry in dir.read_dir()? {
+			let entry = entry?;
+			let file_name = entry.file_name();
+			let file_name = match file_name.to_str() {
+				Some(name) => name,
+				None => continue,
+			};
+			let path = format!("{}{}", prefix, file_name);
+
+			// Unpacked maps are folders with extension too
+			if file_name.ends_with(MAP_EXTENSION) {
+				maps.push(InstalledMap {
+					name: file_name.trim_end_matches(MAP_EXTENSION).to_string(),
+					path,
+				});
+			} else if entry.file_type()?.is_dir() {
+				collect(&entry.path(), &format!("{}/", path), maps)?;
 			}
 		}
-	};
-	let map_path = format!("{}/{}.SC2Map", maps, map_name);
-	fs::metadata(&map_path).unwrap_or_else(|_| panic!("Map doesn't exists: {}", map_path));
-	map_path
+		Ok(())
+	}
+
+	let mut maps = Vec::new();
+	collect(Path::new(&get_maps_folder(sc2_path)?), "", &mut maps)?;
+	maps.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+	Ok(maps)
 }
 
-pub fn get_latest_base_version(sc2_path: &str) -> u32 {
-	Path::new(&format!("{}/Versions", sc2_path))
+/// SC2 version found in `Versions` folder of installation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledVersion {
+	/// Number in name of `Base*` folder.
+	pub base_version: u32,
+	/// Name of the version (e.g. `4.10.4`) or `None` if version is unknown.
+	pub name: Option<&'static str>,
+	/// Data hash of the version or `None` if version is unknown.
+	pub data_hash: Option<&'static str>,
+}
+
+/// Returns all SC2 versions installed, sorted from the oldest to the latest.
+pub fn get_installed_versions(sc2_path: &str) -> SC2Result<Vec<InstalledVersion>> {
+	let versions = format!("{}/Versions", sc2_path);
+	let mut installed = Path::new(&versions)
 		.read_dir()
-		.expect("Can't read `Versions` folder")
+		.map_err(|e| SC2Error::NotInstalled(format!("Can't read `{}` folder: {}", versions, e)))?
 		.filter_map(|dir| {
-			let dir = dir.unwrap();
+			let dir = dir.ok()?;
 			dir.file_type().ok().filter(|ftype| ftype.is_dir()).and(
 				dir.file_name()
 					.to_str()
 					.filter(|name| name.starts_with("Base"))
-					.map(|name| name[4..].parse::<u32>().unwrap()),
+					.and_then(|name| name[4..].parse::<u32>().ok()),
 			)
 		})
-		.max()
-		.unwrap()
+		.map(|base_version| {
+			let info = VERSIONS.iter().find(|(_, base, _)| *base == base_version);
+			InstalledVersion {
+				base_version,
+				name: info.map(|(names, _, _)| names[0]),
+				data_hash: info.map(|(_, _, data_hash)| *data_hash),
+			}
+		})
+		.collect::<Vec<_>>();
+	installed.sort_unstable_by_key(|v| v.base_version);
+	Ok(installed)
 }
 
-// Returns (Base version, Data hash)
-pub fn get_version_info(version: &str) -> (u32, &str) {
-	match version {
-		"5.0.2" => (81102, "DC0A1182FB4ABBE8E29E3EC13CF46F68"),
-		"5.0.1" => (81009, "0D28678BC32E7F67A238F19CD3E0A2CE"),
-		"5.0.0" | "5.0" | "5" => (80949, "9AE39C332883B8BF6AA190286183ED72"),
-		"4.12.1" => (80188, "44DED5AED024D23177C742FC227C615A"),
-		"4.12.0" | "4.12" => (79998, "B47567DEE5DC23373BFF57194538DFD3"),
-		"4.11.4" => (78285, "69493AFAB5C7B45DDB2F3442FD60F0CF"),
-		"4.11.3" => (77661, "A15B8E4247434B020086354F39856C51"),
-		"4.11.2" => (77535, "FC43E0897FCC93E4632AC57CBC5A2137"),
-		"4.11.1" => (77474, "F92D1127A291722120AC816F09B2E583"),
-		"4.11.0" | "4.11" => (77379, "70E774E722A58287EF37D487605CD384"),
-		"4.10.4" => (76811, "FF9FA4EACEC5F06DEB27BD297D73ED67"),
-		"4.10.3" => (76114, "CDB276D311F707C29BA664B7754A7293"),
-		"4.10.2" => (76052, "D0F1A68AA88BA90369A84CD1439AA1C3"),
-		"4.10.1" => (75800, "DDFFF9EC4A171459A4F371C6CC189554"),
-		"4.10.0" | "4.10" => (75689, "B89B5D6FA7CBF6452E721311BFBC6CB2"),
-		"4.9.3" => (75025, "C305368C63621480462F8F516FB64374"),
-		"4.9.2" => (74741, "614480EF79264B5BD084E57F912172FF"),
-		"4.9.1" => (74456, "218CB2271D4E2FA083470D30B1A05F02"),
-		"4.9.0" | "4.9" => (74071, "70C74A2DCA8A0D8E7AE8647CAC68ACCA"),
-		"4.8.6" => (73620, "AA18FEAD6573C79EF707DF44ABF1BE61"),
-		"4.8.5" => (73559, "B2465E73AED597C74D0844112D582595"),
-		"4.8.4" => (73286, "CD040C0675FD986ED37A4CA3C88C8EB5"),
-		"4.8.3" => (72282, "0F14399BBD0BA528355FF4A8211F845B"),
-		"4.8.2" => (71663, "FE90C92716FC6F8F04B74268EC369FA5"),
-		"4.8.1" => (71523, "FCAF3F050B7C0CC7ADCF551B61B9B91E"),
-		"4.8.0" | "4.8" => (71061, "760581629FC458A1937A05ED8388725B"),
-		v => panic!("Can't find info about version: {:?}", v),
-	}
+/// Returns base version of the latest SC2 version installed.
+pub fn get_latest_base_version(sc2_path: &str) -> SC2Result<u32> {
+	get_installed_versions(sc2_path)?
+		.last()
+		.map(|v| v.base_version)
+		.ok_or_else(|| SC2Error::NotInstalled(format!("No SC2 versions found in: {}/Versions", sc2_path)))
+}
+
+// (Version names, Base version, Data hash)
+const VERSIONS: &[(&[&str], u32, &str)] = &[
+	(&["5.0.2"], 81102, "DC0A1182FB4ABBE8E29E3EC13CF46F68"),
+	(&["5.0.1"], 81009, "0D28678BC32E7F67A238F19CD3E0A2CE"),
+	(&["5.0.0", "5.0", "5"], 80949, "9AE39C332883B8BF6AA190286183ED72"),
+	(&["4.12.1"], 80188, "44DED5AED024D23177C742FC227C615A"),
+	(&["4.12.0", "4.12"], 79998, "B47567DEE5DC23373BFF57194538DFD3"),
+	(&["4.11.4"], 78285, "69493AFAB5C7B45DDB2F3442FD60F0CF"),
+	(&["4.11.3"], 77661, "A15B8E4247434B020086354F39856C51"),
+	(&["4.11.2"], 77535, "FC43E0897FCC93E4632AC57CBC5A2137"),
+	(&["4.11.1"], 77474, "F92D1127A291722120AC816F09B2E583"),
+	(&["4.11.0", "4.11"], 77379, "70E774E722A58287EF37D487605CD384"),
+	(&["4.10.4"], 76811, "FF9FA4EACEC5F06DEB27BD297D73ED67"),
+	(&["4.10.3"], 76114, "CDB276D311F707C29BA664B7754A7293"),
+	(&["4.10.2"], 76052, "D0F1A68AA88BA90369A84CD1439AA1C3"),
+	(&["4.10.1"], 75800, "DDFFF9EC4A171459A4F371C6CC189554"),
+	(&["4.10.0", "4.10"], 75689, "B89B5D6FA7CBF6452E721311BFBC6CB2"),
+	(&["4.9.3"], 75025, "C305368C63621480462F8F516FB64374"),
+	(&["4.9.2"], 74741, "614480EF79264B5BD084E57F912172FF"),
+	(&["4.9.1"], 74456, "218CB2271D4E2FA083470D30B1A05F02"),
+	(&["4.9.0", "4.9"], 74071, "70C74A2DCA8A0D8E7AE8647CAC68ACCA"),
+	(&["4.8.6"], 73620, "AA18FEAD6573C79EF707DF44ABF1BE61"),
+	(&["4.8.5"], 73559, "B2465E73AED597C74D0844112D582595"),
+	(&["4.8.4"], 73286, "CD040C0675FD986ED37A4CA3C88C8EB5"),
+	(&["4.8.3"], 72282, "0F14399BBD0BA528355FF4A8211F845B"),
+	(&["4.8.2"], 71663, "FE90C92716FC6F8F04B74268EC369FA5"),
+	(&["4.8.1"], 71523, "FCAF3F050B7C0CC7ADCF551B61B9B91E"),
+	(&["4.8.0", "4.8"], 71061, "760581629FC458A1937A05ED8388725B"),
+];
+
+/// Returns base version and data hash of SC2 version with given name (e.g. `4.10` or `4.10.4`).
+pub fn get_version_info(version: &str) -> SC2Result<(u32, &'static str)> {
+	VERSIONS
+		.iter()
+		.find(|(names, _, _)| names.contains(&version))
+		.map(|(_, base_version, data_hash)| (*base_version, *data_hash))
+		.ok_or_else(|| SC2Error::UnknownVersion(version.to_string()))
+}
+
+/// Returns full name of version with given base build, e.g. `81102` -> `5.0.2`.
+pub fn get_version_by_build(build: u32) -> Option<&'static str> {
+	VERSIONS
+		.iter()
+		.find(|(_, base_version, _)| *base_version == build)
+		.map(|(names, _, _)| names[0])
 }
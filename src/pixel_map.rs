@@ -2,7 +2,7 @@
 #![allow(missing_docs)]
 
 use crate::{geometry::Point2, FromProto};
-use ndarray::Array2;
+use ndarray::{Array2, Array3};
 use num_traits::FromPrimitive;
 use sc2_proto::common::ImageData;
 use std::{
@@ -16,6 +16,10 @@ pub type PixelMap = Array2<Pixel>;
 pub type ByteMap = Array2<u8>;
 /// 2-Dimensional Array that represents visibility.
 pub type VisibilityMap = Array2<Visibility>;
+/// 2-Dimensional Array of 32-bit integers (e.g. unit types or hit points in feature layers).
+pub type IntMap = Array2<i32>;
+/// Image with RGB channels, indexed by `(x, y, channel)`.
+pub type RgbMap = Array3<u8>;
 
 impl<T> Index<Point2> for Array2<T> {
 	type Output = T;
@@ -65,6 +69,31 @@ impl FromProto<&ImageData> for ByteMap {
 		.reversed_axes()
 	}
 }
+impl FromProto<&ImageData> for IntMap {
+	fn from_proto(grid: &ImageData) -> Self {
+		let size = grid.get_size();
+		Array2::from_shape_vec(
+			(size.get_y() as usize, size.get_x() as usize),
+			grid.get_data()
+				.chunks_exact(4)
+				.map(|n| i32::from_le_bytes([n[0], n[1], n[2], n[3]]))
+				.collect(),
+		)
+		.expect("Can't create IntMap")
+		.reversed_axes()
+	}
+}
+impl FromProto<&ImageData> for RgbMap {
+	fn from_proto(grid: &ImageData) -> Self {
+		let size = grid.get_size();
+		Array3::from_shape_vec(
+			(size.get_y() as usize, size.get_x() as usize, 3),
+			grid.get_data().to_vec(),
+		)
+		.expect("Can't create RgbMap")
+		.permuted_axes([1, 0, 2])
+	}
+}
 impl FromProto<&ImageData> for VisibilityMap {
 	fn from_proto(grid: &ImageData) -> Self {
 		let size = grid.get_size();
@@ -202,6 +202,8 @@ impl IntoProto<ProtoPlayerType> for PlayerType {
 }
 
 /// Computer opponent configuration used in [`run_vs_computer`](crate::client::run_vs_computer).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Copy, Clone)]
 pub struct Computer {
 	pub race: Race,
 	pub difficulty: Difficulty,
@@ -0,0 +1,108 @@
+//! Launched SC2 clients: detection of their exit, shutdown with timeout and capture of their output.
+
+use crate::{
+	api::API,
+	client::{Launcher, SC2Error, SC2Result},
+};
+use sc2_proto::sc2api::Request;
+use std::{
+	fs::{self, OpenOptions},
+	path::Path,
+	process::{Child, Command, ExitStatus},
+	thread,
+	time::{Duration, Instant},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+// Launched SC2 client, which is shut down and reaped when dropped
+pub(crate) struct SC2Process {
+	child: Child,
+	shutdown_timeout: Duration,
+	exit_status: Option<ExitStatus>,
+}
+impl SC2Process {
+	pub(crate) fn spawn(mut command: Command, port: i32, launcher: &Launcher) -> SC2Result<Self> {
+		if let Some(dir) = &launcher.log_dir {
+			fs::create_dir_all(dir)?;
+			// Appending, so logs of crashed client aren't overwritten by the relaunched one
+			let log = OpenOptions::new()
+				.create(true)
+				.append(true)
+				.open(Path::new(dir).join(format!("SC2_{}.log", port)))?;
+			command.stdout(log.try_clone()?).stderr(log);
+		}
+		Ok(Self {
+			child: command.spawn().map_err(SC2Error::Launch)?,
+			shutdown_timeout: launcher.shutdown_timeout,
+			exit_status: None,
+		})
+	}
+
+	// Returns exit status if process has exited
+	pub(crate) fn exit_status(&mut self) -> Option<ExitStatus> {
+		if self.exit_status.is_none() {
+			match self.child.try_wait() {
+				Ok(status) => self.exit_status = status,
+				Err(e) => error!("Can't get status of SC2 process: {}", e),
+			}
+		}
+		self.exit_status
+	}
+
+	pub(crate) fn check_running(&mut self) -> SC2Result<()> {
+		match self.exit_status() {
+			Some(status) => Err(SC2Error::ProcessExited(status)),
+			None => Ok(()),
+		}
+	}
+
+	// Waits for process to exit after `QuitGame` request and kills it if it didn't in time
+	pub(crate) fn shutdown(&mut self) {
+		let start = Instant::now();
+		while self.exit_status().is_none() {
+			if start.elapsed() >= self.shutdown_timeout {
+				warn!(
+					"SC2 process didn't exit in {:?}, killing it",
+					self.shutdown_timeout
+				);
+				if let Err(e) = self.child.kill() {
+					error!("Can't kill SC2 process: {}", e);
+				}
+				// Waiting is required to not leave zombie process behind
+				match self.child.wait() {
+					Ok(status) => self.exit_status = Some(status),
+					Err(e) => error!("Can't wait for SC2 process: {}", e),
+				}
+				break;
+			}
+			thread::sleep(POLL_INTERVAL);
+		}
+	}
+}
+impl Drop for SC2Process {
+	fn drop(&mut self) {
+		self.shutdown();
+	}
+}
+
+// Leaves the game and quits SC2 client, then waits for its process to exit
+pub(crate) fn close_client(api: Option<&mut API>, process: Option<&mut SC2Process>) {
+	if let Some(api) = api {
+		let mut req = Request::new();
+		req.mut_leave_game();
+		if let Err(e) = api.send_request(req) {
+			error!("Request LeaveGame failed: {}", e);
+		}
+
+		let mut req = Request::new();
+		req.mut_quit();
+		if let Err(e) = api.send_request(req) {
+			error!("Request QuitGame failed: {}", e);
+		}
+	}
+
+	if let Some(process) = process {
+		process.shutdown();
+	}
+}
@@ -0,0 +1,128 @@
+//! Recording of raw API protocol stream and it's playback.
+//!
+//! Recording stores every `Request`/`Response` pair sent through [`API`](crate::api::API) in a file.
+//! Such file can be played back later with [`run_recorded_game`](crate::client::run_recorded_game),
+//! which feeds recorded responses to the bot instead of SC2 client.
+//! That allows to reproduce any game deterministically (as long as bot itself is deterministic).
+//!
+//! File is a sequence of length-prefixed protobuf messages:
+//! `[u32 LE length][Request][u32 LE length][Response]` repeated for every pair.
+
+use crate::client::{SC2Error, SC2Result};
+use protobuf::Message;
+use sc2_proto::sc2api::{Request, Response};
+use std::{
+	collections::VecDeque,
+	fs::File,
+	io::{BufReader, BufWriter, ErrorKind, Read, Write},
+	mem::discriminant,
+};
+
+/// Writes `Request`/`Response` pairs to a file.
+pub struct Recorder {
+	file: BufWriter<File>,
+}
+impl Recorder {
+	/// Creates new recording file in given path.
+	pub fn create(path: &str) -> SC2Result<Self> {
+		Ok(Self {
+			file: BufWriter::new(File::create(path)?),
+		})
+	}
+	/// Appends given pair to the recording.
+	pub fn write(&mut self, req: &Request, res: &Response) -> SC2Result<()> {
+		write_message(&mut self.file, req)?;
+		write_message(&mut self.file, res)?;
+		self.file.flush()?;
+		Ok(())
+	}
+}
+
+fn write_message<M: Message>(file: &mut BufWriter<File>, msg: &M) -> SC2Result<()> {
+	let bytes = msg.write_to_bytes()?;
+	file.write_all(&(bytes.len() as u32).to_le_bytes())?;
+	file.write_all(&bytes)?;
+	Ok(())
+}
+
+fn read_message<M: Message>(file: &mut BufReader<File>) -> SC2Result<Option<M>> {
+	let mut len = [0; 4];
+	if let Err(e) = file.read_exact(&mut len) {
+		return if e.kind() == ErrorKind::UnexpectedEof {
+			Ok(None)
+		} else {
+			Err(e.into())
+		};
+	}
+	let mut bytes = vec![0; u32::from_le_bytes(len) as usize];
+	file.read_exact(&mut bytes)?;
+
+	let mut msg = M::new();
+	msg.merge_from_bytes(&bytes)?;
+	Ok(Some(msg))
+}
+
+/// Plays back recorded `Request`/`Response` pairs.
+///
+/// Recording is started from the first `RequestJoinGame`,
+/// everything sent before (e.g. `RequestCreateGame`) is skipped.
+pub struct Playback {
+	pairs: VecDeque<(Request, Response)>,
+}
+impl Playback {
+	/// Loads recording from given path.
+	pub fn open(path: &str) -> SC2Result<Self> {
+		let mut file = BufReader::new(File::open(path)?);
+		let mut pairs = VecDeque::new();
+		while let Some(req) = read_message::<Request>(&mut file)? {
+			let res = read_message::<Response>(&mut file)?
+				.ok_or_else(|| SC2Error::Playback("Recording is truncated".to_string()))?;
+			pairs.push_back((req, res));
+		}
+
+		while let Some((req, _)) = pairs.front() {
+			if req.has_join_game() {
+				break;
+			}
+			pairs.pop_front();
+		}
+
+		Ok(Self { pairs })
+	}
+	/// Returns count of pairs left to play back.
+	pub fn len(&self) -> usize {
+		self.pairs.len()
+	}
+	/// Returns `true` if all recorded pairs were played back.
+	pub fn is_empty(&self) -> bool {
+		self.pairs.is_empty()
+	}
+	/// Returns recorded response to given request.
+	///
+	/// Request must be of the same kind as recorded one,
+	/// otherwise bot is considered diverged from recording and [`SC2Error::Playback`] returned.
+	pub fn respond(&mut self, req: &Request) -> SC2Result<Response> {
+		let kind = req.request.as_ref().map(discriminant);
+		let expected = self
+			.pairs
+			.front()
+			.map(|(recorded, _)| recorded.request.as_ref().map(discriminant) == kind);
+
+		match expected {
+			Some(true) => Ok(self.pairs.pop_front().unwrap().1),
+			// Recording made in realtime mode doesn't contain steps
+			_ if req.has_step() => {
+				let mut res = Response::new();
+				res.mut_step();
+				Ok(res)
+			}
+			// Bot is closed after recording is over
+			None if req.has_leave_game() || req.has_quit() => Ok(Response::new()),
+			Some(false) => Err(SC2Error::Playback(format!(
+				"Bot diverged from recording: expected {:?}, got {:?}",
+				self.pairs[0].0.request, req.request
+			))),
+			None => Err(SC2Error::Playback("Recording is over".to_string())),
+		}
+	}
+}
@@ -0,0 +1,410 @@
+//! Decomposition of the map into regions connected by chokes.
+//!
+//! Pathable area is flooded from points furthest from obstacles (like water rising from the lowest points),
+//! regions are formed where floods from different peaks meet at narrow places,
+//! and cells where they meet become chokes.
+//! Chokes are found regardless of terrain height, so ramps are chokes too,
+//! but not every choke is a ramp.
+
+use crate::{
+	distance::Distance,
+	geometry::Point2,
+	pathfinding::{cell_center, PathingGrid},
+	ramp::Ramp,
+	utils::dbscan,
+};
+use ndarray::Array2;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::f32::consts::SQRT_2;
+
+type Pos = (usize, usize);
+
+// Floods are merged into one region if one of them has less cells than this
+const MIN_REGION_SIZE: usize = 80;
+// or if it's peak is closer to obstacles than this
+const MIN_REGION_ALTITUDE: f32 = 3.0;
+// or if floods meet at place, which isn't much narrower than one of peaks
+const MERGE_ALTITUDE_RATIO: f32 = 0.9;
+
+/// Structured collection of regions and chokes.
+/// Returned by [`regions`](crate::bot::Bot::regions) method of bot.
+#[derive(Default, Clone)]
+pub struct Regions {
+	/// All regions on the map, index in this vector is id of region.
+	pub all: Vec<Region>,
+	/// All chokes on the map, index in this vector is id of choke.
+	pub chokes: Vec<Choke>,
+	labels: Array2<Option<usize>>,
+}
+
+/// Area of the map, surrounded by obstacles and chokes.
+#[derive(Debug, Clone)]
+pub struct Region {
+	/// Id of region in [`Regions`].
+	pub id: usize,
+	/// All cells which belong to this region.
+	pub points: Vec<Pos>,
+	/// Point of region, furthest from obstacles.
+	pub center: Point2,
+	/// Ids of chokes leading out of this region.
+	pub chokes: Vec<usize>,
+	/// Ids of regions connected to this one by chokes.
+	pub neighbors: Vec<usize>,
+	/// Indices of expansions (in [`expansions`](crate::bot::Bot::expansions) of bot)
+	/// located in this region.
+	pub expansions: Vec<usize>,
+}
+
+/// Narrow place between 2 regions.
+#[derive(Debug, Clone)]
+pub struct Choke {
+	/// Id of choke in [`Regions`].
+	pub id: usize,
+	/// Ids of regions connected by this choke.
+	pub regions: (usize, usize),
+	/// Cells of the line across choke, which separates regions.
+	pub points: Vec<Pos>,
+	/// Ends of the line across choke, next to obstacles.
+	pub sides: [Point2; 2],
+	/// Point of choke, furthest from obstacles.
+	pub center: Point2,
+	/// Width of choke.
+	pub width: f32,
+	/// Index of ramp (in [`ramps.all`](crate::ramp::Ramps::all) of bot) if this choke is ramp.
+	pub ramp: Option<usize>,
+}
+impl Choke {
+	fn new(id: usize, regions: (usize, usize), points: Vec<Pos>, altitude: &Array2<f32>) -> Self {
+		let center = *points
+			.iter()
+			.max_by(|p1, p2| altitude[**p1].partial_cmp(&altitude[**p2]).unwrap())
+			.unwrap();
+		let (side1, side2) = iproduct!(&points, &points)
+			.max_by(|(a1, b1), (a2, b2)| {
+				cell_center(**a1)
+					.distance_squared(cell_center(**b1))
+					.partial_cmp(&cell_center(**a2).distance_squared(cell_center(**b2)))
+					.unwrap()
+			})
+			.unwrap();
+		let sides = [cell_center(*side1), cell_center(*side2)];
+		Self {
+			id,
+			regions,
+			width: sides[0].distance(sides[1]) + 1.0,
+			sides,
+			center: cell_center(center),
+			points,
+			ramp: None,
+		}
+	}
+
+	/// Returns id of region on the other side of choke,
+	/// or `None` if choke doesn't lead out of given region.
+	pub fn other_region(&self, region: usize) -> Option<usize> {
+		match self.regions {
+			(a, b) if a == region => Some(b),
+			(a, b) if b == region => Some(a),
+			_ => None,
+		}
+	}
+}
+
+impl Regions {
+	/// Splits pathable area of given grid into regions and finds chokes between them.
+	///
+	/// `expansions` and `ramps` are used to tie them with regions and chokes,
+	/// pass empty slices if it's not needed.
+	pub fn new(grid: &PathingGrid, expansions: &[(Point2, Point2)], ramps: &[Ramp]) -> Self {
+		let altitude = altitude(grid);
+		let mut floods = Floods::default();
+		let mut labels = Array2::from_elem(grid.size(), None);
+		// Cells where floods met, with ids of these floods
+		let mut frontier = Vec::new();
+
+		let mut cells = altitude
+			.indexed_iter()
+			.filter(|(_, alt)| **alt > 0.0)
+			.map(|(pos, alt)| (pos, *alt))
+			.collect::<Vec<_>>();
+		cells.sort_by(|(_, a1), (_, a2)| a2.partial_cmp(a1).unwrap());
+
+		for (pos, alt) in cells {
+			let mut around = neighbors4(pos, grid.size())
+				.filter_map(|n| labels[n])
+				.map(|id| floods.find(id));
+			let first = around.next();
+			let second = first.and_then(|first| around.find(|id| *id != first));
+
+			let id = match (first, second) {
+				(None, _) => floods.add(pos, alt),
+				(Some(id), None) => id,
+				(Some(a), Some(b)) => {
+					let (big, small) = if floods.size[a] >= floods.size[b] {
+						(a, b)
+					} else {
+						(b, a)
+					};
+					if floods.size[small] < MIN_REGION_SIZE
+						|| floods.peak[small] < MIN_REGION_ALTITUDE
+						|| alt >= floods.peak[big] * MERGE_ALTITUDE_RATIO
+						|| alt >= floods.peak[small] * MERGE_ALTITUDE_RATIO
+					{
+						floods.merge(big, small)
+					} else {
+						frontier.push((pos, big, small));
+						big
+					}
+				}
+			};
+			floods.size[id] += 1;
+			labels[pos] = Some(id);
+		}
+
+		// Giving regions sequential ids
+		let mut region_ids = FxHashMap::default();
+		let mut all = Vec::new();
+		for (pos, label) in labels.indexed_iter_mut() {
+			if let Some(flood) = label {
+				let root = floods.find(*flood);
+				let id = *region_ids.entry(root).or_insert_with(|| {
+					all.push(Region {
+						id: all.len(),
+						points: Vec::new(),
+						center: cell_center(floods.top[root]),
+						chokes: Vec::new(),
+						neighbors: Vec::new(),
+						expansions: Vec::new(),
+					});
+					all.len() - 1
+				});
+				all[id].points.push(pos);
+				*label = Some(id);
+			}
+		}
+
+		// Grouping frontier cells by regions they separate
+		let mut borders = FxHashMap::<(usize, usize), FxHashSet<Pos>>::default();
+		for (pos, a, b) in frontier {
+			let (a, b) = (region_ids[&floods.find(a)], region_ids[&floods.find(b)]);
+			if a != b {
+				borders.entry((a.min(b), a.max(b))).or_default().insert(pos);
+			}
+		}
+		let mut borders = borders.into_iter().collect::<Vec<_>>();
+		borders.sort_unstable_by_key(|(regions, _)| *regions);
+
+		// Splitting borders into separate chokes
+		let mut chokes = Vec::new();
+		for (regions, points) in borders {
+			let lines = dbscan(
+				&points,
+				|&(x, y)| {
+					iproduct!(x.saturating_sub(2)..=x + 2, y.saturating_sub(2)..=y + 2)
+						.filter(|n| points.contains(n))
+						.collect()
+				},
+				1,
+			)
+			.0;
+			for mut line in lines {
+				line.sort_unstable();
+				chokes.push(Choke::new(chokes.len(), regions, line, &altitude));
+			}
+		}
+
+		for choke in &mut chokes {
+			choke.ramp = ramps.iter().position(|ramp| {
+				let ramp_points = ramp.points.iter().collect::<FxHashSet<_>>();
+				choke.points.iter().any(|p| ramp_points.contains(p))
+			});
+
+			let (a, b) = choke.regions;
+			all[a].chokes.push(choke.id);
+			all[b].chokes.push(choke.id);
+			for &(region, other) in &[(a, b), (b, a)] {
+				if !all[region].neighbors.contains(&other) {
+					all[region].neighbors.push(other);
+				}
+			}
+		}
+
+		let mut regions = Self { all, chokes, labels };
+		for (i, (location, _)) in expansions.iter().enumerate() {
+			if let Some(id) = regions.region_id(*location) {
+				regions.all[id].expansions.push(i);
+			}
+		}
+		regions
+	}
+
+	/// Returns id of region which contains given point.
+	pub fn region_id(&self, pos: Point2) -> Option<usize> {
+		if pos.x < 0.0 || pos.y < 0.0 {
+			return None;
+		}
+		self.labels
+			.get((pos.x as usize, pos.y as usize))
+			.copied()
+			.flatten()
+	}
+	/// Returns region which contains given point.
+	pub fn region(&self, pos: Point2) -> Option<&Region> {
+		self.region_id(pos).map(|id| &self.all[id])
+	}
+	/// Returns all chokes between 2 given regions.
+	pub fn chokes_between(&self, a: usize, b: usize) -> impl Iterator<Item = &Choke> {
+		self.chokes
+			.iter()
+			.filter(move |c| c.regions == (a.min(b), a.max(b)))
+	}
+	/// Returns ids of chokes, which should be passed to get from `start` to `goal`, in order of passing.
+	///
+	/// Chokes are chosen to minimize straight distance through their centers,
+	/// so it's only approximation of the real shortest path.
+	/// Returns `None` if points aren't connected.
+	pub fn choke_path(&self, start: Point2, goal: Point2) -> Option<Vec<usize>> {
+		let start_region = self.region_id(start)?;
+		let goal_region = self.region_id(goal)?;
+		if start_region == goal_region {
+			return Some(Vec::new());
+		}
+
+		// Dijkstra on chokes, there are not many of them, so it's done without heap
+		let mut costs = vec![f32::INFINITY; self.chokes.len()];
+		let mut came_from = vec![None; self.chokes.len()];
+		let mut visited = vec![false; self.chokes.len()];
+		for &id in &self.all[start_region].chokes {
+			costs[id] = start.distance(self.chokes[id].center);
+		}
+
+		let mut best: Option<(usize, f32)> = None;
+		while let Some(current) = (0..self.chokes.len())
+			.filter(|i| !visited[*i] && costs[*i].is_finite())
+			.min_by(|a, b| costs[*a].partial_cmp(&costs[*b]).unwrap())
+		{
+			if best.map_or(false, |(_, cost)| costs[current] >= cost) {
+				break;
+			}
+			visited[current] = true;
+
+			let choke = &self.chokes[current];
+			let (a, b) = choke.regions;
+			if a == goal_region || b == goal_region {
+				let cost = costs[current] + choke.center.distance(goal);
+				if best.map_or(true, |(_, best_cost)| cost < best_cost) {
+					best = Some((current, cost));
+				}
+			}
+			for &region in &[a, b] {
+				for &next in &self.all[region].chokes {
+					let cost = costs[current] + choke.center.distance(self.chokes[next].center);
+					if cost < costs[next] {
+						costs[next] = cost;
+						came_from[next] = Some(current);
+					}
+				}
+			}
+		}
+
+		let mut path = vec![best?.0];
+		while let Some(prev) = came_from[*path.last().unwrap()] {
+			path.push(prev);
+		}
+		path.reverse();
+		Some(path)
+	}
+}
+
+// Floods joined with union-find
+#[derive(Default)]
+struct Floods {
+	parent: Vec<usize>,
+	size: Vec<usize>,
+	peak: Vec<f32>,
+	top: Vec<Pos>,
+}
+impl Floods {
+	fn add(&mut self, pos: Pos, altitude: f32) -> usize {
+		let id = self.parent.len();
+		self.parent.push(id);
+		self.size.push(0);
+		self.peak.push(altitude);
+		self.top.push(pos);
+		id
+	}
+	fn find(&mut self, mut id: usize) -> usize {
+		while self.parent[id] != id {
+			self.parent[id] = self.parent[self.parent[id]];
+			id = self.parent[id];
+		}
+		id
+	}
+	fn merge(&mut self, into: usize, from: usize) -> usize {
+		self.parent[from] = into;
+		self.size[into] += self.size[from];
+		if self.peak[from] > self.peak[into] {
+			self.peak[into] = self.peak[from];
+			self.top[into] = self.top[from];
+		}
+		into
+	}
+}
+
+fn neighbors4((x, y): Pos, (width, height): (usize, usize)) -> impl Iterator<Item = Pos> {
+	let mut neighbors = Vec::with_capacity(4);
+	if x > 0 {
+		neighbors.push((x - 1, y));
+	}
+	if y > 0 {
+		neighbors.push((x, y - 1));
+	}
+	if x + 1 < width {
+		neighbors.push((x + 1, y));
+	}
+	if y + 1 < height {
+		neighbors.push((x, y + 1));
+	}
+	neighbors.into_iter()
+}
+
+// Approximate distance from every cell to the closest obstacle (0 for obstacles),
+// edges of the grid are obstacles too
+fn altitude(grid: &PathingGrid) -> Array2<f32> {
+	let (width, height) = grid.size();
+	let mut altitude = Array2::from_shape_fn((width, height), |(x, y)| {
+		if grid.is_pathable((x, y)) {
+			(x.min(y).min(width - 1 - x).min(height - 1 - y) + 1) as f32
+		} else {
+			0.0
+		}
+	});
+
+	// Chamfer distance transform in 2 passes
+	let mut relax = |x: usize, y: usize, dx: isize, dy: isize, step: f32| {
+		let (nx, ny) = (x as isize + dx, y as isize + dy);
+		if nx >= 0 && ny >= 0 && (nx as usize) < width && (ny as usize) < height {
+			let candidate = altitude[(nx as usize, ny as usize)] + step;
+			if candidate < altitude[(x, y)] {
+				altitude[(x, y)] = candidate;
+			}
+		}
+	};
+	for y in 0..height {
+		for x in 0..width {
+			relax(x, y, -1, 0, 1.0);
+			relax(x, y, 0, -1, 1.0);
+			relax(x, y, -1, -1, SQRT_2);
+			relax(x, y, 1, -1, SQRT_2);
+		}
+	}
+	for y in (0..height).rev() {
+		for x in (0..width).rev() {
+			relax(x, y, 1, 0, 1.0);
+			relax(x, y, 0, 1, 1.0);
+			relax(x, y, 1, 1, SQRT_2);
+			relax(x, y, -1, 1, SQRT_2);
+		}
+	}
+	altitude
+}
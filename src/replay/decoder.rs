@@ -0,0 +1,211 @@
+//! Decoder of the versioned format used in replay header, details and tracker events.
+//!
+//! Every value is prefixed with a byte of it's type, so values can be decoded without protocol tables.
+//! Struct fields are identified by their tags, which are stable between game versions.
+
+use crate::client::{SC2Error, SC2Result};
+
+#[derive(Debug, Clone)]
+pub(crate) enum Value {
+	Array(Vec<Value>),
+	BitArray(Vec<u8>),
+	Blob(Vec<u8>),
+	Choice(i64, Box<Value>),
+	Optional(Option<Box<Value>>),
+	Struct(Vec<(i64, Value)>),
+	Int(i64),
+}
+impl Value {
+	fn inner(&self) -> &Self {
+		match self {
+			Self::Optional(Some(value)) => value.inner(),
+			value => value,
+		}
+	}
+	pub fn field(&self, tag: i64) -> Option<&Self> {
+		match self.inner() {
+			Self::Struct(fields) => fields.iter().find(|(t, _)| *t == tag).map(|(_, v)| v.inner()),
+			_ => None,
+		}
+	}
+	pub fn int(&self) -> Option<i64> {
+		match self.inner() {
+			Self::Int(n) => Some(*n),
+			Self::Choice(_, value) => value.int(),
+			_ => None,
+		}
+	}
+	pub fn string(&self) -> Option<String> {
+		match self.inner() {
+			Self::Blob(bytes) => Some(String::from_utf8_lossy(bytes).into_owned()),
+			_ => None,
+		}
+	}
+	pub fn array(&self) -> Option<&[Self]> {
+		match self.inner() {
+			Self::Array(values) => Some(values),
+			_ => None,
+		}
+	}
+	pub fn int_field(&self, tag: i64) -> Option<i64> {
+		self.field(tag).and_then(Self::int)
+	}
+	pub fn string_field(&self, tag: i64) -> Option<String> {
+		self.field(tag).and_then(Self::string)
+	}
+}
+
+pub(crate) struct Decoder<'a> {
+	data: &'a [u8],
+	pos: usize,
+}
+impl<'a> Decoder<'a> {
+	pub fn new(data: &'a [u8]) -> Self {
+		Self { data, pos: 0 }
+	}
+	pub fn is_done(&self) -> bool {
+		self.pos >= self.data.len()
+	}
+
+	pub fn value(&mut self) -> SC2Result<Value> {
+		Ok(match self.byte()? {
+			0 => {
+				let len = self.len()?;
+				Value::Array((0..len).map(|_| self.value()).collect::<SC2Result<_>>()?)
+			}
+			1 => {
+				let bits = self.vint()?;
+				if bits < 0 {
+					return Err(SC2Error::InvalidReplay(format!("Invalid length: {}", bits)));
+				}
+				Value::BitArray(self.bytes((bits as usize + 7) / 8)?.to_vec())
+			}
+			2 => {
+				let len = self.len()?;
+				Value::Blob(self.bytes(len)?.to_vec())
+			}
+			3 => {
+				let tag = self.vint()?;
+				Value::Choice(tag, Box::new(self.value()?))
+			}
+			4 => Value::Optional(if self.byte()? != 0 {
+				Some(Box::new(self.value()?))
+			} else {
+				None
+			}),
+			5 => {
+				let len = self.len()?;
+				let mut fields = Vec::with_capacity(len);
+				for _ in 0..len {
+					let tag = self.vint()?;
+					fields.push((tag, self.value()?));
+				}
+				Value::Struct(fields)
+			}
+			6 => Value::Int(self.byte()? as i64),
+			7 => Value::Blob(self.bytes(4)?.to_vec()),
+			8 => Value::Blob(self.bytes(8)?.to_vec()),
+			9 => Value::Int(self.vint()?),
+			t => return Err(SC2Error::InvalidReplay(format!("Unknown value type: {}", t))),
+		})
+	}
+
+	fn byte(&mut self) -> SC2Result<u8> {
+		Ok(self.bytes(1)?[0])
+	}
+	fn bytes(&mut self, n: usize) -> SC2Result<&'a [u8]> {
+		let end = self
+			.pos
+			.checked_add(n)
+			.filter(|end| *end <= self.data.len())
+			.ok_or_else(|| SC2Error::InvalidReplay("Unexpected end of data".to_string()))?;
+		let bytes = &self.data[self.pos..end];
+		self.pos = end;
+		Ok(bytes)
+	}
+	// Variable length integer: first byte stores sign in the lowest bit,
+	// every byte stores continuation flag in the highest bit
+	fn vint(&mut self) -> SC2Result<i64> {
+		let mut b = self.byte()?;
+		let negative = b & 1 != 0;
+		let mut result = ((b >> 1) & 0x3f) as i64;
+		let mut bits = 6;
+		while b & 0x80 != 0 {
+			if bits >= 64 {
+				return Err(SC2Error::InvalidReplay("Integer is too big".to_string()));
+			}
+			b = self.byte()?;
+			result |= ((b & 0x7f) as i64) << bits;
+			bits += 7;
+		}
+		if negative {
+			result
+				.checked_neg()
+				.ok_or_else(|| SC2Error::InvalidReplay("Integer is too big".to_string()))
+		} else {
+			Ok(result)
+		}
+	}
+	fn len(&mut self) -> SC2Result<usize> {
+		let len = self.vint()?;
+		// Every element takes at least one byte
+		if len < 0 || len as usize > self.data.len() - self.pos {
+			return Err(SC2Error::InvalidReplay(format!("Invalid length: {}", len)));
+		}
+		Ok(len as usize)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn decode(data: &[u8]) -> SC2Result<Value> {
+		Decoder::new(data).value()
+	}
+
+	#[test]
+	fn decodes_struct_fields() {
+		// Struct of 2 fields: 0 - int 100, 1 - blob "abc"
+		let data = [5, 4, 0, 9, 200, 1, 2, 2, 6, b'a', b'b', b'c'];
+		let mut decoder = Decoder::new(&data);
+		let value = decoder.value().unwrap();
+		assert!(decoder.is_done());
+		assert_eq!(value.int_field(0), Some(100));
+		assert_eq!(value.string_field(1).as_deref(), Some("abc"));
+		assert!(value.field(2).is_none());
+	}
+
+	#[test]
+	fn decodes_values_of_all_types() {
+		let array = decode(&[0, 4, 6, 1, 6, 2]).unwrap();
+		let ints = array.array().unwrap().iter().map(Value::int).collect::<Vec<_>>();
+		assert_eq!(ints, vec![Some(1), Some(2)]);
+		assert_eq!(decode(&[9, 7]).unwrap().int(), Some(-3));
+		assert_eq!(decode(&[4, 1, 6, 7]).unwrap().int(), Some(7));
+		assert_eq!(decode(&[4, 0]).unwrap().int(), None);
+		assert_eq!(decode(&[3, 2, 6, 5]).unwrap().int(), Some(5));
+		assert!(matches!(decode(&[1, 18, 0xff, 0x01]).unwrap(), Value::BitArray(bits) if bits.len() == 2));
+		assert!(matches!(decode(&[7, 1, 2, 3, 4]).unwrap(), Value::Blob(bytes) if bytes.len() == 4));
+	}
+
+	#[test]
+	fn rejects_broken_data() {
+		let invalid = |data: &[u8]| matches!(decode(data), Err(SC2Error::InvalidReplay(_)));
+		// Unknown type
+		assert!(invalid(&[42]));
+		// Blob is longer than data
+		assert!(invalid(&[2, 20, b'a']));
+		// Negative length
+		assert!(invalid(&[0, 3]));
+		// Integer doesn't fit in 64 bits
+		assert!(invalid(&[
+			9, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01
+		]));
+		// -i64::MIN
+		assert!(invalid(&[
+			9, 0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02
+		]));
+		assert!(invalid(&[]));
+	}
+}
@@ -0,0 +1,371 @@
+//! Offline parser of `.SC2Replay` files, which doesn't need SC2 to be launched.
+//!
+//! Enabled with `replay` feature.
+//!
+//! Reads header (game version and length), details (map and players) and tracker events
+//! (units born and died, upgrades and player stats) of replay.
+//!
+//! # Usage
+//! ```
+//! let replay = Replay::open("replays/Game.SC2Replay")?;
+//! println!("Played on {} ({:?})", replay.details.map_name, replay.header.sc2_version());
+//! for player in &replay.details.players {
+//!     println!("{} ({:?}): {:?}", player.name, player.race, player.result);
+//! }
+//! for event in &replay.tracker_events {
+//!     if let TrackerEventKind::UnitDied { unit_tag, .. } = event.event {
+//!         println!("Unit {} died on {} loop", unit_tag, event.game_loop);
+//!     }
+//! }
+//! ```
+
+mod decoder;
+mod mpq;
+
+use crate::{
+	client::{SC2Error, SC2Result},
+	geometry::Point2,
+	ids::{UnitTypeId, UpgradeId},
+	paths::get_version_by_build,
+	player::{GameResult, Race},
+};
+use decoder::{Decoder, Value};
+use mpq::Mpq;
+use std::{fs, path::Path};
+
+const DETAILS_FILE: &str = "replay.details";
+const TRACKER_EVENTS_FILE: &str = "replay.tracker.events";
+
+/// Parsed `.SC2Replay` file.
+#[derive(Debug, Clone)]
+pub struct Replay {
+	/// Game version and length.
+	pub header: ReplayHeader,
+	/// Map and players.
+	pub details: ReplayDetails,
+	/// Events of units and players, sorted by game loop.
+	pub tracker_events: Vec<TrackerEvent>,
+}
+impl Replay {
+	/// Reads and parses replay file in given path.
+	pub fn open<P: AsRef<Path>>(path: P) -> SC2Result<Self> {
+		Self::from_bytes(fs::read(path)?)
+	}
+	/// Parses replay from raw content of file.
+	pub fn from_bytes(data: Vec<u8>) -> SC2Result<Self> {
+		let archive = Mpq::new(data)?;
+
+		let header = ReplayHeader::from_value(&Decoder::new(archive.user_data()).value()?)?;
+		let details = archive
+			.read_file(DETAILS_FILE)?
+			.ok_or_else(|| SC2Error::InvalidReplay(format!("Missing {}", DETAILS_FILE)))?;
+		let mut details = ReplayDetails::from_value(&Decoder::new(&details).value()?)?;
+		// Tracker events were added in 2.0.8, older replays don't have them
+		let tracker_events = match archive.read_file(TRACKER_EVENTS_FILE)? {
+			Some(data) => read_tracker_events(&data)?,
+			None => Vec::new(),
+		};
+
+		// Race names are localized in details, so units of players are used if it didn't work
+		for player in &mut details.players {
+			if player.race == Race::Random {
+				player.race = race_by_units(player.id, &tracker_events).unwrap_or(Race::Random);
+			}
+		}
+
+		Ok(Self {
+			header,
+			details,
+			tracker_events,
+		})
+	}
+}
+
+/// Game version and length of replay.
+#[derive(Debug, Clone)]
+pub struct ReplayHeader {
+	/// Version of the game as (major, minor, revision).
+	pub version: (u32, u32, u32),
+	/// Build of the game.
+	pub build: u32,
+	/// Base build of the game, which determines SC2 version needed to watch replay.
+	pub base_build: u32,
+	/// Build of game data.
+	pub data_build: u32,
+	/// Length of the game in game loops.
+	pub game_loops: u32,
+}
+impl ReplayHeader {
+	/// Returns version of SC2 client which can be used to watch this replay
+	/// (can be passed to [`ReplayRunner`](crate::observer::ReplayRunner)),
+	/// or `None` if version is unknown.
+	pub fn sc2_version(&self) -> Option<&'static str> {
+		get_version_by_build(self.base_build)
+	}
+
+	fn from_value(value: &Value) -> SC2Result<Self> {
+		let version = value.field(1).ok_or_else(|| invalid_field("version"))?;
+		let int = |value: &Value, tag| value.int_field(tag).unwrap_or_default() as u32;
+		let build = int(version, 4);
+		Ok(Self {
+			version: (int(version, 1), int(version, 2), int(version, 3)),
+			build,
+			base_build: int(version, 5),
+			data_build: value.int_field(6).map_or(build, |n| n as u32),
+			game_loops: int(value, 3),
+		})
+	}
+}
+
+/// Map and players of replay.
+#[derive(Debug, Clone)]
+pub struct ReplayDetails {
+	/// Localized name of the map.
+	pub map_name: String,
+	/// Players of the game, observers aren't included.
+	pub players: Vec<ReplayPlayer>,
+}
+impl ReplayDetails {
+	fn from_value(value: &Value) -> SC2Result<Self> {
+		let players = value
+			.field(0)
+			.and_then(Value::array)
+			.ok_or_else(|| invalid_field("player list"))?;
+		Ok(Self {
+			map_name: value.string_field(1).unwrap_or_default(),
+			players: players
+				.iter()
+				.enumerate()
+				.map(|(i, player)| ReplayPlayer::from_value(i as u32 + 1, player))
+				.collect(),
+		})
+	}
+}
+
+/// Player of replay.
+#[derive(Debug, Clone)]
+pub struct ReplayPlayer {
+	/// Id of player, the same as `player_id` of units and events.
+	pub id: u32,
+	/// Name of player (can contain clan tag).
+	pub name: String,
+	/// Actual race of player (not requested one, which can be random).
+	pub race: Race,
+	/// Result of the game for player.
+	pub result: GameResult,
+	/// Is player a built-in AI or not.
+	pub is_computer: bool,
+	/// Team of player.
+	pub team_id: u32,
+}
+impl ReplayPlayer {
+	fn from_value(id: u32, value: &Value) -> Self {
+		let race = match value.string_field(2).as_deref() {
+			Some("Terran") => Race::Terran,
+			Some("Zerg") => Race::Zerg,
+			Some("Protoss") => Race::Protoss,
+			_ => Race::Random,
+		};
+		let result = match value.int_field(8) {
+			Some(1) => GameResult::Victory,
+			Some(2) => GameResult::Defeat,
+			Some(3) => GameResult::Tie,
+			_ => GameResult::Undecided,
+		};
+		Self {
+			id,
+			name: value.string_field(0).unwrap_or_default(),
+			race,
+			result,
+			is_computer: value.int_field(4) == Some(3),
+			team_id: value.int_field(5).unwrap_or_default() as u32,
+		}
+	}
+}
+
+/// Event of replay, which happened on given game loop.
+#[derive(Debug, Clone)]
+pub struct TrackerEvent {
+	/// Game loop event happened on.
+	pub game_loop: u32,
+	/// Content of event.
+	pub event: TrackerEventKind,
+}
+
+/// Unit type in replay. Contains both id and name, because name may be unknown for the crate.
+#[derive(Debug, Clone)]
+pub struct ReplayUnitType {
+	/// Id of unit type or `None` if it's not present in [`UnitTypeId`].
+	pub type_id: Option<UnitTypeId>,
+	/// Name of unit type in replay.
+	pub name: String,
+}
+impl ReplayUnitType {
+	fn new(name: String) -> Self {
+		Self {
+			type_id: name.parse().ok(),
+			name,
+		}
+	}
+}
+
+/// Kinds of tracker events.
+///
+/// Unit tags are the same as tags of units in live games.
+#[derive(Debug, Clone)]
+pub enum TrackerEventKind {
+	/// Periodical economy stats of player.
+	PlayerStats { player_id: u32, stats: PlayerStats },
+	/// Unit created instantly (e.g. trained units, units on game start).
+	UnitBorn {
+		unit_tag: u64,
+		unit_type: ReplayUnitType,
+		player_id: u32,
+		position: Point2,
+	},
+	/// Unit started construction (e.g. buildings).
+	UnitInit {
+		unit_tag: u64,
+		unit_type: ReplayUnitType,
+		player_id: u32,
+		position: Point2,
+	},
+	/// Construction of unit started with [`UnitInit`](Self::UnitInit) finished.
+	UnitDone { unit_tag: u64 },
+	/// Unit died, with player who killed it (if any).
+	UnitDied {
+		unit_tag: u64,
+		killer_player_id: Option<u32>,
+		position: Point2,
+	},
+	/// Unit changed it's owner.
+	UnitOwnerChange { unit_tag: u64, player_id: u32 },
+	/// Unit morphed into another type.
+	UnitTypeChange {
+		unit_tag: u64,
+		unit_type: ReplayUnitType,
+	},
+	/// Player finished research.
+	Upgrade {
+		player_id: u32,
+		upgrade_id: Option<UpgradeId>,
+		name: String,
+		count: i32,
+	},
+}
+
+/// Economy stats of player.
+#[derive(Debug, Clone, Default)]
+pub struct PlayerStats {
+	pub minerals: u32,
+	pub vespene: u32,
+	/// Minerals collected per minute.
+	pub minerals_rate: u32,
+	/// Vespene collected per minute.
+	pub vespene_rate: u32,
+	pub workers: u32,
+	pub supply_used: f32,
+	pub supply_cap: f32,
+}
+
+fn read_tracker_events(data: &[u8]) -> SC2Result<Vec<TrackerEvent>> {
+	let mut decoder = Decoder::new(data);
+	let mut events = Vec::new();
+	let mut game_loop = 0;
+	while !decoder.is_done() {
+		game_loop += decoder
+			.value()?
+			.int()
+			.ok_or_else(|| invalid_field("game loop delta"))? as u32;
+		let id = decoder.value()?.int().ok_or_else(|| invalid_field("event id"))?;
+		let event = decoder.value()?;
+		if let Some(event) = tracker_event_kind(id, &event) {
+			events.push(TrackerEvent { game_loop, event });
+		}
+	}
+	Ok(events)
+}
+
+fn tracker_event_kind(id: i64, e: &Value) -> Option<TrackerEventKind> {
+	let int = |tag| e.int_field(tag).unwrap_or_default();
+	let unit_tag = || ((int(0) as u64) << 18) + int(1) as u64;
+	let position = |x, y| Point2::new(int(x) as f32, int(y) as f32);
+
+	Some(match id {
+		0 => {
+			let stats = e.field(1)?;
+			let stat = |tag| stats.int_field(tag).unwrap_or_default();
+			TrackerEventKind::PlayerStats {
+				player_id: int(0) as u32,
+				stats: PlayerStats {
+					minerals: stat(0) as u32,
+					vespene: stat(1) as u32,
+					minerals_rate: stat(2) as u32,
+					vespene_rate: stat(3) as u32,
+					workers: stat(4) as u32,
+					// Supply is stored in fixed point
+					supply_used: stat(29) as f32 / 4096.0,
+					supply_cap: stat(30) as f32 / 4096.0,
+				},
+			}
+		}
+		1 => TrackerEventKind::UnitBorn {
+			unit_tag: unit_tag(),
+			unit_type: ReplayUnitType::new(e.string_field(2)?),
+			player_id: int(3) as u32,
+			position: position(5, 6),
+		},
+		2 => TrackerEventKind::UnitDied {
+			unit_tag: unit_tag(),
+			killer_player_id: e.int_field(2).map(|id| id as u32),
+			position: position(3, 4),
+		},
+		3 => TrackerEventKind::UnitOwnerChange {
+			unit_tag: unit_tag(),
+			player_id: int(2) as u32,
+		},
+		4 => TrackerEventKind::UnitTypeChange {
+			unit_tag: unit_tag(),
+			unit_type: ReplayUnitType::new(e.string_field(2)?),
+		},
+		5 => {
+			let name = e.string_field(1)?;
+			TrackerEventKind::Upgrade {
+				player_id: int(0) as u32,
+				upgrade_id: name.parse().ok(),
+				name,
+				count: int(2) as i32,
+			}
+		}
+		6 => TrackerEventKind::UnitInit {
+			unit_tag: unit_tag(),
+			unit_type: ReplayUnitType::new(e.string_field(2)?),
+			player_id: int(3) as u32,
+			position: position(5, 6),
+		},
+		7 => TrackerEventKind::UnitDone { unit_tag: unit_tag() },
+		// Unit positions and other events aren't supported
+		_ => return None,
+	})
+}
+
+// Determines race of player by the first town hall
+fn race_by_units(player_id: u32, events: &[TrackerEvent]) -> Option<Race> {
+	events.iter().find_map(|e| match &e.event {
+		TrackerEventKind::UnitBorn {
+			unit_type,
+			player_id: id,
+			..
+		} if *id == player_id => match unit_type.type_id? {
+			UnitTypeId::CommandCenter => Some(Race::Terran),
+			UnitTypeId::Hatchery => Some(Race::Zerg),
+			UnitTypeId::Nexus => Some(Race::Protoss),
+			_ => None,
+		},
+		_ => None,
+	})
+}
+
+fn invalid_field(name: &str) -> SC2Error {
+	SC2Error::InvalidReplay(format!("Can't read {}", name))
+}
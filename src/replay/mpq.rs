@@ -0,0 +1,374 @@
+//! Minimal reader of MPQ archives, enough to extract files from replays.
+
+use crate::client::{SC2Error, SC2Result};
+use bzip2::read::BzDecoder;
+use flate2::read::ZlibDecoder;
+use std::io::Read;
+
+const USER_DATA_MAGIC: &[u8] = b"MPQ\x1b";
+const HEADER_MAGIC: &[u8] = b"MPQ\x1a";
+
+const HASH_A: u32 = 1;
+const HASH_B: u32 = 2;
+const HASH_TABLE: u32 = 3;
+
+const FILE_IMPLODE: u32 = 0x0000_0100;
+const FILE_COMPRESS: u32 = 0x0000_0200;
+const FILE_ENCRYPTED: u32 = 0x0001_0000;
+const FILE_SINGLE_UNIT: u32 = 0x0100_0000;
+const FILE_SECTOR_CRC: u32 = 0x0400_0000;
+const FILE_EXISTS: u32 = 0x8000_0000;
+
+// Sizes are read from archive, so it's not trusted when reserving memory
+const MAX_RESERVED: usize = 0x0100_0000;
+
+lazy_static! {
+	static ref CRYPT_TABLE: Vec<u32> = {
+		let mut table = vec![0; 0x500];
+		let mut seed: u32 = 0x0010_0001;
+		for i in 0..0x100 {
+			for j in 0..5 {
+				seed = (seed * 125 + 3) % 0x002A_AAAB;
+				let high = (seed & 0xFFFF) << 16;
+				seed = (seed * 125 + 3) % 0x002A_AAAB;
+				let low = seed & 0xFFFF;
+				table[i + j * 0x100] = high | low;
+			}
+		}
+		table
+	};
+}
+
+fn hash(s: &str, hash_type: u32) -> u32 {
+	let mut seed1: u32 = 0x7FED_7FED;
+	let mut seed2: u32 = 0xEEEE_EEEE;
+	for c in s.bytes().map(|c| c.to_ascii_uppercase() as u32) {
+		seed1 = CRYPT_TABLE[((hash_type << 8) + c) as usize] ^ seed1.wrapping_add(seed2);
+		seed2 = c
+			.wrapping_add(seed1)
+			.wrapping_add(seed2)
+			.wrapping_add(seed2 << 5)
+			.wrapping_add(3);
+	}
+	seed1
+}
+
+fn decrypt(data: &[u8], key: u32) -> Vec<u32> {
+	let mut seed1 = key;
+	let mut seed2: u32 = 0xEEEE_EEEE;
+	data.chunks_exact(4)
+		.map(|chunk| {
+			seed2 = seed2.wrapping_add(CRYPT_TABLE[0x400 + (seed1 & 0xFF) as usize]);
+			let value = read_u32(chunk, 0) ^ seed1.wrapping_add(seed2);
+			seed1 = ((!seed1 << 0x15).wrapping_add(0x1111_1111)) | (seed1 >> 0x0B);
+			seed2 = value.wrapping_add(seed2).wrapping_add(seed2 << 5).wrapping_add(3);
+			value
+		})
+		.collect()
+}
+
+fn read_u32(data: &[u8], pos: usize) -> u32 {
+	let mut bytes = [0; 4];
+	bytes.copy_from_slice(&data[pos..pos + 4]);
+	u32::from_le_bytes(bytes)
+}
+fn read_u16(data: &[u8], pos: usize) -> u16 {
+	u16::from_le_bytes([data[pos], data[pos + 1]])
+}
+
+fn invalid(msg: &str) -> SC2Error {
+	SC2Error::InvalidReplay(msg.to_string())
+}
+
+struct HashEntry {
+	hash_a: u32,
+	hash_b: u32,
+	block_index: u32,
+}
+
+struct BlockEntry {
+	offset: usize,
+	archived_size: usize,
+	size: usize,
+	flags: u32,
+}
+
+pub(crate) struct Mpq {
+	data: Vec<u8>,
+	header_offset: usize,
+	user_data: Vec<u8>,
+	sector_size: usize,
+	hash_table: Vec<HashEntry>,
+	block_table: Vec<BlockEntry>,
+}
+impl Mpq {
+	pub fn new(data: Vec<u8>) -> SC2Result<Self> {
+		let slice = |pos: usize, len: usize| {
+			pos.checked_add(len)
+				.and_then(|end| data.get(pos..end))
+				.ok_or_else(|| invalid("Archive is truncated"))
+		};
+
+		// Replays start with user data, which contains replay header
+		let (header_offset, user_data) = if slice(0, 4)? == USER_DATA_MAGIC {
+			let header = slice(0, 16)?;
+			let user_data_size = read_u32(header, 12) as usize;
+			(read_u32(header, 8) as usize, slice(16, user_data_size)?.to_vec())
+		} else {
+			(0, Vec::new())
+		};
+
+		let header = slice(header_offset, 32)?;
+		if &header[..4] != HEADER_MAGIC {
+			return Err(invalid("Not an MPQ archive"));
+		}
+		let sector_shift = read_u16(header, 14) as u32;
+		let sector_size = 512usize
+			.checked_shl(sector_shift)
+			.filter(|size| size >> sector_shift == 512)
+			.ok_or_else(|| invalid("Sector size is too big"))?;
+		// Offsets of tables are relative to the header
+		let table = |offset: u32, entries: u32| {
+			let pos = header_offset.checked_add(offset as usize);
+			let len = (entries as usize).checked_mul(16);
+			match (pos, len) {
+				(Some(pos), Some(len)) => slice(pos, len),
+				_ => Err(invalid("Table is out of archive bounds")),
+			}
+		};
+
+		let hash_table = decrypt(
+			table(read_u32(header, 16), read_u32(header, 24))?,
+			hash("(hash table)", HASH_TABLE),
+		)
+		.chunks_exact(4)
+		.map(|entry| HashEntry {
+			hash_a: entry[0],
+			hash_b: entry[1],
+			block_index: entry[3],
+		})
+		.collect();
+		let block_table = decrypt(
+			table(read_u32(header, 20), read_u32(header, 28))?,
+			hash("(block table)", HASH_TABLE),
+		)
+		.chunks_exact(4)
+		.map(|entry| BlockEntry {
+			offset: entry[0] as usize,
+			archived_size: entry[1] as usize,
+			size: entry[2] as usize,
+			flags: entry[3],
+		})
+		.collect();
+
+		Ok(Self {
+			data,
+			header_offset,
+			user_data,
+			sector_size,
+			hash_table,
+			block_table,
+		})
+	}
+
+	/// Returns user data of archive, which contains header in replays.
+	pub fn user_data(&self) -> &[u8] {
+		&self.user_data
+	}
+
+	/// Returns content of file with given name or `None` if there's no such file in archive.
+	pub fn read_file(&self, name: &str) -> SC2Result<Option<Vec<u8>>> {
+		let (hash_a, hash_b) = (hash(name, HASH_A), hash(name, HASH_B));
+		// Hash tables of replays are small, so linear search is enough
+		let block = match self
+			.hash_table
+			.iter()
+			.find(|e| e.hash_a == hash_a && e.hash_b == hash_b)
+			.and_then(|e| self.block_table.get(e.block_index as usize))
+		{
+			Some(block) if block.flags & FILE_EXISTS != 0 => block,
+			_ => return Ok(None),
+		};
+		if block.archived_size == 0 {
+			return Ok(Some(Vec::new()));
+		}
+		if block.flags & (FILE_ENCRYPTED | FILE_IMPLODE) != 0 {
+			return Err(invalid("Encrypted and imploded files are not supported"));
+		}
+
+		let data = self
+			.header_offset
+			.checked_add(block.offset)
+			.and_then(|start| self.data.get(start..start.checked_add(block.archived_size)?))
+			.ok_or_else(|| invalid("File is out of archive bounds"))?;
+
+		if block.flags & FILE_COMPRESS == 0 {
+			return Ok(Some(data.to_vec()));
+		}
+		if block.flags & FILE_SINGLE_UNIT != 0 {
+			return Ok(Some(if block.size > block.archived_size {
+				decompress(data, block.size)?
+			} else {
+				data.to_vec()
+			}));
+		}
+
+		// File is split into sectors, which are compressed separately
+		let sectors = block.size / self.sector_size + (block.size % self.sector_size != 0) as usize;
+		let positions_count = sectors + if block.flags & FILE_SECTOR_CRC != 0 { 2 } else { 1 };
+		if data.len() / 4 < positions_count {
+			return Err(invalid("File is truncated"));
+		}
+		let positions = (0..positions_count)
+			.map(|i| read_u32(data, i * 4) as usize)
+			.collect::<Vec<_>>();
+
+		let mut result = Vec::with_capacity(block.size.min(MAX_RESERVED));
+		for i in 0..sectors {
+			let sector = data
+				.get(positions[i]..positions[i + 1])
+				.ok_or_else(|| invalid("Sector is out of file bounds"))?;
+			let expected = self.sector_size.min(block.size.saturating_sub(result.len()));
+			// Sector is stored as is if compression didn't make it smaller
+			if sector.len() < expected {
+				result.extend(decompress(sector, expected)?);
+			} else {
+				result.extend_from_slice(sector);
+			}
+		}
+		Ok(Some(result))
+	}
+}
+
+fn decompress(data: &[u8], size: usize) -> SC2Result<Vec<u8>> {
+	let (kind, data) = data
+		.split_first()
+		.ok_or_else(|| invalid("Compressed data is empty"))?;
+	let mut result = Vec::with_capacity(size.min(MAX_RESERVED));
+	match kind {
+		0x02 => {
+			ZlibDecoder::new(data).read_to_end(&mut result)?;
+		}
+		0x10 => {
+			BzDecoder::new(data).read_to_end(&mut result)?;
+		}
+		kind => {
+			return Err(SC2Error::InvalidReplay(format!(
+				"Unsupported compression: {:#x}",
+				kind
+			)))
+		}
+	}
+	Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const FILE_NAME: &str = "replay.details";
+	const CONTENT: &[u8] = b"hello replay";
+
+	fn encrypt(values: &[u32], key: u32) -> Vec<u8> {
+		let mut seed1 = key;
+		let mut seed2: u32 = 0xEEEE_EEEE;
+		let mut data = Vec::new();
+		for value in values {
+			seed2 = seed2.wrapping_add(CRYPT_TABLE[0x400 + (seed1 & 0xFF) as usize]);
+			data.extend_from_slice(&(value ^ seed1.wrapping_add(seed2)).to_le_bytes());
+			seed1 = ((!seed1 << 0x15).wrapping_add(0x1111_1111)) | (seed1 >> 0x0B);
+			seed2 = value.wrapping_add(seed2).wrapping_add(seed2 << 5).wrapping_add(3);
+		}
+		data
+	}
+
+	fn set_u32(data: &mut [u8], pos: usize, value: u32) {
+		data[pos..pos + 4].copy_from_slice(&value.to_le_bytes());
+	}
+
+	// Header, content of the file, hash table and block table with one entry
+	fn archive() -> Vec<u8> {
+		let mut data = vec![0; 32];
+		data[..4].copy_from_slice(HEADER_MAGIC);
+		set_u32(&mut data, 4, 32);
+		data[14] = 3;
+		set_u32(&mut data, 16, 44);
+		set_u32(&mut data, 20, 60);
+		set_u32(&mut data, 24, 1);
+		set_u32(&mut data, 28, 1);
+		data.extend_from_slice(CONTENT);
+		data.extend(encrypt(
+			&[hash(FILE_NAME, HASH_A), hash(FILE_NAME, HASH_B), 0, 0],
+			hash("(hash table)", HASH_TABLE),
+		));
+		let size = CONTENT.len() as u32;
+		data.extend(encrypt(
+			&[32, size, size, FILE_EXISTS | FILE_SINGLE_UNIT],
+			hash("(block table)", HASH_TABLE),
+		));
+		set_u32(&mut data, 8, data.len() as u32);
+		data
+	}
+
+	fn is_invalid<T>(result: SC2Result<T>) -> bool {
+		matches!(result, Err(SC2Error::InvalidReplay(_)))
+	}
+
+	#[test]
+	fn reads_files() {
+		let mpq = Mpq::new(archive()).unwrap();
+		assert!(mpq.user_data().is_empty());
+		assert_eq!(mpq.read_file(FILE_NAME).unwrap().as_deref(), Some(CONTENT));
+		assert_eq!(mpq.read_file("replay.tracker.events").unwrap(), None);
+	}
+
+	#[test]
+	fn reads_user_data() {
+		let mut data = vec![0; 32];
+		data[..4].copy_from_slice(USER_DATA_MAGIC);
+		set_u32(&mut data, 8, 32);
+		set_u32(&mut data, 12, 3);
+		data[16..19].copy_from_slice(b"abc");
+		data.extend(archive());
+
+		let mpq = Mpq::new(data).unwrap();
+		assert_eq!(mpq.user_data(), b"abc");
+		assert_eq!(mpq.read_file(FILE_NAME).unwrap().as_deref(), Some(CONTENT));
+	}
+
+	#[test]
+	fn rejects_broken_archives() {
+		assert!(is_invalid(Mpq::new(b"MPQ\x1a".to_vec())));
+		assert!(is_invalid(Mpq::new(vec![0; 64])));
+
+		let mut data = archive();
+		data[14] = 0xff;
+		assert!(is_invalid(Mpq::new(data)));
+
+		let mut data = archive();
+		set_u32(&mut data, 16, u32::MAX);
+		assert!(is_invalid(Mpq::new(data)));
+
+		let mut data = archive();
+		set_u32(&mut data, 24, u32::MAX);
+		assert!(is_invalid(Mpq::new(data)));
+
+		let mut data = vec![0; 32];
+		data[..4].copy_from_slice(USER_DATA_MAGIC);
+		set_u32(&mut data, 8, u32::MAX);
+		assert!(is_invalid(Mpq::new(data)));
+	}
+
+	#[test]
+	fn rejects_files_out_of_bounds() {
+		let mut data = archive();
+		let size = CONTENT.len() as u32;
+		let block_table = encrypt(
+			&[u32::MAX, size, size, FILE_EXISTS | FILE_SINGLE_UNIT],
+			hash("(block table)", HASH_TABLE),
+		);
+		data[60..76].copy_from_slice(&block_table);
+		let mpq = Mpq::new(data).unwrap();
+		assert!(is_invalid(mpq.read_file(FILE_NAME)));
+	}
+}
@@ -2,8 +2,11 @@
 
 use crate::{FromProto, IntoSC2};
 use sc2_proto::score::{CategoryScoreDetails, Score as ProtoScore, Score_ScoreType, VitalScoreDetails};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 #[variant_checkers]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone)]
 pub enum ScoreType {
 	Curriculum,
@@ -23,6 +26,7 @@ impl Default for ScoreType {
 	}
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, Clone)]
 pub struct Category {
 	pub none: f32,
@@ -43,6 +47,7 @@ impl FromProto<&CategoryScoreDetails> for Category {
 	}
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, Clone)]
 pub struct Vital {
 	pub life: f32,
@@ -62,6 +67,7 @@ impl FromProto<&VitalScoreDetails> for Vital {
 /// All kinds of scores stored here.
 ///
 /// Can be accessed through [state.observation.score](crate::game_state::Observation::score).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, Clone)]
 pub struct Score {
 	pub score_type: ScoreType,
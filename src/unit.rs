@@ -5,20 +5,19 @@ use crate::{
 	action::{Commander, Target},
 	bot::{LockBool, LockOwned, LockU32, Locked, Reader, Rs, Rw},
 	consts::{
-		RaceValues, ANTI_ARMOR_BUFF, DAMAGE_BONUS_PER_UPGRADE, FRAMES_PER_SECOND, MISSED_WEAPONS,
+		anti_armor_buff, RaceValues, DAMAGE_BONUS_PER_UPGRADE, FRAMES_PER_SECOND, MISSED_WEAPONS,
 		OFF_CREEP_SPEED_UPGRADES, SPEED_BUFFS, SPEED_ON_CREEP, SPEED_UPGRADES, WARPGATE_ABILITIES,
 	},
 	distance::Distance,
 	game_data::{Attribute, Cost, GameData, TargetType, UnitTypeData, Weapon},
 	game_state::Alliance,
 	geometry::{Point2, Point3},
-	ids::{AbilityId, BuffId, UnitTypeId, UpgradeId},
+	ids::{AbilityId, BuffId, DataVersion, UnitTypeId, UpgradeId},
 	pixel_map::{PixelMap, VisibilityMap},
 	player::Race,
 	units::Container,
 	FromProto,
 };
-use num_traits::FromPrimitive;
 use rustc_hash::{FxHashMap, FxHashSet};
 use sc2_proto::raw::{
 	CloakState as ProtoCloakState, DisplayType as ProtoDisplayType, Unit as ProtoUnit,
@@ -40,6 +39,7 @@ pub(crate) struct DataForUnit {
 	pub creep: Rw<PixelMap>,
 	pub game_step: Rs<LockU32>,
 	pub allow_spam: Rs<LockBool>,
+	pub data_version: DataVersion,
 }
 
 /// Weapon target used in [`calculate_weapon_stats`](Unit::calculate_weapon_stats).
@@ -512,6 +512,7 @@ impl Unit {
 				match buff {
 					BuffId::MedivacSpeedBoost => return speed * 1.7,
 					BuffId::VoidRaySwarmDamageBoost => return speed * 0.75,
+					BuffId::ChargeUp if self.data.data_version == DataVersion::V4_10 => speed *= 2.2,
 					_ => {
 						if let Some(increase) = SPEED_BUFFS.get(&buff) {
 							speed *= increase;
@@ -1058,7 +1059,7 @@ impl Unit {
 				target.buffs.iter().for_each(|buff| match buff {
 					BuffId::GuardianShield => target_has_guardian_shield = true,
 					_ => {
-						if *buff == ANTI_ARMOR_BUFF {
+						if *buff == anti_armor_buff(self.data.data_version) {
 							enemy_armor -= 3;
 							enemy_shield_armor -= 3;
 						}
@@ -1691,7 +1692,8 @@ impl Unit {
 	pub(crate) fn from_proto(data: SharedUnitData, visibility: &VisibilityMap, u: &ProtoUnit) -> Self {
 		let pos = u.get_pos();
 		let position = Point2::from_proto(pos);
-		let type_id = UnitTypeId::from_u32(u.get_unit_type()).unwrap();
+		let version = data.data_version;
+		let type_id = UnitTypeId::from_raw(u.get_unit_type(), version).unwrap();
 		let is_burrowed = u.get_is_burrowed();
 		let (is_cloaked, is_revealed) = if is_burrowed {
 			(true, false)
@@ -1728,7 +1730,7 @@ impl Unit {
 			buffs: u
 				.get_buff_ids()
 				.iter()
-				.map(|b| BuffId::from_u32(*b).unwrap())
+				.map(|b| BuffId::from_raw(*b, version).unwrap())
 				.collect(),
 			detect_range: match type_id {
 				UnitTypeId::Observer => 11.0,
@@ -1761,7 +1763,7 @@ impl Unit {
 				.get_orders()
 				.iter()
 				.map(|order| UnitOrder {
-					ability: AbilityId::from_u32(order.get_ability_id()).unwrap(),
+					ability: AbilityId::from_raw(order.get_ability_id(), version).unwrap(),
 					target: match &order.target {
 						Some(ProtoTarget::target_world_space_pos(pos)) => {
 							Target::Pos(Point2::from_proto(pos))
@@ -1784,7 +1786,7 @@ impl Unit {
 					shield_max: p.get_shield_max(),
 					energy: p.get_energy(),
 					energy_max: p.get_energy_max(),
-					type_id: UnitTypeId::from_u32(p.get_unit_type()).unwrap(),
+					type_id: UnitTypeId::from_raw(p.get_unit_type(), version).unwrap(),
 				})
 				.collect(),
 			cargo_space_taken: u.cargo_space_taken.map(|x| x as u32),
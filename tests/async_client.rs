@@ -0,0 +1,118 @@
+#![cfg(all(feature = "async", feature = "test-utils"))]
+
+use rust_sc2::{action::ActionResult, bot::Bot, prelude::*};
+use std::{cell::RefCell, rc::Rc};
+use tokio::runtime::Builder;
+
+#[derive(Default)]
+struct PlacementBot {
+	bot: Rc<RefCell<Bot>>,
+	steps: usize,
+	placements: Vec<ActionResult>,
+}
+
+impl Player for PlacementBot {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Terran, Some("PlacementBot"))
+	}
+	fn on_step(&mut self, _iteration: usize) -> SC2Result<()> {
+		self.steps += 1;
+		let place = (AbilityId::TerranBuildSupplyDepot, Point2::new(10.0, 10.0), None);
+		let results = self.bot.borrow_mut().query_placement(vec![place], false)?;
+		self.placements.extend(results);
+		Ok(())
+	}
+	fn on_end(&self, result: GameResult) -> SC2Result<()> {
+		assert_eq!(result, GameResult::Victory);
+		Ok(())
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+fn play(bot: &mut PlacementBot, realtime: bool) -> (SC2Result<()>, MockServer) {
+	let server = MockServer::start(MockFixtures::new(Race::Terran, 10)).unwrap();
+	let runtime = Builder::new_multi_thread().enable_all().build().unwrap();
+	let options = LaunchOptions {
+		realtime,
+		..Default::default()
+	};
+	let computer = Computer::new(Race::Random, Difficulty::VeryEasy, None);
+	let result = runtime.block_on(run_mock_game_async(bot, computer, &server, options));
+	(result, server)
+}
+
+#[test]
+fn runs_game_on_mock_server_async() {
+	let mut bot = PlacementBot::default();
+	let (result, server) = play(&mut bot, false);
+	result.unwrap();
+
+	assert_eq!(bot.steps, 9);
+	assert_eq!(bot.placements, vec![ActionResult::Success; 9]);
+	assert!(server.requests().iter().any(|req| req.has_step()));
+}
+
+#[test]
+fn requests_observation_ahead_in_realtime() {
+	let mut bot = PlacementBot::default();
+	let (result, server) = play(&mut bot, true);
+	result.unwrap();
+
+	// Bot's queries are answered correctly, even though observation was requested before them
+	assert_eq!(bot.placements, vec![ActionResult::Success; bot.steps]);
+	let requests = server.requests();
+	assert!(requests
+		.windows(2)
+		.any(|pair| pair[0].has_observation() && pair[1].has_query()));
+	assert!(!requests.iter().any(|req| req.has_step()));
+}
+
+#[test]
+fn fails_on_current_thread_runtime() {
+	let server = MockServer::start(MockFixtures::new(Race::Terran, 10)).unwrap();
+	let runtime = Builder::new_current_thread().enable_all().build().unwrap();
+	let mut bot = PlacementBot::default();
+	let computer = Computer::new(Race::Random, Difficulty::VeryEasy, None);
+	let result = runtime.block_on(run_mock_game_async(
+		&mut bot,
+		computer,
+		&server,
+		Default::default(),
+	));
+
+	assert!(matches!(result, Err(SC2Error::Runtime(_))));
+	assert_eq!(bot.steps, 0);
+}
+
+#[test]
+fn rejects_unsupported_options() {
+	let server = MockServer::start(MockFixtures::new(Race::Terran, 10)).unwrap();
+	let runtime = Builder::new_multi_thread().enable_all().build().unwrap();
+	let unsupported = vec![
+		LaunchOptions {
+			record_as: Some("game.sc2rec"),
+			..Default::default()
+		},
+		LaunchOptions {
+			pipelined: true,
+			..Default::default()
+		},
+		LaunchOptions {
+			limits: GameLimits {
+				max_game_loop: Some(100),
+				..Default::default()
+			},
+			..Default::default()
+		},
+	];
+	for options in unsupported {
+		let mut bot = PlacementBot::default();
+		let computer = Computer::new(Race::Random, Difficulty::VeryEasy, None);
+		let result = runtime.block_on(run_mock_game_async(&mut bot, computer, &server, options));
+		assert!(matches!(result, Err(SC2Error::InvalidArgument(_))));
+		assert_eq!(bot.steps, 0);
+	}
+	assert!(server.requests().is_empty());
+}
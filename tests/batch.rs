@@ -0,0 +1,61 @@
+use rust_sc2::{
+	batch::{BatchRunner, BatchSummary, GameRecord},
+	prelude::*,
+};
+use std::time::Duration;
+
+#[test]
+fn builds_games_matrix() {
+	let runner = BatchRunner::new(
+		&["EverDreamLE", "GoldenWallLE"],
+		&[
+			Computer::new(Race::Zerg, Difficulty::Easy, None),
+			Computer::new(Race::Terran, Difficulty::Hard, Some(AIBuild::Air)),
+		],
+		3,
+	);
+	let games = runner.games();
+	assert_eq!(games.len(), 12);
+	assert_eq!(games[1].map, "EverDreamLE");
+	assert_eq!(games[1].computer.race, Race::Terran);
+	assert_eq!(games[4].repetition, 1);
+}
+
+#[test]
+fn formats_summary() {
+	let computer = Computer::new(Race::Zerg, Difficulty::Easy, None);
+	let runner = BatchRunner::new(&["Maps, \"Test\""], &[computer], 2);
+	let mut games = runner.games().into_iter();
+	let mut won = GameRecord {
+		game: games.next().unwrap(),
+		result: GameResult::Victory,
+		game_loops: 1000,
+		score: Default::default(),
+		wall_time: Duration::from_millis(1500),
+		error: None,
+	};
+	won.score.total_score = 42;
+	let failed = GameRecord {
+		game: games.next().unwrap(),
+		result: GameResult::Undecided,
+		game_loops: 0,
+		score: Default::default(),
+		wall_time: Duration::from_secs(1),
+		error: Some("Timed out".to_string()),
+	};
+	let summary = BatchSummary {
+		games: vec![won, failed],
+	};
+
+	assert_eq!(summary.win_rate(), 0.5);
+	assert_eq!(summary.errors(), 1);
+
+	let csv = summary.to_csv();
+	let lines = csv.lines().collect::<Vec<_>>();
+	assert_eq!(lines.len(), 3);
+	assert!(lines[0].starts_with("map,race,difficulty,"));
+	assert_eq!(
+		lines[1],
+		"\"Maps, \"\"Test\"\"\",Zerg,Easy,,0,Victory,1000,42,0,0,0,0,1.500,"
+	);
+}
@@ -0,0 +1,76 @@
+use rust_sc2::{bot::Bot, prelude::*};
+use std::{
+	cell::RefCell,
+	net::TcpListener,
+	rc::Rc,
+	thread,
+	time::{Duration, Instant},
+};
+
+#[derive(Default)]
+struct Idle {
+	bot: Rc<RefCell<Bot>>,
+}
+
+impl Player for Idle {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Terran, Some("Idle"))
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+fn ladder_args(port: u16, connect_options: ConnectOptions) -> LadderArgs {
+	LadderArgs {
+		ladder_server: "127.0.0.1".to_string(),
+		game_port: port as i32,
+		start_port: 5690,
+		opponent_id: None,
+		realtime: false,
+		connect_options,
+	}
+}
+
+#[test]
+fn gives_up_connecting_to_closed_port() {
+	// Listener is dropped right away, so nothing listens on the port
+	let port = TcpListener::bind("127.0.0.1:0")
+		.unwrap()
+		.local_addr()
+		.unwrap()
+		.port();
+	let options = ConnectOptions {
+		timeout: Duration::from_millis(100),
+		backoff: Duration::from_millis(10),
+		max_backoff: Duration::from_millis(20),
+		max_attempts: 3,
+		read_timeout: None,
+	};
+
+	let start = Instant::now();
+	let result = run_ladder_game_with_args(&mut Idle::default(), &ladder_args(port, options));
+	assert!(matches!(result, Err(SC2Error::Timeout(_))));
+	assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn times_out_waiting_for_silent_client() {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let port = listener.local_addr().unwrap().port();
+	// Client accepts connection and reads requests, but never responds
+	thread::spawn(move || {
+		let (stream, _) = listener.accept().unwrap();
+		let mut ws = tungstenite::accept(stream).unwrap();
+		while ws.read_message().is_ok() {}
+	});
+	let options = ConnectOptions {
+		read_timeout: Some(Duration::from_millis(200)),
+		..Default::default()
+	};
+
+	let start = Instant::now();
+	let result = run_ladder_game_with_args(&mut Idle::default(), &ladder_args(port, options));
+	assert!(matches!(result, Err(SC2Error::Timeout(_))));
+	assert!(start.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,57 @@
+use ndarray::Array2;
+use rust_sc2::{geometry::Point2, influence::ThreatMap, pathfinding::PathingGrid, pixel_map::Pixel};
+
+#[test]
+fn sums_threats_by_layers() {
+	let mut threats = ThreatMap::new((20, 20));
+	threats.add_ground(Point2::new(5.0, 5.0), 3.0, 10.0);
+	threats.add_ground(Point2::new(7.0, 5.0), 3.0, 5.0);
+	threats.add_air(Point2::new(5.0, 5.0), 6.0, 7.0);
+
+	assert_eq!(threats.ground_threat(Point2::new(6.0, 5.0)), 15.0);
+	assert_eq!(threats.ground_threat(Point2::new(3.0, 5.0)), 10.0);
+	assert_eq!(threats.ground_threat(Point2::new(15.0, 15.0)), 0.0);
+	assert_eq!(threats.air_threat(Point2::new(10.0, 5.0)), 7.0);
+	assert_eq!(threats.air_threat(Point2::new(-1.0, 5.0)), 0.0);
+
+	let safe = threats
+		.safest_point(Point2::new(5.5, 5.5), 5.0, false, |_| true)
+		.unwrap();
+	assert_eq!(threats.ground_threat(safe), 0.0);
+	// All air cells around are threatened, so one of them is returned
+	let safe = threats
+		.safest_point(Point2::new(5.5, 5.5), 5.0, true, |_| true)
+		.unwrap();
+	assert_eq!(threats.air_threat(safe), 7.0);
+	let safe = threats
+		.safest_point(Point2::new(5.5, 5.5), 8.0, true, |_| true)
+		.unwrap();
+	assert_eq!(threats.air_threat(safe), 0.0);
+}
+
+#[test]
+fn paths_around_threats() {
+	let grid = PathingGrid::new(&Array2::from_elem((30, 20), Pixel::Empty));
+	let mut threats = ThreatMap::new(grid.size());
+	threats.add_ground(Point2::new(15.0, 10.0), 5.0, 20.0);
+
+	let (start, goal) = (Point2::new(5.5, 10.5), Point2::new(25.5, 10.5));
+	let direct = grid.find_path(start, goal, 0.5).unwrap();
+	assert!(direct.waypoints.iter().all(|p| p.y == 10.5));
+
+	let safe = threats.find_path(&grid, start, goal, 0.5, 1.0).unwrap();
+	assert!(safe.distance > direct.distance);
+	assert!(safe.waypoints.iter().all(|p| threats.ground_threat(*p) == 0.0));
+}
+
+#[test]
+fn paths_with_smaller_threat_map() {
+	let grid = PathingGrid::new(&Array2::from_elem((30, 20), Pixel::Empty));
+	let mut threats = ThreatMap::new((10, 10));
+	threats.add_ground(Point2::new(5.0, 5.0), 3.0, 20.0);
+
+	let path = threats
+		.find_path(&grid, Point2::new(2.5, 2.5), Point2::new(25.5, 15.5), 0.5, 1.0)
+		.unwrap();
+	assert_eq!(path.waypoints.last(), Some(&Point2::new(25.5, 15.5)));
+}
@@ -0,0 +1,33 @@
+use rust_sc2::client::LadderArgs;
+
+#[test]
+fn parses_ladder_args() {
+	let args = LadderArgs::parse(&[
+		"--GamePort",
+		"5677",
+		"--StartPort=5690",
+		"--OpponentId",
+		"some-id",
+		"--RealTime",
+		"--custom",
+	])
+	.unwrap()
+	.unwrap();
+	assert_eq!(args.ladder_server, "127.0.0.1");
+	assert_eq!(args.game_port, 5677);
+	assert_eq!(args.start_port, 5690);
+	assert_eq!(args.opponent_id.as_deref(), Some("some-id"));
+	assert!(args.realtime);
+}
+
+#[test]
+fn detects_local_launch() {
+	assert_eq!(LadderArgs::parse(&["--step", "2"]).unwrap(), None);
+}
+
+#[test]
+fn rejects_incomplete_ladder_args() {
+	assert!(LadderArgs::parse(&["--LadderServer", "127.0.0.1", "--GamePort", "5677"]).is_err());
+	assert!(LadderArgs::parse(&["--GamePort", "port", "--StartPort", "5690"]).is_err());
+	assert!(LadderArgs::parse(&["--GamePort"]).is_err());
+}
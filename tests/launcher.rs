@@ -0,0 +1,171 @@
+#![cfg(unix)]
+
+use rust_sc2::{bot::Bot, prelude::*};
+use std::{
+	cell::RefCell,
+	env, fs,
+	path::PathBuf,
+	rc::Rc,
+	sync::Once,
+	time::{Duration, Instant},
+};
+
+#[test]
+fn launches_client_with_custom_command() {
+	let root = env::temp_dir().join(format!("rust-sc2-launcher-{}", std::process::id()));
+	let _ = fs::remove_dir_all(&root);
+	for dir in &["Versions/Base75689", "Support", "Support64"] {
+		fs::create_dir_all(root.join(dir)).unwrap();
+	}
+	fs::write(
+		root.join("Versions/Base75689/fake_sc2.sh"),
+		"echo \"$FAKE_ENV $@\" > \"$FAKE_OUT\"\n",
+	)
+	.unwrap();
+	let out = root.join("args.txt");
+
+	let launcher = Launcher {
+		command: Some("sh".to_string()),
+		executable: Some("fake_sc2.sh".to_string()),
+		args: vec!["-verbose".to_string()],
+		env: vec![
+			("FAKE_ENV".to_string(), "env".to_string()),
+			("FAKE_OUT".to_string(), out.to_string_lossy().into_owned()),
+		],
+		..Default::default()
+	};
+	let status = launcher
+		.command(&root.to_string_lossy(), 5000, Some("4.10"))
+		.unwrap()
+		.status()
+		.unwrap();
+	assert!(status.success());
+	assert_eq!(
+		fs::read_to_string(out).unwrap().trim(),
+		"env -listen 127.0.0.1 -port 5000 -displayMode 0 \
+		 -dataVersion B89B5D6FA7CBF6452E721311BFBC6CB2 -verbose"
+	);
+}
+
+#[derive(Default)]
+struct Idle {
+	bot: Rc<RefCell<Bot>>,
+}
+
+impl Player for Idle {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Terran, Some("Idle"))
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+// Installation with fake clients, shared by all runners, since `SC2PATH` is global
+fn fake_installation() -> PathBuf {
+	static INIT: Once = Once::new();
+	let root = env::temp_dir().join(format!("rust-sc2-process-{}", std::process::id()));
+	INIT.call_once(|| {
+		let _ = fs::remove_dir_all(&root);
+		for dir in &["Versions/Base75689", "Support", "Support64", "Maps"] {
+			fs::create_dir_all(root.join(dir)).unwrap();
+		}
+		fs::write(root.join("Maps/Fake.SC2Map"), "").unwrap();
+		let scripts = [
+			("exit.sh", "echo launched >> \"$FAKE_OUT\"\n"),
+			("output.sh", "echo to stdout\necho to stderr >&2\n"),
+			("hang.sh", "exec sleep 30\n"),
+		];
+		for (name, script) in &scripts {
+			fs::write(root.join("Versions/Base75689").join(name), script).unwrap();
+		}
+		env::set_var("SC2PATH", &root);
+	});
+	root
+}
+
+fn fake_launcher(script: &str) -> Launcher {
+	Launcher {
+		command: Some("sh".to_string()),
+		executable: Some(script.to_string()),
+		..Default::default()
+	}
+}
+
+fn fake_runner(bot: &mut Idle) -> RunnerSingle<Idle> {
+	fake_installation();
+	let computer = Computer::new(Race::Random, Difficulty::VeryEasy, None);
+	RunnerSingle::new(bot, computer, "Fake", Some("4.10")).unwrap()
+}
+
+#[test]
+fn relaunches_exited_client() {
+	let out = fake_installation().join("launches.txt");
+	let mut bot = Idle::default();
+	let mut runner = fake_runner(&mut bot);
+	runner.launcher = fake_launcher("exit.sh");
+	runner
+		.launcher
+		.env
+		.push(("FAKE_OUT".to_string(), out.to_string_lossy().into_owned()));
+
+	// Exit is detected without waiting for all connection attempts
+	let start = Instant::now();
+	assert!(matches!(runner.launch(), Err(SC2Error::ProcessExited(_))));
+	assert!(start.elapsed() < Duration::from_secs(10));
+	assert!(runner.client_exit_status().is_some());
+
+	// Client is relaunched before the game, but exits again
+	assert!(matches!(runner.run_game(), Err(SC2Error::ProcessExited(_))));
+	assert_eq!(fs::read_to_string(&out).unwrap().lines().count(), 2);
+}
+
+#[test]
+fn kills_client_after_shutdown_timeout() {
+	let mut bot = Idle::default();
+	let mut runner = fake_runner(&mut bot);
+	runner.launcher = Launcher {
+		shutdown_timeout: Duration::from_millis(300),
+		..fake_launcher("hang.sh")
+	};
+	runner.connect_options = ConnectOptions {
+		timeout: Duration::from_millis(50),
+		backoff: Duration::from_millis(10),
+		max_backoff: Duration::from_millis(10),
+		max_attempts: 2,
+		read_timeout: None,
+	};
+	assert!(matches!(runner.launch(), Err(SC2Error::Timeout(_))));
+	assert!(runner.client_exit_status().is_none());
+
+	// Client doesn't exit by itself, so it's killed
+	let start = Instant::now();
+	runner.close();
+	let elapsed = start.elapsed();
+	assert!(elapsed >= Duration::from_millis(300));
+	assert!(elapsed < Duration::from_secs(5));
+	assert!(runner.client_exit_status().is_some());
+}
+
+#[test]
+fn captures_client_output() {
+	let log_dir = fake_installation().join("logs");
+	let mut bot = Idle::default();
+	let mut runner = fake_runner(&mut bot);
+	runner.launcher = Launcher {
+		log_dir: Some(log_dir.to_string_lossy().into_owned()),
+		..fake_launcher("output.sh")
+	};
+	assert!(matches!(runner.launch(), Err(SC2Error::ProcessExited(_))));
+
+	let logs = fs::read_dir(&log_dir)
+		.unwrap()
+		.map(|entry| entry.unwrap().path())
+		.collect::<Vec<_>>();
+	assert_eq!(logs.len(), 1);
+	let name = logs[0].file_name().unwrap().to_string_lossy().into_owned();
+	assert!(name.starts_with("SC2_") && name.ends_with(".log"));
+	let log = fs::read_to_string(&logs[0]).unwrap();
+	assert!(log.contains("to stdout"));
+	assert!(log.contains("to stderr"));
+}
@@ -0,0 +1,588 @@
+#![cfg(feature = "test-utils")]
+
+use rust_sc2::{action::ActionResult, batch::BatchRunner, bot::Bot, ids::DataVersion, prelude::*};
+use sc2_proto::{common::ImageData, error::ActionResult as ProtoActionResult, sc2api::Request};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Default)]
+struct StepCounter {
+	bot: Rc<RefCell<Bot>>,
+	steps: usize,
+}
+
+impl Player for StepCounter {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Terran, Some("StepCounter"))
+	}
+	fn on_step(&mut self, _iteration: usize) -> SC2Result<()> {
+		self.steps += 1;
+		Ok(())
+	}
+	fn on_end(&self, result: GameResult) -> SC2Result<()> {
+		assert_eq!(result, GameResult::Victory);
+		Ok(())
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+#[test]
+fn runs_game_on_mock_server() {
+	let server = MockServer::start(MockFixtures::new(Race::Terran, 10)).unwrap();
+	let mut bot = StepCounter::default();
+
+	let mut runner = RunnerSingle::mock(
+		&mut bot,
+		Computer::new(Race::Random, Difficulty::VeryEasy, None),
+		&server,
+	)
+	.unwrap();
+	runner.run_game().unwrap();
+
+	// First observation is used by `on_start`.
+	assert_eq!(bot.steps, 9);
+	assert!(server.requests().iter().any(|req| req.has_create_game()));
+}
+
+#[test]
+fn plays_back_recorded_game() {
+	let path = std::env::temp_dir()
+		.join(format!("rust-sc2-recording-{}.bin", std::process::id()))
+		.to_string_lossy()
+		.into_owned();
+	let server = MockServer::start(MockFixtures::new(Race::Terran, 10)).unwrap();
+	let mut bot = StepCounter::default();
+
+	let mut runner = RunnerSingle::mock(
+		&mut bot,
+		Computer::new(Race::Random, Difficulty::VeryEasy, None),
+		&server,
+	)
+	.unwrap();
+	runner.record_as = Some(&path);
+	runner.run_game().unwrap();
+	drop(runner);
+
+	let mut replayed = StepCounter::default();
+	run_recorded_game(&mut replayed, &path).unwrap();
+	let _ = std::fs::remove_file(&path);
+
+	assert_eq!(replayed.steps, bot.steps);
+	assert_eq!(replayed.bot.borrow().game_info.map_name, "Mock");
+}
+
+fn run_mock_game(bot: &mut StepCounter, fixtures: MockFixtures) {
+	let server = MockServer::start(fixtures).unwrap();
+	let mut runner = RunnerSingle::mock(
+		bot,
+		Computer::new(Race::Random, Difficulty::VeryEasy, None),
+		&server,
+	)
+	.unwrap();
+	runner.run_game().unwrap();
+}
+
+#[test]
+fn keeps_data_version_per_bot() {
+	let mut fixtures = MockFixtures::new(Race::Terran, 10);
+	fixtures.base_build = 75689;
+	fixtures.data_version = "B89B5D6FA7CBF6452E721311BFBC6CB2".to_string();
+	let mut old_bot = StepCounter::default();
+	run_mock_game(&mut old_bot, fixtures);
+
+	let mut new_bot = StepCounter::default();
+	run_mock_game(&mut new_bot, MockFixtures::new(Race::Terran, 10));
+
+	// Game with the latest version doesn't change ids of the older one
+	assert_eq!(old_bot.bot.borrow().data_version(), DataVersion::V4_10);
+	assert_eq!(new_bot.bot.borrow().data_version(), DataVersion::Latest);
+}
+
+#[test]
+fn air_flow_field_stays_in_playable_area() {
+	let mut bot = StepCounter::default();
+	run_mock_game(&mut bot, MockFixtures::with_map_size(Race::Terran, 2, 32, 32));
+	let bot = bot.bot.borrow();
+
+	// Playable area of mock map is from (2, 2) to (30, 30)
+	let grid = PathingGrid::air(&bot);
+	assert_eq!(grid.size(), (32, 32));
+	assert!(grid.is_pathable((2, 2)));
+	assert!(grid.is_pathable((29, 29)));
+	assert!(!grid.is_pathable((1, 15)));
+	assert!(!grid.is_pathable((30, 15)));
+
+	let target = Point2::new(29.5, 29.5);
+	let field = grid.flow_field(target, 0.5).unwrap();
+	assert!(field.distance(Point2::new(2.5, 2.5)).is_some());
+	assert_eq!(field.distance(Point2::new(0.5, 0.5)), None);
+	assert_eq!(field.distance(Point2::new(30.5, 15.5)), None);
+	// Units outside of playable area are led into it
+	assert_eq!(
+		field.next_step(Point2::new(1.5, 15.5)),
+		Some(Point2::new(2.5, 15.5))
+	);
+
+	// Ground grid of the mock map is pathable everywhere
+	let ground = PathingGrid::from_bot(&bot).flow_field(target, 0.5).unwrap();
+	assert!(ground.distance(Point2::new(0.5, 0.5)).is_some());
+}
+
+#[test]
+fn computes_regions_on_first_request() {
+	let mut bot = StepCounter::default();
+	run_mock_game(&mut bot, MockFixtures::with_map_size(Race::Terran, 2, 32, 32));
+	let bot = bot.bot.borrow();
+
+	let regions = bot.regions();
+	assert!(!regions.all.is_empty());
+	assert!(regions.region_id(Point2::new(16.5, 16.5)).is_some());
+	// Cached until the next game
+	assert!(std::ptr::eq(&*regions, &*bot.regions()));
+}
+
+#[test]
+fn runs_batch_on_mock_servers() {
+	let mut batch = BatchRunner::new(
+		&["Mock"],
+		&[Computer::new(Race::Random, Difficulty::VeryEasy, None)],
+		2,
+	);
+	batch.parallel = 2;
+	let servers = (0..2)
+		.map(|_| MockServer::start(MockFixtures::new(Race::Terran, 10)).unwrap())
+		.collect();
+
+	let summary = batch.run_mock(StepCounter::default, servers).unwrap();
+	assert_eq!(summary.games.len(), 2);
+	assert_eq!(summary.errors(), 0);
+	assert_eq!(summary.count(GameResult::Victory), 2);
+}
+
+#[derive(Default)]
+struct Unlimited {
+	bot: Rc<RefCell<Bot>>,
+	steps: usize,
+}
+
+impl Player for Unlimited {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Terran, Some("Unlimited"))
+	}
+	fn on_step(&mut self, _iteration: usize) -> SC2Result<()> {
+		self.steps += 1;
+		Ok(())
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+fn run_limited_game(action: LimitAction) -> (GameResult, usize, Vec<Request>) {
+	let server = MockServer::start(MockFixtures::new(Race::Terran, 10)).unwrap();
+	let mut bot = Unlimited::default();
+
+	let mut runner = RunnerSingle::mock(
+		&mut bot,
+		Computer::new(Race::Random, Difficulty::VeryEasy, None),
+		&server,
+	)
+	.unwrap();
+	runner.limits = GameLimits {
+		max_game_loop: Some(5),
+		max_time: None,
+		action,
+	};
+	let result = runner.run_game().unwrap();
+	drop(runner);
+
+	(result, bot.steps, server.requests())
+}
+
+#[test]
+fn stops_game_with_tie_on_limit() {
+	let (result, steps, requests) = run_limited_game(LimitAction::Tie);
+	assert_eq!(result, GameResult::Tie);
+	assert_eq!(steps, 5);
+	assert!(requests.iter().any(|req| req.has_leave_game()));
+}
+
+#[test]
+fn leaves_game_on_limit() {
+	let (result, steps, requests) = run_limited_game(LimitAction::Leave);
+	assert_eq!(result, GameResult::Defeat);
+	assert_eq!(steps, 5);
+	assert!(requests.iter().any(|req| req.has_leave_game()));
+}
+
+#[test]
+fn ends_game_with_debug_on_limit() {
+	let (result, steps, requests) = run_limited_game(LimitAction::EndGame(true));
+	// Result is received from SC2 when game ends
+	assert_eq!(result, GameResult::Victory);
+	assert_eq!(steps, 9);
+	assert!(!requests.iter().any(|req| req.has_leave_game()));
+	let end_games = requests
+		.iter()
+		.filter(|req| req.has_debug())
+		.flat_map(|req| req.get_debug().get_debug())
+		.filter(|cmd| cmd.has_end_game())
+		.count();
+	assert_eq!(end_games, 1);
+}
+
+#[derive(Default)]
+struct Greeter {
+	bot: Rc<RefCell<Bot>>,
+	failed: usize,
+}
+
+impl Player for Greeter {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Terran, Some("Greeter"))
+	}
+	fn on_step(&mut self, iteration: usize) -> SC2Result<()> {
+		if iteration == 0 {
+			self.bot.borrow_mut().chat("gl hf");
+		}
+		Ok(())
+	}
+	fn on_event(&mut self, event: Event) -> SC2Result<()> {
+		if let Event::ActionFailed(outcome) = event {
+			assert_eq!(outcome.result, ActionResult::Error);
+			self.failed += 1;
+			// Failed action is resubmitted on the same step
+			self.bot.borrow_mut().chat("gl hf");
+		}
+		Ok(())
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+#[test]
+fn resubmits_failed_actions() {
+	let mut fixtures = MockFixtures::new(Race::Terran, 10);
+	fixtures.action_results = vec![ProtoActionResult::Error; 5];
+	let server = MockServer::start(fixtures).unwrap();
+	let mut bot = Greeter::default();
+
+	let mut runner = RunnerSingle::mock(
+		&mut bot,
+		Computer::new(Race::Random, Difficulty::VeryEasy, None),
+		&server,
+	)
+	.unwrap();
+	runner.run_game().unwrap();
+
+	assert_eq!(bot.failed, 5);
+	// 4 rounds on the first step, the last resubmitted action is sent on the next step and succeeds
+	let requests = server.requests();
+	assert_eq!(requests.iter().filter(|req| req.has_action()).count(), 6);
+}
+
+// Saves the game on the first step and loads it once on the fourth one
+#[derive(Default)]
+struct Trainer {
+	bot: Rc<RefCell<Bot>>,
+	game_loops: Vec<u32>,
+	loaded: bool,
+}
+
+impl Player for Trainer {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Terran, Some("Trainer"))
+	}
+	fn on_step(&mut self, iteration: usize) -> SC2Result<()> {
+		let mut bot = self.bot.borrow_mut();
+		self.game_loops.push(bot.state.observation.game_loop);
+		if iteration == 0 {
+			bot.quick_save()?;
+		} else if iteration == 3 && !self.loaded {
+			bot.enemy_upgrades().insert(UpgradeId::Stimpack);
+			bot.chat("Lost this one");
+			bot.quick_load()?;
+			self.loaded = true;
+
+			assert!(!bot.enemy_has_upgrade(UpgradeId::Stimpack));
+		}
+		Ok(())
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+#[test]
+fn loads_saved_game() {
+	let server = MockServer::start(MockFixtures::new(Race::Terran, 10)).unwrap();
+	let mut bot = Trainer::default();
+
+	let mut runner = RunnerSingle::mock(
+		&mut bot,
+		Computer::new(Race::Random, Difficulty::VeryEasy, None),
+		&server,
+	)
+	.unwrap();
+	assert_eq!(runner.run_game().unwrap(), GameResult::Victory);
+	drop(runner);
+
+	assert_eq!(bot.game_loops, vec![1, 2, 3, 4, 2, 3, 4, 5, 6, 7, 8, 9]);
+	// Chat message given before loading is dropped
+	assert!(!server.requests().iter().any(|req| req.has_action()));
+}
+
+#[test]
+fn plays_game_again_with_runner() {
+	let server = MockServer::start(MockFixtures::new(Race::Terran, 10)).unwrap();
+	let mut bot = Trainer::default();
+
+	let mut runner = RunnerSingle::mock(
+		&mut bot,
+		Computer::new(Race::Random, Difficulty::VeryEasy, None),
+		&server,
+	)
+	.unwrap();
+	assert_eq!(runner.run_game().unwrap(), GameResult::Victory);
+	// Continues from the state saved on the first step, which is used by `on_start`
+	assert_eq!(runner.quick_load().unwrap(), GameResult::Victory);
+	assert_eq!(runner.restart_game().unwrap(), GameResult::Victory);
+	drop(runner);
+
+	let game_loops = [
+		vec![1, 2, 3, 4, 2, 3, 4, 5, 6, 7, 8, 9],
+		vec![3, 4, 5, 6, 7, 8, 9],
+		vec![1, 2, 3, 4, 5, 6, 7, 8, 9],
+	]
+	.concat();
+	assert_eq!(bot.game_loops, game_loops);
+	let requests = server.requests();
+	assert_eq!(requests.iter().filter(|req| req.has_restart_game()).count(), 1);
+	assert_eq!(requests.iter().filter(|req| req.has_quick_load()).count(), 2);
+}
+
+#[derive(Default)]
+struct LockstepBot {
+	bot: Rc<RefCell<Bot>>,
+	name: &'static str,
+	log: Rc<RefCell<Vec<(&'static str, usize)>>>,
+}
+
+impl Player for LockstepBot {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Terran, Some(self.name))
+	}
+	fn on_step(&mut self, iteration: usize) -> SC2Result<()> {
+		self.log.borrow_mut().push((self.name, iteration));
+		Ok(())
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+#[test]
+fn runs_bot_vs_bot_on_mock_servers() {
+	let server1 = MockServer::start(MockFixtures::new(Race::Terran, 4)).unwrap();
+	let mut fixtures = MockFixtures::new(Race::Terran, 4);
+	fixtures.player_id = 2;
+	let server2 = MockServer::start(fixtures).unwrap();
+	let log = Rc::new(RefCell::new(Vec::new()));
+	let mut bot1 = LockstepBot {
+		name: "Bot1",
+		log: Rc::clone(&log),
+		..Default::default()
+	};
+	let mut bot2 = LockstepBot {
+		name: "Bot2",
+		log: Rc::clone(&log),
+		..Default::default()
+	};
+
+	let mut runner = RunnerBotVsBot::mock(&mut bot1, &mut bot2, &server1, &server2).unwrap();
+	let results = runner.run_game().unwrap();
+	assert_eq!(results, (GameResult::Victory, GameResult::Defeat));
+
+	// Each bot steps once, then waits for the other one
+	assert_eq!(
+		*log.borrow(),
+		vec![
+			("Bot1", 0),
+			("Bot2", 0),
+			("Bot1", 1),
+			("Bot2", 1),
+			("Bot1", 2),
+			("Bot2", 2)
+		]
+	);
+	let (requests1, requests2) = (server1.requests(), server2.requests());
+	assert!(requests1.iter().any(|req| req.has_create_game()));
+	assert!(!requests2.iter().any(|req| req.has_create_game()));
+	// Step after `on_start` and after each of 3 steps
+	for requests in &[requests1, requests2] {
+		assert_eq!(requests.iter().filter(|req| req.has_step()).count(), 4);
+		assert!(!requests.iter().any(|req| req.has_debug()));
+	}
+}
+
+#[derive(Default)]
+struct SpatialBot {
+	bot: Rc<RefCell<Bot>>,
+}
+
+impl Player for SpatialBot {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Terran, Some("SpatialBot"))
+			.with_feature_layer(SpatialOptions::new((64, 48), (32, 32)))
+			.with_render(SpatialOptions::new((128, 96), (64, 64)))
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+fn image((x, y): (i32, i32), bits_per_pixel: i32, fill: u8) -> ImageData {
+	let mut image = ImageData::new();
+	image.set_bits_per_pixel(bits_per_pixel);
+	image.mut_size().set_x(x);
+	image.mut_size().set_y(y);
+	image.set_data(vec![fill; (x * y * bits_per_pixel / 8) as usize]);
+	image
+}
+
+fn run_spatial_game(fixtures: MockFixtures) -> (SpatialBot, MockServer) {
+	let server = MockServer::start(fixtures).unwrap();
+	let mut bot = SpatialBot::default();
+	let mut runner = RunnerSingle::mock(
+		&mut bot,
+		Computer::new(Race::Random, Difficulty::VeryEasy, None),
+		&server,
+	)
+	.unwrap();
+	runner.run_game().unwrap();
+	(bot, server)
+}
+
+#[test]
+fn requests_spatial_observations() {
+	let (_, server) = run_spatial_game(MockFixtures::new(Race::Terran, 2));
+
+	let requests = server.requests();
+	let join = requests.iter().find(|req| req.has_join_game()).unwrap();
+	let options = join.get_join_game().get_options();
+	let feature_layer = options.get_feature_layer();
+	assert_eq!(feature_layer.get_resolution().get_x(), 64);
+	assert_eq!(feature_layer.get_resolution().get_y(), 48);
+	assert_eq!(feature_layer.get_minimap_resolution().get_x(), 32);
+	assert_eq!(feature_layer.get_width(), 24.0);
+	let render = options.get_render();
+	assert_eq!(render.get_resolution().get_x(), 128);
+	assert_eq!(render.get_resolution().get_y(), 96);
+	assert_eq!(render.get_minimap_resolution().get_y(), 64);
+	assert!(!render.has_width());
+}
+
+#[test]
+fn decodes_spatial_observations() {
+	let mut fixtures = MockFixtures::new(Race::Terran, 2);
+	for res in &mut fixtures.observations {
+		let obs = res.mut_observation();
+		let screen = obs.mut_feature_layer_data().mut_renders();
+		screen.set_height_map(image((64, 48), 8, 200));
+		screen.set_unit_type(image((64, 48), 32, 1));
+		screen.set_creep(image((64, 48), 1, u8::MAX));
+		let minimap = obs.mut_feature_layer_data().mut_minimap_renders();
+		minimap.set_height_map(image((32, 32), 8, 100));
+		let render = obs.mut_render_data();
+		render.set_map(image((128, 96), 24, 10));
+		render.set_minimap(image((64, 64), 24, 20));
+	}
+	let (bot, _) = run_spatial_game(fixtures);
+	let bot = bot.bot.borrow();
+
+	let feature_layer = bot.state.observation.feature_layer.as_ref().unwrap();
+	let screen = &feature_layer.screen;
+	assert_eq!(screen.height_map.dim(), (64, 48));
+	assert_eq!(screen.height_map[(63, 47)], 200);
+	assert_eq!(screen.unit_type.dim(), (64, 48));
+	assert_eq!(screen.unit_type[(0, 0)], i32::from_le_bytes([1; 4]));
+	assert_eq!(screen.creep.dim(), (64, 48));
+	// Absent layers are empty
+	assert_eq!(screen.pathable.dim(), (0, 0));
+	assert_eq!(feature_layer.minimap.height_map.dim(), (32, 32));
+
+	let render = bot.state.observation.render.as_ref().unwrap();
+	assert_eq!(render.map.dim(), (128, 96, 3));
+	assert_eq!(render.map[(127, 95, 2)], 10);
+	assert_eq!(render.minimap.dim(), (64, 64, 3));
+}
+
+// Chats and draws debug text on every step
+#[derive(Default)]
+struct Chatter {
+	bot: Rc<RefCell<Bot>>,
+	steps: u32,
+	failed: usize,
+}
+
+impl Player for Chatter {
+	fn get_player_settings(&self) -> PlayerSettings {
+		PlayerSettings::new(Race::Terran, Some("Chatter"))
+	}
+	fn on_step(&mut self, iteration: usize) -> SC2Result<()> {
+		self.steps += 1;
+		let mut bot = self.bot.borrow_mut();
+		bot.chat("ping");
+		bot.debug
+			.draw_text_screen(&format!("Step {}", iteration), None, None, None);
+		Ok(())
+	}
+	fn on_event(&mut self, event: Event) -> SC2Result<()> {
+		if let Event::ActionFailed(outcome) = event {
+			assert_eq!(outcome.result, ActionResult::Error);
+			self.failed += 1;
+		}
+		Ok(())
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+#[test]
+fn pipelines_step_requests() {
+	let mut fixtures = MockFixtures::new(Race::Terran, 10);
+	fixtures.action_results = vec![ProtoActionResult::Error; 2];
+	let server = MockServer::start(fixtures).unwrap();
+	let mut bot = Chatter::default();
+
+	let mut runner = RunnerSingle::mock(
+		&mut bot,
+		Computer::new(Race::Random, Difficulty::VeryEasy, None),
+		&server,
+	)
+	.unwrap();
+	runner.pipelined = true;
+	assert_eq!(runner.run_game().unwrap(), GameResult::Victory);
+
+	assert_eq!(bot.steps, 9);
+	assert_eq!(bot.failed, 2);
+	// The first step isn't measured
+	assert_eq!(bot.bot.borrow().step_stats().steps, bot.steps);
+
+	let requests = server.requests();
+	let start = requests.iter().position(|req| req.has_action()).unwrap();
+	// Observation of the next step is requested together with requests of every step
+	let steps = requests[start..]
+		.chunks(4)
+		.take_while(|batch| batch.len() == 4 && batch[0].has_action())
+		.inspect(|batch| {
+			assert!(batch[1].has_debug());
+			assert!(batch[2].has_step());
+			assert!(batch[3].has_observation());
+		})
+		.count();
+	assert_eq!(steps, 9);
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "test-utils")]
+
+use rust_sc2::{bot::Bot, prelude::*};
+use sc2_proto::sc2api::PlayerType;
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Default)]
+struct Watcher {
+	bot: Rc<RefCell<Bot>>,
+	started: bool,
+	steps: usize,
+}
+
+impl Observer for Watcher {
+	fn on_start(&mut self) -> SC2Result<()> {
+		self.started = true;
+		Ok(())
+	}
+	fn on_step(&mut self, _iteration: usize) -> SC2Result<()> {
+		self.steps += 1;
+		Ok(())
+	}
+	fn bot(&self) -> Rc<RefCell<Bot>> {
+		Rc::clone(&self.bot)
+	}
+}
+
+#[test]
+fn observes_game_on_mock_server() {
+	let server = MockServer::start(MockFixtures::new(Race::Terran, 10)).unwrap();
+	let mut watcher = Watcher::default();
+
+	let mut runner = RunnerObserver::mock(
+		&mut watcher,
+		(
+			Computer::new(Race::Zerg, Difficulty::VeryEasy, None),
+			Computer::new(Race::Protoss, Difficulty::VeryEasy, None),
+		),
+		&server,
+	)
+	.unwrap();
+	runner.observed_player_id = 2;
+	let results = runner.run_game().unwrap();
+	drop(runner);
+
+	assert!(watcher.started);
+	assert_eq!(watcher.steps, 9);
+	assert_eq!(results, vec![(1, GameResult::Victory), (2, GameResult::Defeat)]);
+	assert_eq!(watcher.bot.borrow().player_id, 2);
+
+	let requests = server.requests();
+	let create_game = requests.iter().find(|req| req.has_create_game()).unwrap();
+	let setup = create_game.get_create_game().get_player_setup();
+	assert_eq!(setup.len(), 3);
+	assert_eq!(setup[0].get_field_type(), PlayerType::Observer);
+	let join_game = requests.iter().find(|req| req.has_join_game()).unwrap();
+	assert_eq!(join_game.get_join_game().get_observed_player_id(), 2);
+}
+
+#[test]
+fn watches_replay_on_mock_server() {
+	let server = MockServer::start(MockFixtures::new(Race::Terran, 5)).unwrap();
+	let mut watcher = Watcher::default();
+
+	let mut runner = ReplayRunner::mock(&mut watcher, &server).unwrap();
+	runner.disable_fog = true;
+	let results = runner.run_replay("Mock.SC2Replay").unwrap();
+	drop(runner);
+
+	assert_eq!(watcher.steps, 4);
+	assert_eq!(results.len(), 2);
+	assert!(watcher.bot.borrow().disable_fog);
+
+	let requests = server.requests();
+	let start_replay = requests.iter().find(|req| req.has_start_replay()).unwrap();
+	let start_replay = start_replay.get_start_replay();
+	assert!(start_replay.get_replay_path().ends_with("Mock.SC2Replay"));
+	assert!(start_replay.get_disable_fog());
+	assert!(!requests
+		.iter()
+		.any(|req| req.has_create_game() || req.has_join_game()));
+}
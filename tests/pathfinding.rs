@@ -0,0 +1,132 @@
+use ndarray::Array2;
+use rust_sc2::{
+	distance::Distance,
+	geometry::Point2,
+	influence::ThreatMap,
+	pathfinding::{PathWeights, PathingGrid},
+	pixel_map::{Pixel, PixelMap},
+};
+
+// 10x10 grid with wall at x = 5, which has a gap at y = 8
+fn walled_grid() -> PathingGrid {
+	let mut map: PixelMap = Array2::from_elem((10, 10), Pixel::Empty);
+	for y in 0..10 {
+		if y != 8 {
+			map[(5, y)] = Pixel::Set;
+		}
+	}
+	PathingGrid::new(&map)
+}
+
+#[test]
+fn finds_path_around_wall() {
+	let grid = walled_grid();
+	let start = Point2::new(2.5, 2.5);
+	let goal = Point2::new(8.5, 2.5);
+
+	let path = grid.find_path(start, goal, 0.375).unwrap();
+	assert_eq!(path.waypoints.first(), Some(&start));
+	assert_eq!(path.waypoints.last(), Some(&goal));
+	// Corners of the wall can't be cut, so gap is passed straight
+	assert!(path.waypoints.contains(&Point2::new(4.5, 8.5)));
+	assert!(path.waypoints.contains(&Point2::new(6.5, 8.5)));
+	// 2 diagonal and 4 straight moves to the gap, 2 moves through it and the same back
+	let expected = 4.0 * 2f32.sqrt() + 10.0;
+	assert!(
+		(path.distance - expected).abs() < 0.01,
+		"{} != {}",
+		path.distance,
+		expected
+	);
+}
+
+#[test]
+fn big_units_dont_fit_in_gaps() {
+	let grid = walled_grid();
+	let (start, goal) = (Point2::new(2.5, 2.5), Point2::new(8.5, 2.5));
+	assert!(grid.find_path(start, goal, 0.375).is_some());
+	assert!(grid.find_path(start, goal, 1.5).is_none());
+}
+
+#[test]
+fn blocked_goal_leads_to_closest_cell() {
+	let mut grid = walled_grid();
+	grid.set_rect(Point2::new(8.0, 3.0), (2, 2), false);
+	let path = grid
+		.find_path(Point2::new(7.5, 8.5), Point2::new(8.0, 3.0), 0.375)
+		.unwrap();
+	let end = *path.waypoints.last().unwrap();
+	assert!(!grid.is_pathable((8, 3)));
+	assert!(grid.is_pathable((end.x as usize, end.y as usize)));
+	assert!(end.distance_squared(Point2::new(8.0, 3.0)) <= 4.0);
+}
+
+#[test]
+fn flow_field_leads_to_target() {
+	let grid = walled_grid();
+	let (start, target) = (Point2::new(2.5, 2.5), Point2::new(8.5, 2.5));
+	let field = grid.flow_field(target, 0.375).unwrap();
+	let path = grid.find_path(start, target, 0.375).unwrap();
+	assert!((field.distance(start).unwrap() - path.distance).abs() < 0.01);
+	assert_eq!(field.distance(Point2::new(5.5, 2.5)), None);
+
+	let mut pos = start;
+	let mut steps = 0;
+	while pos != target {
+		pos = field.next_step(pos).unwrap();
+		steps += 1;
+		assert!(steps <= 20, "Target isn't reached");
+	}
+	// 6 moves to the gap, 2 moves through it and 6 back
+	assert_eq!(steps, 14);
+
+	assert!(grid.flow_field(target, 1.5).unwrap().distance(start).is_none());
+}
+
+#[test]
+fn weights_threats_detection_and_creep() {
+	let mut threats = ThreatMap::new((10, 10));
+	threats.add_ground(Point2::new(2.0, 2.0), 1.0, 10.0);
+	threats.add_air(Point2::new(7.0, 7.0), 1.0, 5.0);
+	threats.add_detection(Point2::new(7.0, 2.0), 1.0);
+	let mut creep: PixelMap = Array2::from_elem((10, 10), Pixel::Set);
+	creep[(2, 7)] = Pixel::Empty;
+	let weights = PathWeights {
+		threat: 2.0,
+		detection: 20.0,
+		creep: 3.0,
+		margin: 1.0,
+	};
+
+	let ground = weights.costs_from(&threats, &creep, false, false);
+	assert_eq!(ground[(1, 1)], 20.0);
+	assert_eq!(ground[(7, 7)], 0.0);
+	assert_eq!(ground[(7, 2)], 0.0);
+	assert_eq!(ground[(2, 7)], 3.0);
+
+	let air = weights.costs_from(&threats, &creep, true, true);
+	assert_eq!(air[(1, 1)], 0.0);
+	assert_eq!(air[(7, 7)], 10.0);
+	assert_eq!(air[(7, 2)], 20.0);
+	assert_eq!(air[(2, 7)], 0.0);
+}
+
+#[test]
+fn safe_path_avoids_detection() {
+	let grid = PathingGrid::new(&Array2::from_elem((30, 20), Pixel::Empty));
+	let mut threats = ThreatMap::new(grid.size());
+	threats.add_detection(Point2::new(15.0, 10.0), 5.0);
+	let creep: PixelMap = Array2::from_elem(grid.size(), Pixel::Set);
+	let costs = PathWeights::default().costs_from(&threats, &creep, false, true);
+
+	let (start, goal) = (Point2::new(5.5, 10.5), Point2::new(25.5, 10.5));
+	let direct = grid.find_path(start, goal, 0.5).unwrap();
+	let safe = grid
+		.find_path_with_cost(start, goal, 0.5, |cell| costs[cell])
+		.unwrap();
+	assert!(safe.distance > direct.distance);
+	assert!(safe
+		.waypoints
+		.iter()
+		.all(|p| threats.detection[(p.x as usize, p.y as usize)] == 0.0));
+}
@@ -0,0 +1,69 @@
+use rust_sc2::paths::{get_installed_maps, get_installed_versions, get_map_path, get_version_info};
+use std::{env, fs};
+
+fn fake_installation(name: &str) -> String {
+	let root = env::temp_dir().join(format!("rust-sc2-{}-{}", name, std::process::id()));
+	let _ = fs::remove_dir_all(&root);
+	for dir in &[
+		"Versions/Base75689",
+		"Versions/Base99999",
+		"Versions/Other",
+		"Maps/Ladder2019Season3",
+	] {
+		fs::create_dir_all(root.join(dir)).unwrap();
+	}
+	fs::write(root.join("Maps/EverDreamLE.SC2Map"), "").unwrap();
+	fs::write(root.join("Maps/Ladder2019Season3/AcropolisLE.SC2Map"), "").unwrap();
+	root.to_string_lossy().replace("\\", "/")
+}
+
+#[test]
+fn lists_installed_versions_and_maps() {
+	let sc2_path = fake_installation("discovery");
+
+	let versions = get_installed_versions(&sc2_path).unwrap();
+	assert_eq!(versions.len(), 2);
+	assert_eq!(versions[0].base_version, 75689);
+	assert_eq!(versions[0].name, Some("4.10.0"));
+	assert_eq!(versions[0].data_hash, Some("B89B5D6FA7CBF6452E721311BFBC6CB2"));
+	assert_eq!(versions[1].base_version, 99999);
+	assert_eq!(versions[1].data_hash, None);
+
+	let maps = get_installed_maps(&sc2_path).unwrap();
+	let paths = maps.iter().map(|m| m.path.as_str()).collect::<Vec<_>>();
+	assert_eq!(
+		paths,
+		["EverDreamLE.SC2Map", "Ladder2019Season3/AcropolisLE.SC2Map"]
+	);
+	assert_eq!(maps[1].name, "AcropolisLE");
+}
+
+#[test]
+fn resolves_map_paths() {
+	let sc2_path = fake_installation("maps");
+	let expected = format!("{}/Maps/Ladder2019Season3/AcropolisLE.SC2Map", sc2_path);
+
+	assert_eq!(get_map_path(&sc2_path, "AcropolisLE").unwrap(), expected);
+	assert_eq!(
+		get_map_path(&sc2_path, "Ladder2019Season3/AcropolisLE").unwrap(),
+		expected
+	);
+	assert_eq!(
+		get_map_path(&sc2_path, "EverDreamLE.SC2Map").unwrap(),
+		format!("{}/Maps/EverDreamLE.SC2Map", sc2_path)
+	);
+	assert!(get_map_path(&sc2_path, "Ladder2020Season1/AcropolisLE").is_err());
+	assert!(get_map_path(&sc2_path, "UnknownLE").is_err());
+}
+
+#[test]
+fn returns_static_version_info() {
+	// Data hash outlives given version name
+	let (base_build, data_hash) = {
+		let version = "4.10".to_string();
+		get_version_info(&version).unwrap()
+	};
+	assert_eq!(base_build, 75689);
+	assert_eq!(data_hash, "B89B5D6FA7CBF6452E721311BFBC6CB2");
+	assert!(get_version_info("0.1").is_err());
+}
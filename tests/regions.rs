@@ -0,0 +1,53 @@
+use ndarray::Array2;
+use rust_sc2::{
+	geometry::Point2,
+	pathfinding::PathingGrid,
+	pixel_map::{Pixel, PixelMap},
+	regions::Regions,
+};
+
+// 2 rooms 20x20 with wall at x = 20 between them, which has 3 cells wide gap at y = 8..11
+fn two_rooms() -> PathingGrid {
+	let mut map: PixelMap = Array2::from_elem((41, 20), Pixel::Empty);
+	for y in 0..20 {
+		if !(8..11).contains(&y) {
+			map[(20, y)] = Pixel::Set;
+		}
+	}
+	PathingGrid::new(&map)
+}
+
+#[test]
+fn finds_regions_and_chokes() {
+	let expansions = [
+		(Point2::new(5.5, 5.5), Point2::new(5.0, 5.0)),
+		(Point2::new(35.5, 15.5), Point2::new(36.0, 16.0)),
+	];
+	let regions = Regions::new(&two_rooms(), &expansions, &[]);
+	assert_eq!(regions.all.len(), 2);
+	assert_eq!(regions.chokes.len(), 1);
+
+	let left = regions.region_id(Point2::new(2.0, 2.0)).unwrap();
+	let right = regions.region_id(Point2::new(38.0, 2.0)).unwrap();
+	assert_ne!(left, right);
+	assert_eq!(regions.all[left].neighbors, vec![right]);
+	assert_eq!(regions.all[left].expansions, vec![0]);
+	assert_eq!(regions.all[right].expansions, vec![1]);
+	assert_eq!(regions.region_id(Point2::new(20.5, 2.5)), None);
+
+	let choke = &regions.chokes[0];
+	assert_eq!(choke.other_region(left), Some(right));
+	assert_eq!(choke.ramp, None);
+	assert_eq!(choke.center, Point2::new(20.5, 9.5));
+	assert!((choke.width - 3.0).abs() < 0.01, "{}", choke.width);
+	assert_eq!(regions.chokes_between(right, left).count(), 1);
+
+	assert_eq!(
+		regions.choke_path(expansions[0].0, expansions[1].0),
+		Some(vec![0])
+	);
+	assert_eq!(
+		regions.choke_path(expansions[0].0, Point2::new(2.0, 18.0)),
+		Some(vec![])
+	);
+}